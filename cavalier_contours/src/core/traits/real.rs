@@ -39,6 +39,13 @@ pub trait Real:
     fn max_value() -> Self {
         num_traits::real::Real::max_value()
     }
+
+    /// Returns true if this number is neither infinite nor NaN.
+    #[inline]
+    fn is_finite(self) -> bool {
+        // NaN and infinite values fail the comparison
+        self.abs() <= Real::max_value()
+    }
 }
 
 impl Real for f32 {
@@ -61,6 +68,11 @@ impl Real for f32 {
     fn four() -> Self {
         4.0f32
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
 }
 
 impl Real for f64 {
@@ -83,4 +95,9 @@ impl Real for f64 {
     fn four() -> Self {
         4.0f64
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
 }
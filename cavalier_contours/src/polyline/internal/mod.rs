@@ -2,6 +2,17 @@
 //!
 //! Not expected to be used directly as part of the library but may be used to help learn about the
 //! algorithms.
+pub mod pline_arrangement;
 pub mod pline_boolean;
+pub mod pline_clip;
+pub mod pline_corner;
+pub mod pline_fill_rule;
 pub mod pline_intersects;
+pub mod pline_medial_axis;
+pub mod pline_nesting;
 pub mod pline_offset;
+pub mod pline_shape_distance;
+pub mod pline_shape_offset;
+pub mod pline_shared_edges;
+pub mod pline_split;
+pub mod pline_stroke;
@@ -0,0 +1,533 @@
+use super::pline_intersects::find_intersects;
+use crate::{
+    core::{
+        math::{dist_squared, normalize_radians, Vector2},
+        traits::{ControlFlow, Real},
+        Control,
+    },
+    polyline::{
+        seg_arc_radius_and_center, seg_midpoint, seg_split_at_point, seg_tangent_vector,
+        ArrangementFace, FindIntersectsOptions, PlineArrangementOptions, PlineBasicIntersect,
+        PlineIntersectVisitor, PlineOverlappingIntersect, PlineSelfIntersectOptions, PlineVertex,
+        Polyline,
+    },
+};
+use static_aabb2d_index::StaticAABB2DIndexBuilder;
+use std::collections::HashMap;
+
+/// Visitor used to collect the self intersect points of a polyline by segment index.
+struct SplitPointsVisitor<'a, T> {
+    split_points: &'a mut [Vec<Vector2<T>>],
+}
+
+impl<'a, T> PlineIntersectVisitor<T, Control> for SplitPointsVisitor<'a, T>
+where
+    T: Real,
+{
+    fn visit_basic_intr(&mut self, intr: PlineBasicIntersect<T>) -> Control {
+        self.split_points[intr.start_index1].push(intr.point);
+        self.split_points[intr.start_index2].push(intr.point);
+        ControlFlow::continuing()
+    }
+
+    fn visit_overlapping_intr(&mut self, intr: PlineOverlappingIntersect<T>) -> Control {
+        for &point in [intr.point1, intr.point2].iter() {
+            self.split_points[intr.start_index1].push(point);
+            self.split_points[intr.start_index2].push(point);
+        }
+        ControlFlow::continuing()
+    }
+}
+
+/// Edge of the arrangement (single line or arc segment between two nodes).
+#[derive(Debug, Clone, Copy)]
+struct Edge<T> {
+    /// Start vertex of the segment (bulge defines the segment).
+    v1: PlineVertex<T>,
+    /// End position of the segment.
+    v2: Vector2<T>,
+    node1: usize,
+    node2: usize,
+}
+
+impl<T> Edge<T>
+where
+    T: Real,
+{
+    /// Get the segment start vertex for the half edge going from node1 to node2 (`reversed` is
+    /// false) or from node2 to node1 (`reversed` is true).
+    fn half_edge_start(&self, reversed: bool) -> PlineVertex<T> {
+        if reversed {
+            PlineVertex::from_vector2(self.v2, -self.v1.bulge)
+        } else {
+            self.v1
+        }
+    }
+
+    /// Get the segment end vertex for the half edge (see [Edge::half_edge_start]).
+    fn half_edge_end(&self, reversed: bool) -> PlineVertex<T> {
+        if reversed {
+            self.v1.with_bulge(T::zero())
+        } else {
+            PlineVertex::from_vector2(self.v2, T::zero())
+        }
+    }
+}
+
+/// Sort key of a half edge around its origin node: angle in [0, 2π) of the direction leaving the
+/// node and the signed curvature of the segment (positive if turning left). Half edges leaving in
+/// the same direction are ordered counter clockwise by their curvature.
+///
+/// Angles within `angle_eps` of 2π are wrapped to 0 so directions just below and just above the
+/// +x axis compare as fuzzy equal.
+fn half_edge_sort_key<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, angle_eps: T) -> (T, T)
+where
+    T: Real,
+{
+    let dir = seg_tangent_vector(v1, v2, v1.pos());
+    let angle = normalize_radians(dir.y.atan2(dir.x));
+    let angle = if angle > T::tau() - angle_eps {
+        T::zero()
+    } else {
+        angle
+    };
+    let curvature = if v1.bulge_is_zero() {
+        T::zero()
+    } else {
+        let (radius, _) = seg_arc_radius_and_center(v1, v2);
+        if v1.bulge_is_pos() {
+            T::one() / radius
+        } else {
+            -T::one() / radius
+        }
+    };
+
+    (angle, curvature)
+}
+
+/// Planar graph of half edges, half edge `2 * e` goes from node1 to node2 of edge `e` and half
+/// edge `2 * e + 1` goes from node2 to node1.
+struct HalfEdgeGraph<T> {
+    edges: Vec<Edge<T>>,
+    /// Position of each node.
+    node_positions: Vec<Vector2<T>>,
+    /// Half edges leaving each node sorted counter clockwise.
+    outgoing: Vec<Vec<usize>>,
+}
+
+impl<T> HalfEdgeGraph<T>
+where
+    T: Real,
+{
+    fn new(edges: Vec<Edge<T>>, node_positions: Vec<Vector2<T>>, angle_eps: T) -> Self {
+        let mut outgoing = vec![Vec::new(); node_positions.len()];
+        let mut sort_keys = Vec::with_capacity(2 * edges.len());
+        for (i, e) in edges.iter().enumerate() {
+            for &reversed in [false, true].iter() {
+                let h = 2 * i + (reversed as usize);
+                let origin = if reversed { e.node2 } else { e.node1 };
+                outgoing[origin].push(h);
+                sort_keys.push(half_edge_sort_key(
+                    e.half_edge_start(reversed),
+                    e.half_edge_end(reversed),
+                    angle_eps,
+                ));
+            }
+        }
+
+        for hs in outgoing.iter_mut() {
+            hs.sort_by(|&h1, &h2| {
+                let (a1, c1) = sort_keys[h1];
+                let (a2, c2) = sort_keys[h2];
+                if a1.fuzzy_eq_eps(a2, angle_eps) {
+                    c1.partial_cmp(&c2).unwrap()
+                } else {
+                    a1.partial_cmp(&a2).unwrap()
+                }
+            });
+        }
+
+        Self {
+            edges,
+            node_positions,
+            outgoing,
+        }
+    }
+
+    fn origin(&self, h: usize) -> usize {
+        let e = &self.edges[h / 2];
+        if h % 2 == 1 {
+            e.node2
+        } else {
+            e.node1
+        }
+    }
+
+    fn target(&self, h: usize) -> usize {
+        self.origin(h ^ 1)
+    }
+
+    /// Next half edge around the face to the left of half edge `h` (the half edge leaving the
+    /// target node that is next clockwise from the twin of `h`).
+    fn next(&self, h: usize) -> usize {
+        let hs = &self.outgoing[self.target(h)];
+        let twin_pos = hs.iter().position(|&x| x == h ^ 1).unwrap();
+        hs[(twin_pos + hs.len() - 1) % hs.len()]
+    }
+
+    /// Trace all the face cycles of the graph, returns the cycles (as half edge sequences) and the
+    /// cycle index of each half edge.
+    fn trace_cycles(&self) -> (Vec<Vec<usize>>, Vec<usize>) {
+        let mut cycle_of = vec![usize::MAX; 2 * self.edges.len()];
+        let mut cycles = Vec::new();
+        for hs in self.outgoing.iter() {
+            for &start in hs.iter() {
+                if cycle_of[start] != usize::MAX {
+                    continue;
+                }
+
+                let mut cycle = Vec::new();
+                let mut h = start;
+                while cycle_of[h] == usize::MAX {
+                    cycle_of[h] = cycles.len();
+                    cycle.push(h);
+                    h = self.next(h);
+                }
+                cycles.push(cycle);
+            }
+        }
+
+        (cycles, cycle_of)
+    }
+
+    /// Remove all edges with the same face cycle on both sides (dangling edges and bridges).
+    fn remove_edges_in_single_cycle(&mut self, cycle_of: &[usize]) -> bool {
+        let mut removed = false;
+        for hs in self.outgoing.iter_mut() {
+            let len = hs.len();
+            hs.retain(|&h| cycle_of[h] != cycle_of[h ^ 1]);
+            removed |= hs.len() != len;
+        }
+
+        removed
+    }
+
+    fn cycle_to_polyline(&self, cycle: &[usize]) -> Polyline<T> {
+        let mut pline = Polyline::with_capacity(cycle.len(), true);
+        for &h in cycle.iter() {
+            let pos = self.node_positions[self.origin(h)];
+            let bulge = self.edges[h / 2].half_edge_start(h % 2 == 1).bulge;
+            pline.add(pos.x, pos.y, bulge);
+        }
+
+        pline
+    }
+}
+
+/// Split all the segments of the polylines at the points given and create the edges, merging end
+/// points within `pos_equal_eps` into nodes and skipping duplicate (overlapping) segments.
+fn create_edges<T>(
+    plines: &[&Polyline<T>],
+    split_points: &mut [Vec<Vec<Vector2<T>>>],
+    pos_equal_eps: T,
+) -> (Vec<Edge<T>>, Vec<Vector2<T>>)
+where
+    T: Real,
+{
+    let mut segments = Vec::new();
+    for (pline, points) in plines.iter().zip(split_points.iter_mut()) {
+        for (seg_index, (v1, v2)) in pline.iter_segments().enumerate() {
+            if v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps) {
+                continue;
+            }
+
+            let seg_points = &mut points[seg_index];
+            seg_points.sort_unstable_by(|p1, p2| {
+                dist_squared(v1.pos(), *p1)
+                    .partial_cmp(&dist_squared(v1.pos(), *p2))
+                    .unwrap()
+            });
+
+            let mut current = v1;
+            for &point in seg_points.iter() {
+                if point.fuzzy_eq_eps(current.pos(), pos_equal_eps)
+                    || point.fuzzy_eq_eps(v2.pos(), pos_equal_eps)
+                {
+                    continue;
+                }
+
+                let split = seg_split_at_point(current, v2, point, pos_equal_eps);
+                segments.push((split.updated_start, point));
+                current = split.split_vertex;
+            }
+
+            segments.push((current, v2.pos()));
+        }
+    }
+
+    // merge segment end points into nodes
+    let end_points_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(2 * segments.len());
+        for (v1, v2) in segments.iter() {
+            builder.add(v1.x, v1.y, v1.x, v1.y);
+            builder.add(v2.x, v2.y, v2.x, v2.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let end_point = |i: usize| {
+        let (v1, v2) = segments[i / 2];
+        if i % 2 == 1 {
+            v2
+        } else {
+            v1.pos()
+        }
+    };
+
+    let mut node_of = vec![usize::MAX; 2 * segments.len()];
+    let mut node_positions = Vec::new();
+    let mut query_stack = Vec::new();
+    for i in 0..node_of.len() {
+        if node_of[i] != usize::MAX {
+            continue;
+        }
+
+        let p = end_point(i);
+        let node = node_positions.len();
+        node_positions.push(p);
+        node_of[i] = node;
+        for j in end_points_index.query_with_stack(
+            p.x - pos_equal_eps,
+            p.y - pos_equal_eps,
+            p.x + pos_equal_eps,
+            p.y + pos_equal_eps,
+            &mut query_stack,
+        ) {
+            if node_of[j] == usize::MAX {
+                node_of[j] = node;
+            }
+        }
+    }
+
+    // create edges, skipping segments between the same nodes with the same midpoint
+    let mut edges: Vec<Edge<T>> = Vec::with_capacity(segments.len());
+    let mut edges_between: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, &(v1, v2)) in segments.iter().enumerate() {
+        let node1 = node_of[2 * i];
+        let node2 = node_of[2 * i + 1];
+        if node1 == node2 {
+            continue;
+        }
+
+        let midpoint = seg_midpoint(v1, PlineVertex::from_vector2(v2, T::zero()));
+        let key = (node1.min(node2), node1.max(node2));
+        let existing = edges_between.entry(key).or_default();
+        let is_duplicate = existing.iter().any(|&e| {
+            let e = &edges[e];
+            seg_midpoint(e.v1, PlineVertex::from_vector2(e.v2, T::zero()))
+                .fuzzy_eq_eps(midpoint, pos_equal_eps)
+        });
+
+        if is_duplicate {
+            continue;
+        }
+
+        existing.push(edges.len());
+        edges.push(Edge {
+            v1,
+            v2,
+            node1,
+            node2,
+        });
+    }
+
+    (edges, node_positions)
+}
+
+/// Find the representative (root) of `i` in the union find `parents` (with path halving).
+pub(crate) fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Find all the bounded faces of the planar arrangement formed by the polylines given (open and
+/// closed).
+///
+/// All the polylines are split at every intersect between them (including self intersects) and
+/// the resulting segments form a planar graph of half edges, with the half edges leaving each node
+/// ordered by their direction and curvature so arcs leaving in the same direction are ordered
+/// correctly. Edges not bounding a face (dangling edges and bridges between separate loops) are
+/// removed and the face cycles are traced from the graph. Overlapping segments are only included
+/// once.
+///
+/// Each face is returned with a counter clockwise boundary, the clockwise holes created by the
+/// groups of polylines nested in it, and the index of the face it is nested in.
+pub fn find_arrangement_faces<T>(
+    plines: &[Polyline<T>],
+    options: &PlineArrangementOptions<T>,
+) -> Vec<ArrangementFace<T>>
+where
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let plines: Vec<&Polyline<T>> = plines.iter().filter(|p| p.len() > 1).collect();
+    if plines.is_empty() {
+        return Vec::new();
+    }
+
+    let aabb_indexes: Vec<_> = plines
+        .iter()
+        .map(|p| p.create_approx_aabb_index().unwrap())
+        .collect();
+
+    let mut split_points: Vec<Vec<Vec<Vector2<T>>>> =
+        plines.iter().map(|p| vec![Vec::new(); p.len()]).collect();
+
+    // self intersects
+    for (i, pline) in plines.iter().enumerate() {
+        let mut visitor = SplitPointsVisitor {
+            split_points: &mut split_points[i],
+        };
+        pline.visit_self_intersects_opt(
+            &mut visitor,
+            &PlineSelfIntersectOptions {
+                aabb_index: Some(&aabb_indexes[i]),
+                pos_equal_eps,
+                ..Default::default()
+            },
+        );
+    }
+
+    // intersects between polylines with overlapping extents
+    let extents_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(plines.len());
+        for index in aabb_indexes.iter() {
+            builder.add(index.min_x(), index.min_y(), index.max_x(), index.max_y());
+        }
+        builder.build().unwrap()
+    };
+
+    let mut query_stack = Vec::new();
+    for (i, pline1) in plines.iter().enumerate() {
+        let index1 = &aabb_indexes[i];
+        for j in extents_index.query_with_stack(
+            index1.min_x(),
+            index1.min_y(),
+            index1.max_x(),
+            index1.max_y(),
+            &mut query_stack,
+        ) {
+            if j <= i {
+                continue;
+            }
+
+            let intrs = find_intersects(
+                pline1,
+                plines[j],
+                &FindIntersectsOptions {
+                    pline1_aabb_index: Some(index1),
+                    pos_equal_eps,
+                },
+            );
+
+            for intr in intrs.basic_intersects {
+                split_points[i][intr.start_index1].push(intr.point);
+                split_points[j][intr.start_index2].push(intr.point);
+            }
+
+            for intr in intrs.overlapping_intersects {
+                for &point in [intr.point1, intr.point2].iter() {
+                    split_points[i][intr.start_index1].push(point);
+                    split_points[j][intr.start_index2].push(point);
+                }
+            }
+        }
+    }
+
+    let (edges, node_positions) = create_edges(&plines, &mut split_points, pos_equal_eps);
+    let mut graph = HalfEdgeGraph::new(edges, node_positions, pos_equal_eps);
+    let (mut cycles, cycle_of) = graph.trace_cycles();
+    if graph.remove_edges_in_single_cycle(&cycle_of) {
+        cycles = graph.trace_cycles().0;
+    }
+
+    // connected components of the remaining graph
+    let mut parents: Vec<usize> = (0..graph.node_positions.len()).collect();
+    for hs in graph.outgoing.iter() {
+        for &h in hs.iter() {
+            let r1 = find_root(&mut parents, graph.origin(h));
+            let r2 = find_root(&mut parents, graph.target(h));
+            parents[r1] = r2;
+        }
+    }
+
+    // counter clockwise cycles are faces, clockwise cycles are the outer boundaries of the
+    // connected components
+    let mut faces = Vec::new();
+    let mut face_components = Vec::new();
+    let mut boundaries = Vec::new();
+    for cycle in cycles.iter() {
+        let pline = graph.cycle_to_polyline(cycle);
+        let component = find_root(&mut parents, graph.origin(cycle[0]));
+        if pline.area() > T::zero() {
+            faces.push(ArrangementFace {
+                boundary: pline,
+                holes: Vec::new(),
+                parent: None,
+            });
+            face_components.push(component);
+        } else {
+            boundaries.push((pline, component));
+        }
+    }
+
+    if faces.is_empty() {
+        return faces;
+    }
+
+    // each component boundary is a hole in the smallest face of another component containing it
+    let face_extents: Vec<_> = faces
+        .iter()
+        .map(|f| f.boundary.extents().unwrap())
+        .collect();
+    let mut component_parent = HashMap::new();
+    for (boundary, component) in boundaries {
+        let point = boundary[0].pos();
+        let mut parent: Option<(usize, T)> = None;
+        for (i, face) in faces.iter().enumerate() {
+            let e = &face_extents[i];
+            if face_components[i] == component
+                || point.x < e.min_x
+                || point.x > e.max_x
+                || point.y < e.min_y
+                || point.y > e.max_y
+            {
+                continue;
+            }
+
+            let area = face.boundary.area();
+            let is_smaller = match parent {
+                Some((_, parent_area)) => area < parent_area,
+                None => true,
+            };
+
+            if is_smaller && face.boundary.winding_number(point) != 0 {
+                parent = Some((i, area));
+            }
+        }
+
+        if let Some((i, _)) = parent {
+            faces[i].holes.push(boundary);
+            component_parent.insert(component, i);
+        }
+    }
+
+    for (face, component) in faces.iter_mut().zip(face_components) {
+        face.parent = component_parent.get(&component).copied();
+    }
+
+    faces
+}
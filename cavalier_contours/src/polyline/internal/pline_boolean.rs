@@ -1,9 +1,11 @@
 use crate::{
     core::math::dist_squared,
     polyline::{
-        seg_midpoint, seg_split_at_point, BooleanOp, BooleanPlineSlice, BooleanResult,
-        BooleanResultPline, FindIntersectsOptions, OpenPlineSlice, PlineBasicIntersect,
-        PlineBooleanOptions, PolylineSlice,
+        seg_closest_point, seg_fast_approx_bounding_box, seg_length, seg_midpoint,
+        seg_split_at_point, seg_tangent_vector, BooleanDivideResult, BooleanOp, BooleanPlineSlice,
+        BooleanResult, BooleanResultPline, BooleanTouchingMode, FindIntersectsOptions,
+        OpenPlineSlice, PlineBasicIntersect, PlineBooleanOptions, PlineOpError, PlineSplitOptions,
+        PlineStation, PlineVertex, PolylineSlice, Shape, ShapeBooleanOptions,
     },
 };
 use std::collections::BTreeMap;
@@ -77,13 +79,13 @@ where
 }
 
 #[derive(Debug, Clone, Copy)]
-struct SlicePoint<T> {
+pub(crate) struct SlicePoint<T> {
     pos: Vector2<T>,
     is_start_of_overlapping_slice: bool,
 }
 
 impl<T> SlicePoint<T> {
-    fn new(pos: Vector2<T>, is_start_of_overlapping_slice: bool) -> Self {
+    pub(crate) fn new(pos: Vector2<T>, is_start_of_overlapping_slice: bool) -> Self {
         Self {
             pos,
             is_start_of_overlapping_slice,
@@ -108,8 +110,40 @@ pub fn slice_at_intersects<T, F>(
     T: Real,
     F: FnMut(Vector2<T>) -> bool,
 {
-    let mut intersects_lookup = BTreeMap::<usize, Vec<SlicePoint<T>>>::new();
+    let mut intersects_lookup = BTreeMap::new();
+    add_slice_points(
+        &mut intersects_lookup,
+        pline,
+        boolean_info,
+        use_second_index,
+        pos_equal_eps,
+    );
+    slice_at_slice_points(
+        pline,
+        intersects_lookup,
+        !use_second_index,
+        point_on_slice_pred,
+        output_slices,
+        pos_equal_eps,
+    );
+}
 
+/// Add the points where `pline` is to be sliced (intersects and overlapping slice end points from
+/// `boolean_info`) to `intersects_lookup`, keyed by the segment index the point lies on.
+///
+/// If `use_second_index` is true then the second index of the intersect types is used to correspond
+/// with pline, otherwise the first index is used. Calling this multiple times with different
+/// `boolean_info` (e.g. from processing `pline` against several other polylines) collects all the
+/// points in the one lookup.
+fn add_slice_points<T>(
+    intersects_lookup: &mut BTreeMap<usize, Vec<SlicePoint<T>>>,
+    pline: &Polyline<T>,
+    boolean_info: &ProcessForBooleanResult<T>,
+    use_second_index: bool,
+    pos_equal_eps: T,
+) where
+    T: Real,
+{
     // helper function to adjust overlapping slice start point and endpoint indexes for lookup
     let adjust_sp_ep_indexes =
         |sp_idx: &mut usize, sp: Vector2<T>, ep_idx: &mut usize, ep: Vector2<T>| {
@@ -180,7 +214,24 @@ pub fn slice_at_intersects<T, F>(
                 .push(SlicePoint::new(ep, !sp_is_slice_start));
         }
     }
+}
 
+/// Slice `pline` at all the points in `intersects_lookup` (see [add_slice_points]).
+///
+/// `point_on_slice_pred` is called on at least one point from each slice, if it returns true then
+/// the slice is kept, otherwise it is discarded. `source_is_pline1` is recorded in the slices
+/// created and appended to `output_slices`.
+pub(crate) fn slice_at_slice_points<T, F>(
+    pline: &Polyline<T>,
+    mut intersects_lookup: BTreeMap<usize, Vec<SlicePoint<T>>>,
+    source_is_pline1: bool,
+    point_on_slice_pred: &mut F,
+    output_slices: &mut Vec<BooleanPlineSlice<T>>,
+    pos_equal_eps: T,
+) where
+    T: Real,
+    F: FnMut(Vector2<T>) -> bool,
+{
     // sort intersects by distance from segment start vertex
     for (&i, intr_list) in intersects_lookup.iter_mut() {
         let start_pos = pline[i].pos();
@@ -239,7 +290,7 @@ pub fn slice_at_intersects<T, F>(
                 if let Some(s) = opl {
                     output_slices.push(BooleanPlineSlice::from_open_pline_slice(
                         &s,
-                        !use_second_index,
+                        source_is_pline1,
                         false,
                     ));
                 }
@@ -289,7 +340,7 @@ pub fn slice_at_intersects<T, F>(
                         loop_count,
                         pos_equal_eps,
                     ),
-                    !use_second_index,
+                    source_is_pline1,
                     false,
                 );
 
@@ -340,28 +391,17 @@ where
     F: FnMut(Vector2<T>) -> bool,
     U: FnMut(Vector2<T>) -> bool,
 {
-    let mut slices_remaining = Vec::new();
-    // slice pline1
-    slice_at_intersects(
-        pline1,
-        boolean_info,
-        false,
+    let pruned = prune_loop_slices(
+        &[pline1],
+        &[pline2],
+        &[(0, 0, boolean_info)],
         pline1_point_on_slice_pred,
-        &mut slices_remaining,
-        pos_equal_eps,
-    );
-
-    let start_of_pline2_slices = slices_remaining.len();
-    // slice pline2
-    slice_at_intersects(
-        pline2,
-        boolean_info,
-        true,
         pline2_point_on_slice_pred,
-        &mut slices_remaining,
         pos_equal_eps,
     );
 
+    let mut slices_remaining = pruned.slices;
+    let start_of_pline2_slices = pruned.start_of_loops2_slices;
     let start_of_pline1_overlapping_slices = slices_remaining.len();
 
     // reserve space for set of overlapping slices from both polylines
@@ -401,6 +441,128 @@ where
         start_of_pline2_overlapping_slices,
     }
 }
+
+/// Slice every loop of `loops` at all of its intersects from `pair_infos` (see
+/// [prune_loop_slices]), the second index of each pair is the loop index if `use_second_index` is
+/// true otherwise the first index is. Slices are appended to `output_slices` with the index of the
+/// loop they came from appended to `slice_loops`, returns the indexes of the loops with no
+/// intersects.
+fn slice_loops_at_intersects<T, F>(
+    loops: &[&Polyline<T>],
+    pair_infos: &[(usize, usize, &ProcessForBooleanResult<T>)],
+    use_second_index: bool,
+    point_on_slice_pred: &mut F,
+    output_slices: &mut Vec<BooleanPlineSlice<T>>,
+    slice_loops: &mut Vec<usize>,
+    pos_equal_eps: T,
+) -> Vec<usize>
+where
+    T: Real,
+    F: FnMut(Vector2<T>) -> bool,
+{
+    let mut loop_pairs = vec![Vec::new(); loops.len()];
+    for &(i1, i2, boolean_info) in pair_infos.iter() {
+        loop_pairs[if use_second_index { i2 } else { i1 }].push(boolean_info);
+    }
+
+    let mut unsliced = Vec::new();
+    for (i, &pline) in loops.iter().enumerate() {
+        let mut intersects_lookup = BTreeMap::new();
+        for &boolean_info in loop_pairs[i].iter() {
+            add_slice_points(
+                &mut intersects_lookup,
+                pline,
+                boolean_info,
+                use_second_index,
+                pos_equal_eps,
+            );
+        }
+
+        if intersects_lookup.is_empty() {
+            unsliced.push(i);
+            continue;
+        }
+
+        slice_at_slice_points(
+            pline,
+            intersects_lookup,
+            !use_second_index,
+            point_on_slice_pred,
+            output_slices,
+            pos_equal_eps,
+        );
+        slice_loops.resize(output_slices.len(), i);
+    }
+
+    unsliced
+}
+
+/// Holds the non-overlapping slices of two collections of loops after pruning them, see
+/// [prune_loop_slices].
+pub(crate) struct PrunedLoopSlices<T> {
+    /// Slices of the `loops1` polylines followed by the slices of the `loops2` polylines (starting
+    /// at `start_of_loops2_slices`).
+    pub slices: Vec<BooleanPlineSlice<T>>,
+    /// Index of the loop each slice in `slices` came from.
+    pub slice_loops: Vec<usize>,
+    pub start_of_loops2_slices: usize,
+    /// Indexes of the `loops1` polylines that have no intersects (not sliced).
+    pub unsliced_loops1: Vec<usize>,
+    /// Indexes of the `loops2` polylines that have no intersects (not sliced).
+    pub unsliced_loops2: Vec<usize>,
+}
+
+/// Same as [prune_slices] for the non-overlapping slices but between two collections of loops
+/// (e.g. the polylines of two shapes), `pair_infos` holds the result of [process_for_boolean] for
+/// every pair of loops processed (`loops1` index, `loops2` index, result).
+///
+/// Each loop is sliced at all of its intersects with the loops of the other collection. Overlapping
+/// slices are not added, they are left to the caller since which copies bound the result depends on
+/// the operation.
+pub(crate) fn prune_loop_slices<T, F, U>(
+    loops1: &[&Polyline<T>],
+    loops2: &[&Polyline<T>],
+    pair_infos: &[(usize, usize, &ProcessForBooleanResult<T>)],
+    loops1_point_on_slice_pred: &mut F,
+    loops2_point_on_slice_pred: &mut U,
+    pos_equal_eps: T,
+) -> PrunedLoopSlices<T>
+where
+    T: Real,
+    F: FnMut(Vector2<T>) -> bool,
+    U: FnMut(Vector2<T>) -> bool,
+{
+    let mut slices = Vec::new();
+    let mut slice_loops = Vec::new();
+    let unsliced_loops1 = slice_loops_at_intersects(
+        loops1,
+        pair_infos,
+        false,
+        loops1_point_on_slice_pred,
+        &mut slices,
+        &mut slice_loops,
+        pos_equal_eps,
+    );
+    let start_of_loops2_slices = slices.len();
+    let unsliced_loops2 = slice_loops_at_intersects(
+        loops2,
+        pair_infos,
+        true,
+        loops2_point_on_slice_pred,
+        &mut slices,
+        &mut slice_loops,
+        pos_equal_eps,
+    );
+
+    PrunedLoopSlices {
+        slices,
+        slice_loops,
+        start_of_loops2_slices,
+        unsliced_loops1,
+        unsliced_loops2,
+    }
+}
+
 pub trait StitchSelector {
     fn select(&self, current_slice_idx: usize, available_idx: &[usize]) -> Option<usize>;
 }
@@ -563,6 +725,79 @@ pub fn stitch_slices_into_closed_polylines<T, S>(
 where
     T: Real,
     S: StitchSelector,
+{
+    let mut dangling_count = 0;
+    stitch_slices_counting_dangling(
+        slices,
+        source_pline1,
+        source_pline2,
+        stitch_selector,
+        slice_join_eps,
+        pos_equal_eps,
+        &mut dangling_count,
+    )
+}
+
+/// Same as [stitch_slices_into_closed_polylines] but also adds the number of polylines discarded
+/// due to failing to stitch closed to `dangling_count`.
+fn stitch_slices_counting_dangling<T, S>(
+    slices: &[BooleanPlineSlice<T>],
+    source_pline1: &Polyline<T>,
+    source_pline2: &Polyline<T>,
+    stitch_selector: &S,
+    slice_join_eps: T,
+    pos_equal_eps: T,
+    dangling_count: &mut usize,
+) -> Vec<BooleanResultPline<T>>
+where
+    T: Real,
+    S: StitchSelector,
+{
+    let get_source = |i: usize| {
+        if slices[i].source_is_pline1 {
+            source_pline1
+        } else {
+            source_pline2
+        }
+    };
+
+    stitch_slices_with_sources(
+        slices,
+        get_source,
+        stitch_selector,
+        slice_join_eps,
+        pos_equal_eps,
+        dangling_count,
+    )
+}
+
+/// Test if the open polyline `pline` that failed to stitch closed is a dangling chain to be
+/// reported (counted as dangling) rather than a sliver left over from epsilon thresholds around
+/// overlapping segments.
+///
+/// A chain no longer than `slice_join_eps` is not reported, its end point is within
+/// `slice_join_eps` of its start point so it is a loop collapsed to nothing.
+pub(crate) fn is_dangling_chain<T>(pline: &Polyline<T>, slice_join_eps: T) -> bool
+where
+    T: Real,
+{
+    pline.path_length() > slice_join_eps
+}
+
+/// Same as [stitch_slices_counting_dangling] but `get_source` is used to get the source polyline
+/// for each slice index (allowing slices from any number of polylines to be stitched together).
+pub(crate) fn stitch_slices_with_sources<'a, T, S, G>(
+    slices: &[BooleanPlineSlice<T>],
+    get_source: G,
+    stitch_selector: &S,
+    slice_join_eps: T,
+    pos_equal_eps: T,
+    dangling_count: &mut usize,
+) -> Vec<BooleanResultPline<T>>
+where
+    T: Real + 'a,
+    S: StitchSelector,
+    G: Fn(usize) -> &'a Polyline<T>,
 {
     let mut result = Vec::new();
     if slices.is_empty() {
@@ -610,13 +845,6 @@ where
     let mut query_results = Vec::new();
     let mut query_stack = Vec::with_capacity(8);
 
-    let get_source = |s: BooleanPlineSlice<T>| {
-        if s.source_is_pline1 {
-            source_pline1
-        } else {
-            source_pline2
-        }
-    };
     // loop through all slice indexes
     for i in 0..slices.len() {
         if visited_slice_idx[i] {
@@ -625,7 +853,7 @@ where
         visited_slice_idx[i] = true;
 
         let s = slices[i];
-        let mut current_pline = s.to_polyline(get_source(s), pos_equal_eps);
+        let mut current_pline = s.to_polyline(get_source(i), pos_equal_eps);
         let mut subslices = vec![s];
 
         let beginning_slice_idx = i;
@@ -662,12 +890,18 @@ where
             if query_results.is_empty() {
                 // may arrive here due to epsilon/thresholds around overlapping segments,
                 // discard the pline
+                if is_dangling_chain(&current_pline, slice_join_eps) {
+                    *dangling_count += 1;
+                }
                 break;
             }
 
             match stitch_selector.select(current_slice_idx, &query_results) {
                 None => {
                     // discard current polyline
+                    if is_dangling_chain(&current_pline, slice_join_eps) {
+                        *dangling_count += 1;
+                    }
                     break;
                 }
                 Some(connected_slice_idx) if connected_slice_idx == beginning_slice_idx => {
@@ -678,7 +912,11 @@ where
                 Some(connected_slice_idx) => {
                     let s = slices[connected_slice_idx];
                     current_pline.remove_last();
-                    s.stitch_onto(get_source(s), &mut current_pline, pos_equal_eps);
+                    s.stitch_onto(
+                        get_source(connected_slice_idx),
+                        &mut current_pline,
+                        pos_equal_eps,
+                    );
                     visited_slice_idx[connected_slice_idx] = true;
                     subslices.push(s);
 
@@ -700,6 +938,171 @@ pub fn polyline_boolean<T>(
     operation: BooleanOp,
     options: &PlineBooleanOptions<T>,
 ) -> BooleanResult<T>
+where
+    T: Real,
+{
+    let mut dangling_count = 0;
+    polyline_boolean_counting_dangling(pline1, pline2, operation, options, &mut dangling_count)
+}
+
+/// Same as [polyline_boolean] but validates the input polylines and returns an error if slices
+/// failed to stitch together into closed polylines rather than discarding them.
+pub fn try_polyline_boolean<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    operation: BooleanOp,
+    options: &PlineBooleanOptions<T>,
+) -> Result<BooleanResult<T>, PlineOpError>
+where
+    T: Real,
+{
+    for (input, pline) in [pline1, pline2].iter().enumerate() {
+        pline.validate_as_input(input, options.pos_equal_eps)?;
+        if !pline.is_closed() {
+            return Err(PlineOpError::OpenInput { input });
+        }
+    }
+
+    let mut dangling_count = 0;
+    let result =
+        polyline_boolean_counting_dangling(pline1, pline2, operation, options, &mut dangling_count);
+    if dangling_count > 0 {
+        return Err(PlineOpError::DanglingSlices {
+            count: dangling_count,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Divide `pline1` into the pieces inside `pline2` and the pieces outside `pline2`.
+///
+/// Same result as performing [BooleanOp::And] and [BooleanOp::Not] with [polyline_boolean] but the
+/// intersects between the polylines are only found once.
+pub fn polyline_boolean_divide<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineBooleanOptions<T>,
+) -> BooleanDivideResult<T>
+where
+    T: Real,
+{
+    if pline1.len() < 2 {
+        return BooleanDivideResult::default();
+    }
+
+    let constructed_index;
+    let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
+        x
+    } else {
+        constructed_index = pline1.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let boolean_info =
+        process_for_boolean(pline1, pline2, pline1_aabb_index, options.pos_equal_eps);
+
+    let mut dangling_count = 0;
+    let inside = boolean_from_info(
+        pline1,
+        pline2,
+        &boolean_info,
+        BooleanOp::And,
+        options,
+        &mut dangling_count,
+    );
+    let outside = boolean_from_info(
+        pline1,
+        pline2,
+        &boolean_info,
+        BooleanOp::Not,
+        options,
+        &mut dangling_count,
+    );
+
+    BooleanDivideResult { inside, outside }
+}
+
+/// Imprint the intersects between `pline1` and `pline2` onto `pline1`.
+///
+/// Returns a copy of `pline1` with a vertex added at every point where `pline2` intersects it
+/// (including the end points of any overlapping segments), bulges are updated so the shape of
+/// `pline1` is unchanged. No vertex is added where an intersect is at an existing vertex. `pline1`
+/// and `pline2` may be open or closed.
+pub fn polyline_imprint<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineBooleanOptions<T>,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    if pline1.len() < 2 || pline2.len() < 2 {
+        return pline1.clone();
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let constructed_index;
+    let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
+        x
+    } else {
+        constructed_index = pline1.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let boolean_info = process_for_boolean(pline1, pline2, pline1_aabb_index, pos_equal_eps);
+    let mut intersects_lookup = BTreeMap::new();
+    add_slice_points(
+        &mut intersects_lookup,
+        pline1,
+        &boolean_info,
+        false,
+        pos_equal_eps,
+    );
+
+    let mut result = Polyline::with_capacity(
+        pline1.len() + intersects_lookup.values().map(|l| l.len()).sum::<usize>(),
+        pline1.is_closed(),
+    );
+    for (i, j) in pline1.iter_segment_indexes() {
+        let mut start = pline1[i];
+        let end = pline1[j];
+        if let Some(intrs_list) = intersects_lookup.get_mut(&i) {
+            let start_pos = start.pos();
+            intrs_list.sort_unstable_by(|intr1, intr2| {
+                let dist1 = dist_squared(intr1.pos, start_pos);
+                let dist2 = dist_squared(intr2.pos, start_pos);
+                dist1.partial_cmp(&dist2).unwrap()
+            });
+
+            for intr in intrs_list.iter() {
+                if intr.pos.fuzzy_eq_eps(start.pos(), pos_equal_eps)
+                    || intr.pos.fuzzy_eq_eps(end.pos(), pos_equal_eps)
+                {
+                    continue;
+                }
+                let split = seg_split_at_point(start, end, intr.pos, pos_equal_eps);
+                result.add_vertex(split.updated_start);
+                start = split.split_vertex;
+            }
+        }
+        result.add_vertex(start);
+    }
+
+    if !pline1.is_closed() {
+        result.add_vertex(*pline1.last().unwrap());
+    }
+
+    result
+}
+
+fn polyline_boolean_counting_dangling<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    operation: BooleanOp,
+    options: &PlineBooleanOptions<T>,
+    dangling_count: &mut usize,
+) -> BooleanResult<T>
 where
     T: Real,
 {
@@ -718,6 +1121,30 @@ where
     let boolean_info =
         process_for_boolean(pline1, pline2, pline1_aabb_index, options.pos_equal_eps);
 
+    boolean_from_info(
+        pline1,
+        pline2,
+        &boolean_info,
+        operation,
+        options,
+        dangling_count,
+    )
+}
+
+/// Perform boolean operation between two polylines using the intersects already found with
+/// [process_for_boolean] (allowing multiple operations to be performed without finding the
+/// intersects again).
+fn boolean_from_info<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    boolean_info: &ProcessForBooleanResult<T>,
+    operation: BooleanOp,
+    options: &PlineBooleanOptions<T>,
+    dangling_count: &mut usize,
+) -> BooleanResult<T>
+where
+    T: Real,
+{
     // helper functions to test if point is inside pline1 and pline2
     let mut point_in_pline1 = |point: Vector2<T>| pline1.winding_number(point) != 0;
     let mut point_in_pline2 = |point: Vector2<T>| pline2.winding_number(point) != 0;
@@ -729,7 +1156,7 @@ where
     let pos_equal_eps = options.pos_equal_eps;
     let slice_join_eps = options.slice_join_eps;
 
-    match operation {
+    let result = match operation {
         BooleanOp::Or => {
             if boolean_info.completely_overlapping() {
                 // pline1 completely overlapping pline2 just return pline2
@@ -753,7 +1180,7 @@ where
                 let pruned_slices = prune_slices(
                     &pline1,
                     &pline2,
-                    &boolean_info,
+                    boolean_info,
                     &mut |pt| !point_in_pline2(pt),
                     &mut |pt| !point_in_pline1(pt),
                     false,
@@ -762,13 +1189,14 @@ where
 
                 let stitch_selector = OrAndStitchSelector::from_pruned_slices(&pruned_slices);
 
-                let remaining = stitch_slices_into_closed_polylines(
+                let remaining = stitch_slices_counting_dangling(
                     &pruned_slices.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector,
                     slice_join_eps,
                     pos_equal_eps,
+                    dangling_count,
                 );
 
                 let mut pos_plines = Vec::new();
@@ -809,7 +1237,7 @@ where
                 let pruned_slices = prune_slices(
                     &pline1,
                     &pline2,
-                    &boolean_info,
+                    boolean_info,
                     &mut point_in_pline2,
                     &mut point_in_pline1,
                     false,
@@ -817,13 +1245,14 @@ where
                 );
 
                 let stitch_selector = OrAndStitchSelector::from_pruned_slices(&pruned_slices);
-                let pos_plines = stitch_slices_into_closed_polylines(
+                let pos_plines = stitch_slices_counting_dangling(
                     &pruned_slices.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector,
                     slice_join_eps,
                     pos_equal_eps,
+                    dangling_count,
                 );
 
                 BooleanResult::new(pos_plines, Vec::new())
@@ -850,7 +1279,7 @@ where
                 let pruned_slices = prune_slices(
                     &pline1,
                     &pline2,
-                    &boolean_info,
+                    boolean_info,
                     &mut |pt| !point_in_pline2(pt),
                     &mut point_in_pline1,
                     true,
@@ -859,13 +1288,14 @@ where
 
                 let stitch_selector = NotXorStitchSelector::from_pruned_slices(&pruned_slices);
 
-                let pos_plines = stitch_slices_into_closed_polylines(
+                let pos_plines = stitch_slices_counting_dangling(
                     &pruned_slices.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector,
                     slice_join_eps,
                     pos_equal_eps,
+                    dangling_count,
                 );
 
                 BooleanResult::new(pos_plines, Vec::new())
@@ -891,7 +1321,7 @@ where
                 let pruned_slices1 = prune_slices(
                     &pline1,
                     &pline2,
-                    &boolean_info,
+                    boolean_info,
                     &mut |pt| !point_in_pline2(pt),
                     &mut point_in_pline1,
                     true,
@@ -899,20 +1329,21 @@ where
                 );
 
                 let stitch_selector1 = NotXorStitchSelector::from_pruned_slices(&pruned_slices1);
-                let mut remaining1 = stitch_slices_into_closed_polylines(
+                let mut remaining1 = stitch_slices_counting_dangling(
                     &pruned_slices1.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector1,
                     slice_join_eps,
                     pos_equal_eps,
+                    dangling_count,
                 );
 
                 // collect pline2 NOT pline1 results
                 let pruned_slices2 = prune_slices(
                     &pline1,
                     &pline2,
-                    &boolean_info,
+                    boolean_info,
                     &mut point_in_pline2,
                     &mut |pt| !point_in_pline1(pt),
                     true,
@@ -920,18 +1351,897 @@ where
                 );
 
                 let stitch_selector2 = NotXorStitchSelector::from_pruned_slices(&pruned_slices2);
-                let remaining2 = stitch_slices_into_closed_polylines(
+                let remaining2 = stitch_slices_counting_dangling(
                     &pruned_slices2.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector2,
                     slice_join_eps,
                     pos_equal_eps,
+                    dangling_count,
                 );
 
                 remaining1.extend(remaining2);
                 BooleanResult::new(remaining1, Vec::new())
             }
         }
+    };
+
+    apply_touching_options(result, options)
+}
+
+/// Apply the [BooleanTouchingMode] and sliver removal from `options` to the stitched result of a
+/// boolean operation.
+fn apply_touching_options<T>(
+    result: BooleanResult<T>,
+    options: &PlineBooleanOptions<T>,
+) -> BooleanResult<T>
+where
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let BooleanResult {
+        mut pos_plines,
+        mut neg_plines,
+    } = result;
+
+    // slivers are dropped first so they are never merged into other polylines
+    if options.drop_slivers {
+        pos_plines.retain(|r| !is_sliver(&r.pline, pos_equal_eps));
+        neg_plines.retain(|r| !is_sliver(&r.pline, pos_equal_eps));
+    }
+
+    match options.touching_mode {
+        BooleanTouchingMode::AsStitched => {}
+        BooleanTouchingMode::Merge => {
+            let merged = merge_touching_plines(pos_plines, neg_plines, pos_equal_eps);
+            pos_plines = merged.0;
+            neg_plines = merged.1;
+        }
+        BooleanTouchingMode::Separate => {
+            insert_touching_vertexes(&mut pos_plines, pos_equal_eps);
+            insert_touching_vertexes(&mut neg_plines, pos_equal_eps);
+            let mut separated_pos = Vec::with_capacity(pos_plines.len());
+            let mut separated_neg = Vec::with_capacity(neg_plines.len());
+            for r in pos_plines {
+                split_touching_pline(r, &mut separated_pos, &mut separated_neg, pos_equal_eps);
+            }
+            for r in neg_plines {
+                split_touching_pline(r, &mut separated_neg, &mut separated_pos, pos_equal_eps);
+            }
+            // splitting may leave zero area loops (e.g. a spur touching the polyline)
+            if options.drop_slivers {
+                separated_pos.retain(|r| !is_sliver(&r.pline, pos_equal_eps));
+                separated_neg.retain(|r| !is_sliver(&r.pline, pos_equal_eps));
+            }
+            pos_plines = separated_pos;
+            neg_plines = separated_neg;
+        }
     }
+
+    BooleanResult::new(pos_plines, neg_plines)
+}
+
+/// Returns true if the closed `pline` has (near) zero area relative to its length.
+fn is_sliver<T>(pline: &Polyline<T>, pos_equal_eps: T) -> bool
+where
+    T: Real,
+{
+    pline.area().abs() < pos_equal_eps * pline.path_length()
+}
+
+/// Test if the segments of `pline1` and `pline2` joined at vertexes `i` and `j` (at the same
+/// position) include a segment shared by both polylines.
+fn shares_edge_at<T>(
+    pline1: &Polyline<T>,
+    i: usize,
+    pline2: &Polyline<T>,
+    j: usize,
+    pos_equal_eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let segs_at = |pline: &Polyline<T>, k: usize| {
+        let prev = pline.prev_wrapping_index(k);
+        let next = pline.next_wrapping_index(k);
+        [(pline[prev], pline[k]), (pline[k], pline[next])]
+    };
+
+    let same_seg = |(u1, u2): (PlineVertex<T>, PlineVertex<T>),
+                    (w1, w2): (PlineVertex<T>, PlineVertex<T>)| {
+        let ends_match = (u1.pos().fuzzy_eq_eps(w1.pos(), pos_equal_eps)
+            && u2.pos().fuzzy_eq_eps(w2.pos(), pos_equal_eps))
+            || (u1.pos().fuzzy_eq_eps(w2.pos(), pos_equal_eps)
+                && u2.pos().fuzzy_eq_eps(w1.pos(), pos_equal_eps));
+        ends_match && seg_midpoint(u1, u2).fuzzy_eq_eps(seg_midpoint(w1, w2), pos_equal_eps)
+    };
+
+    let segs1 = segs_at(pline1, i);
+    let segs2 = segs_at(pline2, j);
+    segs1
+        .iter()
+        .any(|&seg1| segs2.iter().any(|&seg2| same_seg(seg1, seg2)))
+}
+
+/// Insert a vertex into the result polylines at every point where a vertex of a polyline touches
+/// the middle of a segment (of another polyline or a non adjacent segment of the same polyline) so
+/// all touching points are at vertexes of both polylines.
+fn insert_touching_vertexes<T>(plines: &mut [BooleanResultPline<T>], pos_equal_eps: T)
+where
+    T: Real,
+{
+    let segments: Vec<(usize, usize)> = plines
+        .iter()
+        .enumerate()
+        .flat_map(|(p, r)| r.pline.iter_segment_indexes().map(move |(i, _)| (p, i)))
+        .collect();
+
+    if segments.is_empty() {
+        return;
+    }
+
+    let segment_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(segments.len());
+        for &(p, i) in segments.iter() {
+            let pline = &plines[p].pline;
+            let bb = seg_fast_approx_bounding_box(pline[i], pline[pline.next_wrapping_index(i)]);
+            builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut stations: Vec<Vec<PlineStation<T>>> = vec![Vec::new(); plines.len()];
+    let mut query_stack = Vec::new();
+    for (a, r) in plines.iter().enumerate() {
+        for (i, v) in r.pline.iter().enumerate() {
+            let point = v.pos();
+            for k in segment_index.query_with_stack(
+                point.x - pos_equal_eps,
+                point.y - pos_equal_eps,
+                point.x + pos_equal_eps,
+                point.y + pos_equal_eps,
+                &mut query_stack,
+            ) {
+                let (b, s) = segments[k];
+                let pline = &plines[b].pline;
+                let next = pline.next_wrapping_index(s);
+                if b == a && (s == i || next == i) {
+                    // segments adjacent to the vertex
+                    continue;
+                }
+
+                let (v1, v2) = (pline[s], pline[next]);
+                let closest = seg_closest_point(v1, v2, point);
+                if !closest.fuzzy_eq_eps(point, pos_equal_eps)
+                    || closest.fuzzy_eq_eps(v1.pos(), pos_equal_eps)
+                    || closest.fuzzy_eq_eps(v2.pos(), pos_equal_eps)
+                {
+                    continue;
+                }
+
+                let split = seg_split_at_point(v1, v2, closest, pos_equal_eps);
+                stations[b].push(PlineStation {
+                    seg_start_index: s,
+                    length: seg_length(split.updated_start, split.split_vertex),
+                    point: closest,
+                    tangent: seg_tangent_vector(v1, v2, closest).normalize(),
+                });
+            }
+        }
+    }
+
+    let mut split_options = PlineSplitOptions::new();
+    split_options.pos_equal_eps = pos_equal_eps;
+    for (r, pline_stations) in plines.iter_mut().zip(stations) {
+        if !pline_stations.is_empty() {
+            r.pline = r.pline.insert_stations_opt(&pline_stations, &split_options);
+        }
+    }
+}
+
+/// Merge the result polylines that touch each other at a point into single polylines (touching
+/// themselves at the point), returning the merged positive and negative polylines. Polylines
+/// sharing an edge at the point are not merged.
+///
+/// A positive and negative polyline touching (e.g. a hole touching its outer boundary) are merged
+/// into the polyline with the larger absolute area (the enclosing polyline) with the enclosed
+/// polyline traversed in the opposite direction, polylines of the same sign are merged keeping the
+/// same direction. Zero area polylines (slivers) are never merged.
+fn merge_touching_plines<T>(
+    pos_plines: Vec<BooleanResultPline<T>>,
+    neg_plines: Vec<BooleanResultPline<T>>,
+    pos_equal_eps: T,
+) -> (Vec<BooleanResultPline<T>>, Vec<BooleanResultPline<T>>)
+where
+    T: Real,
+{
+    let mut is_pos: Vec<bool> = pos_plines
+        .iter()
+        .map(|_| true)
+        .chain(neg_plines.iter().map(|_| false))
+        .collect();
+    let mut plines: Vec<BooleanResultPline<T>> = pos_plines.into_iter().chain(neg_plines).collect();
+    insert_touching_vertexes(&mut plines, pos_equal_eps);
+
+    while plines.len() > 1 {
+        let vertexes: Vec<(usize, usize)> = plines
+            .iter()
+            .enumerate()
+            .flat_map(|(p, r)| (0..r.pline.len()).map(move |i| (p, i)))
+            .collect();
+
+        let vertex_index = {
+            let mut builder = StaticAABB2DIndexBuilder::new(vertexes.len());
+            for &(p, i) in vertexes.iter() {
+                let v = plines[p].pline[i];
+                builder.add(v.x, v.y, v.x, v.y);
+            }
+            builder.build().unwrap()
+        };
+
+        let slivers: Vec<bool> = plines
+            .iter()
+            .map(|r| is_sliver(&r.pline, pos_equal_eps))
+            .collect();
+
+        let mut query_stack = Vec::new();
+        let mut touching = None;
+        'search: for &(a, i) in vertexes.iter() {
+            if slivers[a] {
+                continue;
+            }
+            let v = plines[a].pline[i];
+            for k in vertex_index.query_with_stack(
+                v.x - pos_equal_eps,
+                v.y - pos_equal_eps,
+                v.x + pos_equal_eps,
+                v.y + pos_equal_eps,
+                &mut query_stack,
+            ) {
+                let (b, j) = vertexes[k];
+                if b > a
+                    && !slivers[b]
+                    && !shares_edge_at(&plines[a].pline, i, &plines[b].pline, j, pos_equal_eps)
+                {
+                    touching = Some((a, i, b, j));
+                    break 'search;
+                }
+            }
+        }
+
+        let (a, i, b, j) = match touching {
+            Some(t) => t,
+            None => break,
+        };
+
+        // merge into the enclosing polyline
+        let ((t, i), (o, j)) = if plines[a].pline.area().abs() >= plines[b].pline.area().abs() {
+            ((a, i), (b, j))
+        } else {
+            ((b, j), (a, i))
+        };
+
+        let same_sign = is_pos[t] == is_pos[o];
+        let mut other = plines.swap_remove(o);
+        is_pos.swap_remove(o);
+        // target may have been moved by the swap remove
+        let t = if t == plines.len() { o } else { t };
+        let target = &mut plines[t];
+        let touch_pos = target.pline[i].pos();
+
+        let target_is_neg = target.pline.area() < T::zero();
+        let other_is_neg = other.pline.area() < T::zero();
+        if (target_is_neg == other_is_neg) != same_sign {
+            other.pline.invert_direction();
+        }
+
+        // vertex index may have changed from inverting the direction
+        let j = if other.pline[j].pos().fuzzy_eq_eps(touch_pos, pos_equal_eps) {
+            j
+        } else {
+            other
+                .pline
+                .iter()
+                .position(|v| v.pos().fuzzy_eq_eps(touch_pos, pos_equal_eps))
+                .unwrap()
+        };
+
+        let n = target.pline.len();
+        let m = other.pline.len();
+        let mut merged = Polyline::with_capacity(n + m + 1, true);
+        for k in 0..i {
+            merged.add_vertex(target.pline[k]);
+        }
+        merged.add_vertex(PlineVertex::from_vector2(touch_pos, other.pline[j].bulge));
+        for k in 1..m {
+            merged.add_vertex(other.pline[(j + k) % m]);
+        }
+        for k in i..n {
+            merged.add_vertex(target.pline[k]);
+        }
+
+        target.pline = merged;
+        target.subslices.extend(other.subslices);
+    }
+
+    let mut merged_pos = Vec::new();
+    let mut merged_neg = Vec::new();
+    for (r, pos) in plines.into_iter().zip(is_pos) {
+        if pos {
+            merged_pos.push(r);
+        } else {
+            merged_neg.push(r);
+        }
+    }
+
+    (merged_pos, merged_neg)
+}
+
+/// Split a result polyline touching itself at vertexes into separate closed polylines. Loops with
+/// the same orientation as the polyline are added to `same`, loops with the opposite orientation
+/// are added to `opposite`.
+///
+/// The loops split off have no subslices (the subslices of `result_pline` are dropped), a polyline
+/// that does not touch itself is added to `same` unchanged.
+fn split_touching_pline<T>(
+    result_pline: BooleanResultPline<T>,
+    same: &mut Vec<BooleanResultPline<T>>,
+    opposite: &mut Vec<BooleanResultPline<T>>,
+    pos_equal_eps: T,
+) where
+    T: Real,
+{
+    let pline = &result_pline.pline;
+    let n = pline.len();
+
+    // find the vertexes at the same position as another vertex
+    let vertex_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(n);
+        for v in pline.iter() {
+            builder.add(v.x, v.y, v.x, v.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut query_stack = Vec::new();
+    let is_touching: Vec<bool> = pline
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            vertex_index
+                .query_with_stack(
+                    v.x - pos_equal_eps,
+                    v.y - pos_equal_eps,
+                    v.x + pos_equal_eps,
+                    v.y + pos_equal_eps,
+                    &mut query_stack,
+                )
+                .iter()
+                .any(|&j| j != i)
+        })
+        .collect();
+
+    if !is_touching.iter().any(|&t| t) {
+        same.push(result_pline);
+        return;
+    }
+
+    // walk the vertexes, each time a touching position is visited again the loop since the last
+    // visit is split off
+    let mut loops = Vec::new();
+    let mut stack: Vec<PlineVertex<T>> = Vec::with_capacity(n);
+    let mut touching_in_stack: Vec<usize> = Vec::new();
+    for (i, &v) in pline.iter().enumerate() {
+        if is_touching[i] {
+            if let Some(k) = touching_in_stack
+                .iter()
+                .position(|&s| stack[s].pos().fuzzy_eq_eps(v.pos(), pos_equal_eps))
+            {
+                let start = touching_in_stack[k];
+                touching_in_stack.truncate(k);
+                let mut sub_loop = Polyline::with_capacity(stack.len() - start, true);
+                for u in stack.drain(start..) {
+                    sub_loop.add_vertex(u);
+                }
+                if sub_loop.len() > 1 {
+                    loops.push(sub_loop);
+                }
+            }
+            touching_in_stack.push(stack.len());
+        }
+        stack.push(v);
+    }
+
+    if stack.len() > 1 {
+        let mut sub_loop = Polyline::with_capacity(stack.len(), true);
+        for u in stack {
+            sub_loop.add_vertex(u);
+        }
+        loops.push(sub_loop);
+    }
+
+    if loops.len() < 2 {
+        same.push(result_pline);
+        return;
+    }
+
+    let is_neg = pline.area() < T::zero();
+    for sub_loop in loops {
+        let area = sub_loop.area();
+        let r = BooleanResultPline::new(sub_loop, Vec::new());
+        if area != T::zero() && (area < T::zero()) != is_neg {
+            opposite.push(r);
+        } else {
+            same.push(r);
+        }
+    }
+}
+
+/// Perform boolean operation between two shapes (each shape is a region with holes) using
+/// parameters given.
+///
+/// Every loop of `shape1` is processed against every loop of `shape2` with overlapping extents,
+/// each loop is then sliced at all of its intersects and the slices are kept based on whether they
+/// lie inside or outside the region of the other shape. Coincident (overlapping) slices are kept
+/// once if they bound the result region. The kept slices are stitched together into the closed
+/// polylines of a new [Shape].
+pub fn shape_boolean<T>(
+    shape1: &Shape<T>,
+    shape2: &Shape<T>,
+    operation: BooleanOp,
+    options: &ShapeBooleanOptions<T>,
+) -> Shape<T>
+where
+    T: Real,
+{
+    let mut dangling_count = 0;
+    shape_boolean_counting_dangling(shape1, shape2, operation, options, &mut dangling_count)
+}
+
+/// Same as [shape_boolean] but validates the polylines of the input shapes and returns an error
+/// if slices failed to stitch together into closed polylines rather than discarding them.
+pub fn try_shape_boolean<T>(
+    shape1: &Shape<T>,
+    shape2: &Shape<T>,
+    operation: BooleanOp,
+    options: &ShapeBooleanOptions<T>,
+) -> Result<Shape<T>, PlineOpError>
+where
+    T: Real,
+{
+    for (input, shape) in [shape1, shape2].iter().enumerate() {
+        for pline in shape.iter_plines() {
+            pline.validate_as_input(input, options.pos_equal_eps)?;
+            if !pline.is_closed() {
+                return Err(PlineOpError::OpenInput { input });
+            }
+        }
+    }
+
+    let mut dangling_count = 0;
+    let result =
+        shape_boolean_counting_dangling(shape1, shape2, operation, options, &mut dangling_count);
+    if dangling_count > 0 {
+        return Err(PlineOpError::DanglingSlices {
+            count: dangling_count,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Loops of two shapes sliced at all of their intersects with each other for boolean operations,
+/// see [slice_shapes_for_boolean].
+struct ShapeBooleanSlices<'a, T>
+where
+    T: Real,
+{
+    loops1: Vec<&'a Polyline<T>>,
+    loops2: Vec<&'a Polyline<T>>,
+    pair_infos: Vec<(usize, usize, ProcessForBooleanResult<T>)>,
+    /// For each loop completely overlapping a loop of the other shape whether the loops have
+    /// opposing directions.
+    coincident1: Vec<Option<bool>>,
+    coincident2: Vec<Option<bool>>,
+    /// All the non-overlapping slices of both shapes.
+    pruned: PrunedLoopSlices<T>,
+    /// Whether each slice in `pruned` (or each loop not sliced) is inside the other shape.
+    slice_inside_other: Vec<bool>,
+    loop1_inside_other: Vec<bool>,
+    loop2_inside_other: Vec<bool>,
+}
+
+/// Point on the first segment of `slice` (same point tested when pruning slices).
+fn point_on_slice<T>(slice: &BooleanPlineSlice<T>, source: &Polyline<T>) -> Vector2<T>
+where
+    T: Real,
+{
+    let end = if slice.end_index_offset == 0 {
+        PlineVertex::from_vector2(slice.end_point, T::zero())
+    } else {
+        source[source.next_wrapping_index(slice.start_index)]
+    };
+
+    seg_midpoint(slice.updated_start, end)
+}
+
+/// Process every pair of loops of `shape1` and `shape2` with overlapping extents and slice all the
+/// loops at their intersects once, recording which side of the other shape every slice and every
+/// loop not sliced lies on (so any boolean operation can be formed from them).
+fn slice_shapes_for_boolean<'a, T>(
+    shape1: &'a Shape<T>,
+    shape2: &'a Shape<T>,
+    pos_equal_eps: T,
+) -> ShapeBooleanSlices<'a, T>
+where
+    T: Real,
+{
+    let loops1: Vec<&Polyline<T>> = shape1.iter_plines().collect();
+    let loops2: Vec<&Polyline<T>> = shape2.iter_plines().collect();
+
+    // loops that completely overlap each other have no other intersects (loops within a shape do
+    // not intersect)
+    let mut pair_infos = Vec::new();
+    let mut coincident1 = vec![None; loops1.len()];
+    let mut coincident2 = vec![None; loops2.len()];
+    if let Some(index2) = &shape2.plines_index {
+        let mut query_stack = Vec::new();
+        for (i, &pline1) in loops1.iter().enumerate() {
+            let index1 = &shape1.get_indexed_pline(i).spatial_index;
+            let candidates = index2.query_with_stack(
+                index1.min_x(),
+                index1.min_y(),
+                index1.max_x(),
+                index1.max_y(),
+                &mut query_stack,
+            );
+            for j in candidates {
+                let boolean_info = process_for_boolean(pline1, loops2[j], index1, pos_equal_eps);
+                if boolean_info.completely_overlapping() {
+                    coincident1[i] = Some(boolean_info.opposing_directions());
+                    coincident2[j] = Some(boolean_info.opposing_directions());
+                } else if boolean_info.any_intersects() {
+                    pair_infos.push((i, j, boolean_info));
+                }
+            }
+        }
+    }
+
+    // every slice is kept and divided by side of the other shape below
+    let pruned = {
+        let pair_refs: Vec<_> = pair_infos
+            .iter()
+            .map(|(i, j, info)| (*i, *j, info))
+            .collect();
+        prune_loop_slices(
+            &loops1,
+            &loops2,
+            &pair_refs,
+            &mut |_| true,
+            &mut |_| true,
+            pos_equal_eps,
+        )
+    };
+
+    let slice_inside_other = pruned
+        .slices
+        .iter()
+        .zip(pruned.slice_loops.iter())
+        .enumerate()
+        .map(|(i, (slice, &loop_index))| {
+            if i < pruned.start_of_loops2_slices {
+                shape2.contains_point(point_on_slice(slice, loops1[loop_index]))
+            } else {
+                shape1.contains_point(point_on_slice(slice, loops2[loop_index]))
+            }
+        })
+        .collect();
+
+    let loop_inside = |pline: &Polyline<T>, other: &Shape<T>| {
+        other.contains_point(seg_midpoint(pline[0], pline[1]))
+    };
+    let loop1_inside_other = loops1.iter().map(|p| loop_inside(p, shape2)).collect();
+    let loop2_inside_other = loops2.iter().map(|p| loop_inside(p, shape1)).collect();
+
+    ShapeBooleanSlices {
+        loops1,
+        loops2,
+        pair_infos,
+        coincident1,
+        coincident2,
+        pruned,
+        slice_inside_other,
+        loop1_inside_other,
+        loop2_inside_other,
+    }
+}
+
+fn shape_boolean_counting_dangling<T>(
+    shape1: &Shape<T>,
+    shape2: &Shape<T>,
+    operation: BooleanOp,
+    options: &ShapeBooleanOptions<T>,
+    dangling_count: &mut usize,
+) -> Shape<T>
+where
+    T: Real,
+{
+    let sliced = slice_shapes_for_boolean(shape1, shape2, options.pos_equal_eps);
+    let plines = match operation {
+        BooleanOp::Xor => {
+            // both differences are formed from the same slices
+            let mut result =
+                stitch_shape_boolean(&sliced, BooleanOp::Not, false, options, dangling_count);
+            result.extend(stitch_shape_boolean(
+                &sliced,
+                BooleanOp::Not,
+                true,
+                options,
+                dangling_count,
+            ));
+            result
+        }
+        op => stitch_shape_boolean(&sliced, op, false, options, dangling_count),
+    };
+
+    // result region is to the left of every polyline so orientation gives the outer loops and holes
+    Shape::from_plines(plines)
+}
+
+/// Stitch the closed polylines of the result of `operation` (`Or`, `And` or `Not`) from the
+/// slices of two shapes (with the result region to the left of each polyline), if `swapped` is
+/// true then the second shape is the first operand.
+fn stitch_shape_boolean<T>(
+    sliced: &ShapeBooleanSlices<'_, T>,
+    operation: BooleanOp,
+    swapped: bool,
+    options: &ShapeBooleanOptions<T>,
+    dangling_count: &mut usize,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    // whether to keep slices inside (or outside) the other shape and whether second operand slices
+    // are inverted, all loops have their region to the left so for Or and And directions are kept
+    let (keep1_inside, keep2_inside, invert2) = match operation {
+        BooleanOp::Or => (false, false, false),
+        BooleanOp::And => (true, true, false),
+        BooleanOp::Not => (false, true, true),
+        BooleanOp::Xor => unreachable!("xor is stitched as two not operations"),
+    };
+
+    // overlapping slices (and coincident loops) bound the result if both regions are on the same
+    // side for Or and And, or on opposite sides for Not
+    let keep_overlapping = |opposing_directions: bool| opposing_directions == invert2;
+
+    let pruned = &sliced.pruned;
+    let loops1_range = 0..pruned.start_of_loops2_slices;
+    let loops2_range = pruned.start_of_loops2_slices..pruned.slices.len();
+    let (first, second) = if swapped {
+        (
+            (
+                &sliced.loops2,
+                &sliced.coincident2,
+                &pruned.unsliced_loops2,
+                &sliced.loop2_inside_other,
+                loops2_range,
+            ),
+            (
+                &sliced.loops1,
+                &pruned.unsliced_loops1,
+                &sliced.loop1_inside_other,
+                loops1_range,
+            ),
+        )
+    } else {
+        (
+            (
+                &sliced.loops1,
+                &sliced.coincident1,
+                &pruned.unsliced_loops1,
+                &sliced.loop1_inside_other,
+                loops1_range,
+            ),
+            (
+                &sliced.loops2,
+                &pruned.unsliced_loops2,
+                &sliced.loop2_inside_other,
+                loops2_range,
+            ),
+        )
+    };
+    let (loops1, coincident1, unsliced1, loop1_inside, slices1_range) = first;
+    let (loops2, unsliced2, loop2_inside, slices2_range) = second;
+
+    let mut whole_plines = Vec::new();
+    for (i, c) in coincident1.iter().enumerate() {
+        if matches!(c, Some(opposing_directions) if keep_overlapping(*opposing_directions)) {
+            whole_plines.push(loops1[i].clone());
+        }
+    }
+    for &i in unsliced1.iter() {
+        if coincident1[i].is_none() && loop1_inside[i] == keep1_inside {
+            whole_plines.push(loops1[i].clone());
+        }
+    }
+    for &i in unsliced2.iter() {
+        // coincident loops are only kept from the first operand
+        let is_coincident = if swapped {
+            sliced.coincident1[i].is_some()
+        } else {
+            sliced.coincident2[i].is_some()
+        };
+        if !is_coincident && loop2_inside[i] == keep2_inside {
+            let mut whole = loops2[i].clone();
+            if invert2 {
+                whole.invert_direction();
+            }
+            whole_plines.push(whole);
+        }
+    }
+
+    let mut slices = Vec::new();
+    let mut sources: Vec<&Polyline<T>> = Vec::new();
+    for i in slices1_range {
+        if sliced.slice_inside_other[i] == keep1_inside {
+            slices.push(pruned.slices[i]);
+            sources.push(loops1[pruned.slice_loops[i]]);
+        }
+    }
+
+    let start_of_pline2_slices = slices.len();
+    for i in slices2_range {
+        if sliced.slice_inside_other[i] == keep2_inside {
+            let mut slice = pruned.slices[i];
+            slice.inverted = invert2;
+            slices.push(slice);
+            sources.push(loops2[pruned.slice_loops[i]]);
+        }
+    }
+
+    // only one copy of the overlapping slices is kept, oriented to follow the first operand loop
+    // (overlapping slices are always constructed from the loop of the second shape)
+    let start_of_pline1_overlapping_slices = slices.len();
+    for (_, j, boolean_info) in sliced.pair_infos.iter() {
+        let source = sliced.loops2[*j];
+        for overlapping_slice in boolean_info.overlapping_slices.iter() {
+            if keep_overlapping(overlapping_slice.opposing_directions) {
+                let invert = !swapped && overlapping_slice.opposing_directions;
+                slices.push(BooleanPlineSlice::from_overlapping(
+                    source,
+                    overlapping_slice,
+                    invert,
+                ));
+                sources.push(source);
+            }
+        }
+    }
+    let start_of_pline2_overlapping_slices = slices.len();
+
+    let get_source = |i: usize| sources[i];
+    let stitched = if invert2 {
+        stitch_slices_with_sources(
+            &slices,
+            get_source,
+            &NotXorStitchSelector::new(
+                start_of_pline2_slices,
+                start_of_pline1_overlapping_slices,
+                start_of_pline2_overlapping_slices,
+            ),
+            options.slice_join_eps,
+            options.pos_equal_eps,
+            dangling_count,
+        )
+    } else {
+        stitch_slices_with_sources(
+            &slices,
+            get_source,
+            &OrAndStitchSelector::new(
+                start_of_pline2_slices,
+                start_of_pline1_overlapping_slices,
+                start_of_pline2_overlapping_slices,
+            ),
+            options.slice_join_eps,
+            options.pos_equal_eps,
+            dangling_count,
+        )
+    };
+
+    whole_plines.extend(stitched.into_iter().map(|r| r.pline));
+    whole_plines
+}
+
+/// Union all the closed polylines given into a single [Shape].
+///
+/// All the polyline extents are loaded into one spatial index which is used to find the groups of
+/// polylines with overlapping extents (connected components). Each group is unioned by repeatedly
+/// performing [shape_boolean] between pairs of shapes (divide and conquer) so each polyline is
+/// only processed in a logarithmic number of boolean operations. Polylines that do not overlap any
+/// other polyline are moved into the result without being processed.
+///
+/// Open polylines and polylines with less than 2 vertexes are ignored.
+pub fn batch_union<T, I>(plines: I, options: &ShapeBooleanOptions<T>) -> Shape<T>
+where
+    T: Real,
+    I: IntoIterator<Item = Polyline<T>>,
+{
+    let mut plines: Vec<Option<Polyline<T>>> = plines
+        .into_iter()
+        .filter(|p| p.is_closed() && p.len() > 1)
+        .map(Some)
+        .collect();
+
+    let extents: Vec<_> = plines
+        .iter()
+        .flatten()
+        .map(|p| p.extents().unwrap())
+        .collect();
+    let extents_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(extents.len());
+        for e in extents.iter() {
+            builder.add(e.min_x, e.min_y, e.max_x, e.max_y);
+        }
+
+        match builder.build() {
+            Ok(index) => index,
+            Err(_) => return Shape::empty(),
+        }
+    };
+
+    let mut result_plines = Vec::with_capacity(plines.len());
+    let mut visited = vec![false; plines.len()];
+    let mut group = Vec::new();
+    let mut query_stack = Vec::new();
+    for i in 0..plines.len() {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        // find all polylines connected by overlapping extents
+        group.clear();
+        group.push(i);
+        let mut k = 0;
+        while k < group.len() {
+            let e = extents[group[k]];
+            for j in
+                extents_index.query_with_stack(e.min_x, e.min_y, e.max_x, e.max_y, &mut query_stack)
+            {
+                if !visited[j] {
+                    visited[j] = true;
+                    group.push(j);
+                }
+            }
+            k += 1;
+        }
+
+        if group.len() == 1 {
+            result_plines.push(plines[i].take().unwrap());
+            continue;
+        }
+
+        // pairs of shapes are unioned in the order found (neighbors are found close together)
+        let mut shapes: Vec<Shape<T>> = group
+            .iter()
+            .map(|&j| Shape::from_unoriented_plines(plines[j].take()))
+            .collect();
+        while shapes.len() > 1 {
+            let mut next_shapes = Vec::with_capacity(shapes.len() / 2 + 1);
+            let mut shapes_iter = shapes.into_iter();
+            while let Some(shape1) = shapes_iter.next() {
+                match shapes_iter.next() {
+                    Some(shape2) => {
+                        next_shapes.push(shape_boolean(&shape1, &shape2, BooleanOp::Or, options))
+                    }
+                    None => next_shapes.push(shape1),
+                }
+            }
+            shapes = next_shapes;
+        }
+
+        result_plines.extend(shapes.pop().unwrap().into_plines());
+    }
+
+    Shape::from_unoriented_plines(result_plines)
 }
@@ -0,0 +1,271 @@
+use super::pline_intersects::find_intersects;
+use crate::{
+    core::{
+        math::{dist_squared, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        seg_closest_point, seg_midpoint, seg_split_at_point, FindIntersectsOptions, OpenPlineSlice,
+        PlineClipOptions, PlineVertex, Polyline, PolylineSlice, Shape,
+    },
+};
+use static_aabb2d_index::StaticAABB2DIndex;
+
+/// Collect all the points where `pline` intersects or starts/stops overlapping the `region_loops`,
+/// returned as (segment index, point) pairs sorted along the polyline direction.
+///
+/// Points at the end of a segment are recorded using the index of the next segment (matching the
+/// convention of [find_intersects]) except at the very end of the polyline.
+fn collect_split_points<T>(
+    pline: &Polyline<T>,
+    region_loops: &[&Polyline<T>],
+    pline_aabb_index: &StaticAABB2DIndex<T>,
+    pos_equal_eps: T,
+) -> Vec<(usize, Vector2<T>)>
+where
+    T: Real,
+{
+    let mut points = Vec::new();
+    let options = FindIntersectsOptions {
+        pline1_aabb_index: Some(pline_aabb_index),
+        pos_equal_eps,
+    };
+
+    for region in region_loops.iter() {
+        let intrs = find_intersects(pline, region, &options);
+        points.extend(
+            intrs
+                .basic_intersects
+                .iter()
+                .map(|intr| (intr.start_index1, intr.point)),
+        );
+        for intr in intrs.overlapping_intersects.iter() {
+            points.push((intr.start_index1, intr.point1));
+            points.push((intr.start_index1, intr.point2));
+        }
+    }
+
+    let last_seg_index = pline.len() - 2;
+    for (seg_index, point) in points.iter_mut() {
+        if *seg_index < last_seg_index
+            && point.fuzzy_eq_eps(pline[*seg_index + 1].pos(), pos_equal_eps)
+        {
+            *seg_index += 1;
+        }
+    }
+
+    points.sort_unstable_by(|(i1, p1), (i2, p2)| {
+        i1.cmp(i2).then_with(|| {
+            let start_pos = pline[*i1].pos();
+            dist_squared(*p1, start_pos)
+                .partial_cmp(&dist_squared(*p2, start_pos))
+                .unwrap()
+        })
+    });
+
+    points.dedup_by(|(i1, p1), (i2, p2)| i1 == i2 && p1.fuzzy_eq_eps(*p2, pos_equal_eps));
+    points
+}
+
+/// Returns true if `point` lies on (within `eps` of) any of the `region` polylines.
+fn point_on_region_boundary<T>(
+    region: &Shape<T>,
+    point: Vector2<T>,
+    eps: T,
+    query_stack: &mut Vec<usize>,
+) -> bool
+where
+    T: Real,
+{
+    let plines_index = match region.plines_index.as_ref() {
+        Some(i) => i,
+        None => return false,
+    };
+
+    plines_index
+        .query_with_stack(
+            point.x - eps,
+            point.y - eps,
+            point.x + eps,
+            point.y + eps,
+            query_stack,
+        )
+        .into_iter()
+        .any(|pline_index| {
+            let ipline = region.get_indexed_pline(pline_index);
+            let loop_pline = &ipline.polyline;
+            ipline
+                .spatial_index
+                .query_with_stack(
+                    point.x - eps,
+                    point.y - eps,
+                    point.x + eps,
+                    point.y + eps,
+                    query_stack,
+                )
+                .into_iter()
+                .any(|i| {
+                    let closest = seg_closest_point(
+                        loop_pline[i],
+                        loop_pline[loop_pline.next_wrapping_index(i)],
+                        point,
+                    );
+                    closest.fuzzy_eq_eps(point, eps)
+                })
+        })
+}
+
+/// Returns the sum of the winding numbers of the `region` polylines at `point`, only the
+/// polylines whose extents contain the point are visited (using the shape spatial indexes).
+fn region_winding_number<T>(
+    region: &Shape<T>,
+    point: Vector2<T>,
+    query_stack: &mut Vec<usize>,
+) -> i32
+where
+    T: Real,
+{
+    let plines_index = match region.plines_index.as_ref() {
+        Some(i) => i,
+        None => return 0,
+    };
+
+    plines_index
+        .query_with_stack(point.x, point.y, point.x, point.y, query_stack)
+        .into_iter()
+        .map(|i| {
+            let ipline = region.get_indexed_pline(i);
+            ipline
+                .polyline
+                .winding_number_indexed(point, &ipline.spatial_index, query_stack)
+        })
+        .sum()
+}
+
+/// Clip the open polyline `pline` against the `region` shape (point is inside the region if the
+/// sum of the shape polyline winding numbers is not zero).
+///
+/// The polyline is split at all the intersects found with [find_intersects] and each
+/// [OpenPlineSlice] is classified by the winding number at a point along the slice (only
+/// visiting the region polylines found by the shape spatial indexes). Slices that
+/// overlap the region boundary are treated as inside (the region is closed). Consecutive kept
+/// slices are joined, the kept pieces are returned as open polylines in the order they appear
+/// along `pline`.
+///
+/// If `pline` is closed it is treated as an open path starting and ending at its first vertex.
+pub fn clip_open<T>(
+    pline: &Polyline<T>,
+    region: &Shape<T>,
+    keep_inside: bool,
+    options: &PlineClipOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    if pline.len() < 2 {
+        return Vec::new();
+    }
+
+    if pline.is_closed() {
+        let mut open = pline.clone();
+        open.add_vertex(PlineVertex::from_vector2(pline[0].pos(), T::zero()));
+        open.set_is_closed(false);
+        return clip_open(
+            &open,
+            region,
+            keep_inside,
+            &PlineClipOptions {
+                pline_aabb_index: None,
+                pos_equal_eps: options.pos_equal_eps,
+            },
+        );
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let region_loops: Vec<&Polyline<T>> = region.iter_plines().collect();
+
+    let constructed_index;
+    let pline_aabb_index = if let Some(x) = options.pline_aabb_index {
+        x
+    } else {
+        constructed_index = pline.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let mut split_points =
+        collect_split_points(pline, &region_loops, pline_aabb_index, pos_equal_eps);
+    // final slice ends at the end of the polyline
+    let last_seg_index = pline.len() - 2;
+    split_points.push((last_seg_index, pline.last().unwrap().pos()));
+
+    let mut query_stack = Vec::new();
+    let mut keep_slice = |slice: &OpenPlineSlice<T>| {
+        let next_vertex = if slice.end_index_offset == 0 {
+            PlineVertex::from_vector2(slice.end_point, T::zero())
+        } else {
+            pline[slice.start_index + 1]
+        };
+        let point = seg_midpoint(slice.updated_start, next_vertex);
+        if point_on_region_boundary(region, point, pos_equal_eps, &mut query_stack) {
+            return keep_inside;
+        }
+
+        (region_winding_number(region, point, &mut query_stack) != 0) == keep_inside
+    };
+
+    let mut result = Vec::new();
+    let mut current: Option<Polyline<T>> = None;
+    let mut start_index = 0;
+    let mut updated_start = pline[0];
+    for (seg_index, point) in split_points {
+        let (slice, next_start) = if seg_index == start_index {
+            let split =
+                seg_split_at_point(updated_start, pline[start_index + 1], point, pos_equal_eps);
+            let slice = OpenPlineSlice::create_on_single_segment(
+                pline,
+                start_index,
+                split.updated_start,
+                point,
+                pos_equal_eps,
+            );
+            (slice, split.split_vertex)
+        } else {
+            let slice = OpenPlineSlice::create(
+                pline,
+                start_index,
+                point,
+                seg_index,
+                updated_start,
+                seg_index - start_index,
+                pos_equal_eps,
+            );
+            let split =
+                seg_split_at_point(pline[seg_index], pline[seg_index + 1], point, pos_equal_eps);
+            (Some(slice), split.split_vertex)
+        };
+
+        if let Some(slice) = slice {
+            if keep_slice(&slice) {
+                match current.as_mut() {
+                    Some(c) => {
+                        c.remove_last();
+                        slice.stitch_onto(pline, c, pos_equal_eps);
+                    }
+                    None => current = Some(slice.to_polyline(pline, pos_equal_eps)),
+                }
+            } else if let Some(c) = current.take() {
+                result.push(c);
+            }
+        }
+
+        // next slice starts at the split point
+        start_index = seg_index;
+        updated_start = next_start;
+    }
+
+    if let Some(c) = current {
+        result.push(c);
+    }
+
+    result
+}
@@ -0,0 +1,498 @@
+use crate::{
+    core::{
+        math::{
+            angle, bulge_from_angle, circle_circle_intr, delta_angle_signed, line_circle_intr,
+            line_line_intr, normalize_radians, point_from_parametric, point_on_circle,
+            CircleCircleIntr, LineCircleIntr, LineLineIntr, Vector2,
+        },
+        traits::Real,
+    },
+    polyline::{
+        seg_arc_radius_and_center, seg_closest_point, seg_length, seg_tangent_vector,
+        PlineCornerError, PlineCornerOptions, PlineCornerResult, PlineVertex, Polyline,
+    },
+};
+
+/// Operation to perform at a polyline corner.
+#[derive(Debug, Copy, Clone)]
+pub enum CornerOp<T>
+where
+    T: Real,
+{
+    /// Round the corner with a tangent arc of the radius given.
+    Fillet(T),
+    /// Cut the corner with a line starting and ending at the distance given (measured along the
+    /// segments) from the corner.
+    Chamfer(T),
+}
+
+/// Result of cutting a corner, `start` lies on the segment ending at the corner vertex, `end` lies
+/// on the segment starting at the corner vertex and `bulge` is the bulge of the segment connecting
+/// `start` to `end`.
+#[derive(Debug, Copy, Clone)]
+struct CornerCut<T>
+where
+    T: Real,
+{
+    start: Vector2<T>,
+    end: Vector2<T>,
+    bulge: T,
+}
+
+/// Line or circle that a polyline segment lies on.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum SegCarrier<T>
+where
+    T: Real,
+{
+    Line(Vector2<T>, Vector2<T>),
+    Circle(T, Vector2<T>),
+}
+
+/// Compute the signed change in direction (radians) at vertex index `k` of `polyline`, positive
+/// for a left turn and negative for a right turn. Returns `None` if the vertex is the start or end
+/// of an open polyline.
+pub fn corner_turn_angle<T>(polyline: &Polyline<T>, k: usize) -> Option<T>
+where
+    T: Real,
+{
+    let ln = polyline.len();
+    if ln < 2 || k >= ln || (!polyline.is_closed() && (k == 0 || k == ln - 1)) {
+        return None;
+    }
+
+    let prev = polyline.prev_wrapping_index(k);
+    let next = polyline.next_wrapping_index(k);
+    let v = polyline[k];
+    let t1 = seg_tangent_vector(polyline[prev], v, v.pos());
+    let t2 = seg_tangent_vector(v, polyline[next], v.pos());
+    Some(t1.perp_dot(t2).atan2(t1.dot(t2)))
+}
+
+/// Compute the bulge of the part of the segment `v1` to `v2` going from `start` to `end` (both
+/// points must lie on the segment).
+fn sub_seg_bulge<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, start: Vector2<T>, end: Vector2<T>) -> T
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        return T::zero();
+    }
+
+    let (_, arc_center) = seg_arc_radius_and_center(v1, v2);
+    let a1 = angle(arc_center, start);
+    let a2 = angle(arc_center, end);
+    let sweep = if v1.bulge_is_pos() {
+        normalize_radians(a2 - a1)
+    } else {
+        -normalize_radians(a1 - a2)
+    };
+
+    bulge_from_angle(sweep)
+}
+
+/// Length along the segment `v1` to `v2` going from `start` to `end`.
+fn sub_seg_length<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    start: Vector2<T>,
+    end: Vector2<T>,
+) -> T
+where
+    T: Real,
+{
+    seg_length(
+        PlineVertex::from_vector2(start, sub_seg_bulge(v1, v2, start, end)),
+        PlineVertex::from_vector2(end, T::zero()),
+    )
+}
+
+/// Offset the line or circle the segment `v1` to `v2` lies on by `offset` (positive is to the left
+/// of the segment direction), returns `None` if the offset collapses a circle.
+pub(crate) fn offset_carrier<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    offset: T,
+) -> Option<SegCarrier<T>>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let offset_v = (v2.pos() - v1.pos()).unit_perp().scale(offset);
+        return Some(SegCarrier::Line(v1.pos() + offset_v, v2.pos() + offset_v));
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let offset_radius = if v1.bulge_is_neg() {
+        radius + offset
+    } else {
+        radius - offset
+    };
+
+    if offset_radius.fuzzy_lt(T::zero()) || offset_radius.fuzzy_eq_zero() {
+        return None;
+    }
+
+    Some(SegCarrier::Circle(offset_radius, center))
+}
+
+/// Find all the intersect points between two segment carriers.
+pub(crate) fn carrier_intersects<T>(c1: SegCarrier<T>, c2: SegCarrier<T>) -> Vec<Vector2<T>>
+where
+    T: Real,
+{
+    let line_circle_points =
+        |p0: Vector2<T>, p1: Vector2<T>, radius: T, center: Vector2<T>| match line_circle_intr(
+            p0, p1, radius, center,
+        ) {
+            LineCircleIntr::NoIntersect => Vec::new(),
+            LineCircleIntr::TangentIntersect { t0 } => vec![point_from_parametric(p0, p1, t0)],
+            LineCircleIntr::TwoIntersects { t0, t1 } => vec![
+                point_from_parametric(p0, p1, t0),
+                point_from_parametric(p0, p1, t1),
+            ],
+        };
+
+    match (c1, c2) {
+        (SegCarrier::Line(p0, p1), SegCarrier::Line(u0, u1)) => {
+            match line_line_intr(p0, p1, u0, u1) {
+                LineLineIntr::TrueIntersect { seg1_t, .. }
+                | LineLineIntr::FalseIntersect { seg1_t, .. } => {
+                    vec![point_from_parametric(p0, p1, seg1_t)]
+                }
+                LineLineIntr::NoIntersect | LineLineIntr::Overlapping { .. } => Vec::new(),
+            }
+        }
+        (SegCarrier::Line(p0, p1), SegCarrier::Circle(radius, center))
+        | (SegCarrier::Circle(radius, center), SegCarrier::Line(p0, p1)) => {
+            line_circle_points(p0, p1, radius, center)
+        }
+        (SegCarrier::Circle(r1, c1), SegCarrier::Circle(r2, c2)) => {
+            match circle_circle_intr(r1, c1, r2, c2) {
+                CircleCircleIntr::NoIntersect | CircleCircleIntr::Overlapping => Vec::new(),
+                CircleCircleIntr::TangentIntersect { point } => vec![point],
+                CircleCircleIntr::TwoIntersects { point1, point2 } => vec![point1, point2],
+            }
+        }
+    }
+}
+
+/// Find the point where a circle centered at `center` touches the segment `v1` to `v2` (foot of the
+/// perpendicular for a line, closest point on the circle for an arc).
+fn tangent_point<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, center: Vector2<T>) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = (v2.pos() - v1.pos()).normalize();
+        return v1.pos() + dir.scale(dir.dot(center - v1.pos()));
+    }
+
+    let (radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+    arc_center + (center - arc_center).normalize().scale(radius)
+}
+
+/// Test if `point` (which lies on the line or circle of the segment) lies on the segment `v1` to
+/// `v2`.
+fn point_on_seg<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    seg_closest_point(v1, v2, point).fuzzy_eq_eps(point, eps)
+}
+
+/// Compute the fillet of `radius` at the corner `v` between the segments `v_prev` to `v` and `v`
+/// to `v_next`, `turn_left` indicates which way the polyline turns at `v`.
+fn fillet_cut<T>(
+    v_prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    v_next: PlineVertex<T>,
+    radius: T,
+    turn_left: bool,
+    vertex_index: usize,
+    pos_equal_eps: T,
+) -> Result<CornerCut<T>, PlineCornerError>
+where
+    T: Real,
+{
+    let no_fillet = PlineCornerError::NoFillet { vertex_index };
+    // center of the fillet arc lies on both segments offset towards the inside of the corner
+    let offset = if turn_left { radius } else { -radius };
+    let c1 = offset_carrier(v_prev, v, offset).ok_or(no_fillet)?;
+    let c2 = offset_carrier(v, v_next, offset).ok_or(no_fillet)?;
+
+    // use the candidate center closest to the corner
+    let corner = v.pos();
+    let center = carrier_intersects(c1, c2)
+        .into_iter()
+        .map(|p| (p, (p - corner).length_squared()))
+        .fold(None, |acc: Option<(Vector2<T>, T)>, (p, d)| match acc {
+            Some((_, acc_d)) if acc_d <= d => acc,
+            _ => Some((p, d)),
+        })
+        .map(|(p, _)| p)
+        .ok_or(no_fillet)?;
+
+    let start = tangent_point(v_prev, v, center);
+    let end = tangent_point(v, v_next, center);
+    if !point_on_seg(v_prev, v, start, pos_equal_eps)
+        || !point_on_seg(v, v_next, end, pos_equal_eps)
+    {
+        return Err(PlineCornerError::SegmentTooShort { vertex_index });
+    }
+
+    let a1 = angle(center, start);
+    let a2 = angle(center, end);
+    let bulge = bulge_from_angle(delta_angle_signed(a1, a2, !turn_left));
+
+    Ok(CornerCut { start, end, bulge })
+}
+
+/// Find the point at arc length `dist` from the corner along the segment `v1` to `v2`, if
+/// `from_end` is true then the distance is measured from `v2` backwards otherwise it is measured
+/// from `v1` forwards.
+fn point_at_dist_from_corner<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    dist: T,
+    from_end: bool,
+) -> Vector2<T>
+where
+    T: Real,
+{
+    let (corner, other) = if from_end {
+        (v2.pos(), v1.pos())
+    } else {
+        (v1.pos(), v2.pos())
+    };
+
+    if v1.bulge_is_zero() {
+        return corner + (other - corner).normalize().scale(dist);
+    }
+
+    let (radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+    let sweep = dist / radius;
+    // moving forward along a counter clockwise arc increases the angle
+    let forward_ccw = v1.bulge_is_pos() != from_end;
+    let a = angle(arc_center, corner);
+    let point_angle = if forward_ccw { a + sweep } else { a - sweep };
+    point_on_circle(radius, arc_center, point_angle)
+}
+
+/// Compute the chamfer at the corner `v` between the segments `v_prev` to `v` and `v` to `v_next`
+/// with the chamfer end points at `dist` from the corner (measured along the segments).
+fn chamfer_cut<T>(
+    v_prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    v_next: PlineVertex<T>,
+    dist: T,
+    vertex_index: usize,
+    pos_equal_eps: T,
+) -> Result<CornerCut<T>, PlineCornerError>
+where
+    T: Real,
+{
+    if seg_length(v_prev, v) + pos_equal_eps < dist || seg_length(v, v_next) + pos_equal_eps < dist
+    {
+        return Err(PlineCornerError::SegmentTooShort { vertex_index });
+    }
+
+    Ok(CornerCut {
+        start: point_at_dist_from_corner(v_prev, v, dist, true),
+        end: point_at_dist_from_corner(v, v_next, dist, false),
+        bulge: T::zero(),
+    })
+}
+
+/// Compute the corner cut for `op` at vertex index `k`.
+fn corner_cut<T>(
+    polyline: &Polyline<T>,
+    k: usize,
+    op: CornerOp<T>,
+    pos_equal_eps: T,
+) -> Result<CornerCut<T>, PlineCornerError>
+where
+    T: Real,
+{
+    let turn = corner_turn_angle(polyline, k)
+        .filter(|t| !t.fuzzy_eq_zero())
+        .ok_or(PlineCornerError::NotACorner { vertex_index: k })?;
+
+    let v_prev = polyline[polyline.prev_wrapping_index(k)];
+    let v = polyline[k];
+    let v_next = polyline[polyline.next_wrapping_index(k)];
+    match op {
+        CornerOp::Fillet(radius) => fillet_cut(
+            v_prev,
+            v,
+            v_next,
+            radius,
+            turn > T::zero(),
+            k,
+            pos_equal_eps,
+        ),
+        CornerOp::Chamfer(dist) => chamfer_cut(v_prev, v, v_next, dist, k, pos_equal_eps),
+    }
+}
+
+/// Fillet or chamfer (according to `op`) the corners of `polyline` at the vertex indexes given.
+///
+/// Corners are processed in the order given, a corner is skipped (and reported in the result) if
+/// the operation is not possible at the corner or if its cut overlaps the cut of a corner already
+/// processed on a shared segment. The polyline is returned unchanged if the fillet radius or
+/// chamfer distance is not positive.
+pub fn cut_corners<T, I>(
+    polyline: &Polyline<T>,
+    vertex_indexes: I,
+    op: CornerOp<T>,
+    options: &PlineCornerOptions<T>,
+) -> PlineCornerResult<T>
+where
+    T: Real,
+    I: IntoIterator<Item = usize>,
+{
+    let size = match op {
+        CornerOp::Fillet(radius) => radius,
+        CornerOp::Chamfer(dist) => dist,
+    };
+
+    if size <= T::zero() {
+        return PlineCornerResult {
+            polyline: polyline.clone(),
+            skipped: Vec::new(),
+        };
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let ln = polyline.len();
+    let mut cuts: Vec<Option<CornerCut<T>>> = vec![None; ln];
+    let mut skipped = Vec::new();
+
+    for k in vertex_indexes {
+        if k >= ln {
+            skipped.push(PlineCornerError::InvalidIndex { vertex_index: k });
+            continue;
+        }
+
+        let cut = match corner_cut(polyline, k, op, pos_equal_eps) {
+            Ok(c) => c,
+            Err(e) => {
+                skipped.push(e);
+                continue;
+            }
+        };
+
+        // test for overlap with the cuts of adjacent corners on the shared segments
+        let prev = polyline.prev_wrapping_index(k);
+        let next = polyline.next_wrapping_index(k);
+        let cuts_overlap = |v1: PlineVertex<T>, v2: PlineVertex<T>, end: Vector2<T>, start| {
+            sub_seg_length(v1, v2, v1.pos(), end) + sub_seg_length(v1, v2, start, v2.pos())
+                > seg_length(v1, v2) + pos_equal_eps
+        };
+        let overlaps_prev = match cuts[prev] {
+            Some(prev_cut) => cuts_overlap(polyline[prev], polyline[k], prev_cut.end, cut.start),
+            None => false,
+        };
+        let overlaps_next = match cuts[next] {
+            Some(next_cut) => cuts_overlap(polyline[k], polyline[next], cut.end, next_cut.start),
+            None => false,
+        };
+
+        if overlaps_prev || overlaps_next {
+            skipped.push(PlineCornerError::SegmentTooShort { vertex_index: k });
+            continue;
+        }
+
+        cuts[k] = Some(cut);
+    }
+
+    if cuts.iter().all(|c| c.is_none()) {
+        return PlineCornerResult {
+            polyline: polyline.clone(),
+            skipped,
+        };
+    }
+
+    let mut result = Polyline::with_capacity(2 * ln, polyline.is_closed());
+    for k in 0..ln {
+        let v = polyline[k];
+        if !polyline.is_closed() && k == ln - 1 {
+            result.add_or_replace_vertex(v, pos_equal_eps);
+            break;
+        }
+
+        let next = polyline.next_wrapping_index(k);
+        let v_next = polyline[next];
+        let seg_start = match cuts[k] {
+            Some(cut) => {
+                result.add_or_replace(cut.start.x, cut.start.y, cut.bulge, pos_equal_eps);
+                cut.end
+            }
+            None => v.pos(),
+        };
+        let seg_end = cuts[next].map_or(v_next.pos(), |c| c.start);
+        let bulge = if cuts[k].is_none() && cuts[next].is_none() {
+            v.bulge
+        } else {
+            sub_seg_bulge(v, v_next, seg_start, seg_end)
+        };
+        result.add_or_replace(seg_start.x, seg_start.y, bulge, pos_equal_eps);
+    }
+
+    if result.is_closed()
+        && result.len() > 1
+        && result[0]
+            .pos()
+            .fuzzy_eq_eps(result.last().unwrap().pos(), pos_equal_eps)
+    {
+        result.remove_last();
+    }
+
+    PlineCornerResult {
+        polyline: result,
+        skipped,
+    }
+}
+
+/// Fillet or chamfer (according to `op`) the corner of `polyline` at `vertex_index`, see
+/// [cut_corners].
+pub fn cut_corner<T>(
+    polyline: &Polyline<T>,
+    vertex_index: usize,
+    op: CornerOp<T>,
+    options: &PlineCornerOptions<T>,
+) -> Result<Polyline<T>, PlineCornerError>
+where
+    T: Real,
+{
+    let result = cut_corners(polyline, std::iter::once(vertex_index), op, options);
+    match result.skipped.first() {
+        Some(&e) => Err(e),
+        None => Ok(result.polyline),
+    }
+}
+
+/// Fillet or chamfer (according to `op`) all the corners of `polyline`, corners where the change
+/// in direction is not greater than [PlineCornerOptions::angle_threshold] are left unchanged.
+pub fn cut_all_corners<T>(
+    polyline: &Polyline<T>,
+    op: CornerOp<T>,
+    options: &PlineCornerOptions<T>,
+) -> PlineCornerResult<T>
+where
+    T: Real,
+{
+    let corners = (0..polyline.len()).filter(|&k| match corner_turn_angle(polyline, k) {
+        Some(turn) => {
+            let turn = turn.abs();
+            let above_threshold = match options.angle_threshold {
+                Some(a) => turn > a,
+                None => true,
+            };
+            !turn.fuzzy_eq_zero() && above_threshold
+        }
+        None => false,
+    });
+
+    cut_corners(polyline, corners, op, options)
+}
@@ -0,0 +1,355 @@
+use super::{
+    pline_boolean::{
+        slice_at_slice_points, stitch_slices_with_sources, SlicePoint, StitchSelector,
+    },
+    pline_intersects::{visit_global_self_intersects, visit_local_self_intersects},
+};
+use crate::{
+    core::{
+        math::Vector2,
+        traits::{ControlFlow, Real},
+        Control,
+    },
+    polyline::{
+        seg_length, seg_midpoint, seg_tangent_vector, BooleanPlineSlice, FillRule,
+        PlineBasicIntersect, PlineIntersectVisitor, PlineOpError, PlineOrientation,
+        PlineOverlappingIntersect, PlineResolveOptions, PlineVertex, Polyline, PolylineSlice,
+    },
+};
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+use std::collections::BTreeMap;
+
+/// Visitor used to collect all the self intersect points of a polyline keyed by the segment index
+/// they lie on (each intersect point is recorded for both segments involved).
+struct SlicePointsVisitor<'a, T> {
+    pline: &'a Polyline<T>,
+    lookup: BTreeMap<usize, Vec<SlicePoint<T>>>,
+    pos_equal_eps: T,
+}
+
+impl<'a, T> SlicePointsVisitor<'a, T>
+where
+    T: Real,
+{
+    fn add(&mut self, seg_index: usize, point: Vector2<T>) {
+        // point at the end of a segment uses the next index to match convention used for intersects
+        let next_index = self.pline.next_wrapping_index(seg_index);
+        let seg_index = if point.fuzzy_eq_eps(self.pline[next_index].pos(), self.pos_equal_eps) {
+            next_index
+        } else {
+            seg_index
+        };
+
+        self.lookup
+            .entry(seg_index)
+            .or_default()
+            .push(SlicePoint::new(point, false));
+    }
+}
+
+impl<'a, T> PlineIntersectVisitor<T, Control> for SlicePointsVisitor<'a, T>
+where
+    T: Real,
+{
+    fn visit_basic_intr(&mut self, intr: PlineBasicIntersect<T>) -> Control {
+        self.add(intr.start_index1, intr.point);
+        self.add(intr.start_index2, intr.point);
+        ControlFlow::continuing()
+    }
+
+    fn visit_overlapping_intr(&mut self, intr: PlineOverlappingIntersect<T>) -> Control {
+        for &point in [intr.point1, intr.point2].iter() {
+            self.add(intr.start_index1, point);
+            self.add(intr.start_index2, point);
+        }
+        ControlFlow::continuing()
+    }
+}
+
+/// Selects the slice to stitch to next by taking the sharpest right turn at the joining point.
+///
+/// Slices are oriented with the filled region on their left so taking the sharpest right turn
+/// follows the boundary of the filled region around the joining point, keeping loops that only
+/// touch at a point separate (rather than forming a single loop which touches itself).
+struct SharpestTurnStitchSelector<T> {
+    /// Direction at the start of each slice.
+    start_dirs: Vec<Vector2<T>>,
+    /// Direction at the end of each slice.
+    end_dirs: Vec<Vector2<T>>,
+}
+
+impl<T> StitchSelector for SharpestTurnStitchSelector<T>
+where
+    T: Real,
+{
+    fn select(&self, current_slice_idx: usize, available_idx: &[usize]) -> Option<usize> {
+        // clockwise angle from the reversed incoming direction to the outgoing direction, going
+        // straight back along the incoming direction is the last choice
+        let reversed_in = -self.end_dirs[current_slice_idx];
+        let clockwise_angle = |i: usize| {
+            let out_dir = self.start_dirs[i];
+            let a = out_dir
+                .perp_dot(reversed_in)
+                .atan2(out_dir.dot(reversed_in));
+            if a <= T::zero() {
+                a + T::tau()
+            } else {
+                a
+            }
+        };
+
+        available_idx
+            .iter()
+            .copied()
+            .map(|i| (i, clockwise_angle(i)))
+            .min_by(|(_, a1), (_, a2)| a1.partial_cmp(a2).unwrap())
+            .map(|(i, _)| i)
+    }
+}
+
+/// Get the winding numbers of `pline` just to the left and right of the slice (left and right
+/// relative to the direction of the source polyline), `aabb_index` is the spatial index of the
+/// `pline` segments.
+fn winding_numbers_beside_slice<T>(
+    pline: &Polyline<T>,
+    slice: &BooleanPlineSlice<T>,
+    aabb_index: &StaticAABB2DIndex<T>,
+    query_stack: &mut Vec<usize>,
+    pos_equal_eps: T,
+) -> (i32, i32)
+where
+    T: Real,
+{
+    let v1 = slice.updated_start;
+    let v2 = if slice.end_index_offset == 0 {
+        PlineVertex::from_vector2(slice.end_point, T::zero())
+    } else {
+        pline[pline.next_wrapping_index(slice.start_index)]
+    };
+
+    let midpoint = seg_midpoint(v1, v2);
+    let left_dir = seg_tangent_vector(v1, v2, midpoint).unit_perp();
+    // keep the side points close to the slice so no other part of the polyline lies between them
+    let side_dist = num_traits::real::Real::min(pos_equal_eps, seg_length(v1, v2) / T::four());
+    let left_point = midpoint + left_dir.scale(side_dist);
+    let right_point = midpoint - left_dir.scale(side_dist);
+
+    (
+        pline.winding_number_indexed(left_point, aabb_index, query_stack),
+        pline.winding_number_indexed(right_point, aabb_index, query_stack),
+    )
+}
+
+/// Resolve the self intersects of the closed polyline `pline` according to `fill_rule`.
+///
+/// The polyline is split at all of its self intersects and each slice is kept if it lies on the
+/// boundary of the region filled according to `fill_rule` (the winding number on one side of the
+/// slice is filled and the other side is not). The kept slices are oriented with the filled region
+/// on their left and stitched together into simple closed polylines, so outer boundaries are
+/// returned counter clockwise and holes are returned clockwise. Overlapping segments of the
+/// polyline are only included once.
+///
+/// Returns an empty vector if `pline` is open or has less than 2 vertexes.
+pub fn resolve_self_intersects<T>(
+    pline: &Polyline<T>,
+    fill_rule: FillRule,
+    options: &PlineResolveOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let mut dangling_count = 0;
+    resolve_self_intersects_counting_dangling(pline, fill_rule, options, &mut dangling_count)
+}
+
+/// Same as [resolve_self_intersects] but validates `pline` and returns an error if slices failed
+/// to stitch together into closed polylines rather than discarding them.
+pub fn try_resolve_self_intersects<T>(
+    pline: &Polyline<T>,
+    fill_rule: FillRule,
+    options: &PlineResolveOptions<T>,
+) -> Result<Vec<Polyline<T>>, PlineOpError>
+where
+    T: Real,
+{
+    pline.validate_as_input(0, options.pos_equal_eps)?;
+    if !pline.is_closed() {
+        return Err(PlineOpError::OpenInput { input: 0 });
+    }
+
+    let mut dangling_count = 0;
+    let result =
+        resolve_self_intersects_counting_dangling(pline, fill_rule, options, &mut dangling_count);
+    if dangling_count > 0 {
+        return Err(PlineOpError::DanglingSlices {
+            count: dangling_count,
+        });
+    }
+
+    Ok(result)
+}
+
+fn resolve_self_intersects_counting_dangling<T>(
+    pline: &Polyline<T>,
+    fill_rule: FillRule,
+    options: &PlineResolveOptions<T>,
+    dangling_count: &mut usize,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    if !pline.is_closed() || pline.len() < 2 {
+        return Vec::new();
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+
+    let constructed_index;
+    let aabb_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = pline.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let mut visitor = SlicePointsVisitor {
+        pline,
+        lookup: BTreeMap::new(),
+        pos_equal_eps,
+    };
+    visit_local_self_intersects(pline, &mut visitor, pos_equal_eps);
+    visit_global_self_intersects(pline, aabb_index, &mut visitor, pos_equal_eps);
+
+    if visitor.lookup.is_empty() {
+        // simple loop, winding number inside is 1 (counter clockwise) or -1 (clockwise)
+        let inside_winding = match pline.orientation() {
+            PlineOrientation::CounterClockwise => 1,
+            PlineOrientation::Clockwise => -1,
+            PlineOrientation::Open => return Vec::new(),
+        };
+
+        if !fill_rule.is_filled(inside_winding) {
+            return Vec::new();
+        }
+
+        let mut result = pline.clone();
+        if inside_winding < 0 {
+            result.invert_direction();
+        }
+        return vec![result];
+    }
+
+    let mut slices = Vec::new();
+    slice_at_slice_points(
+        pline,
+        visitor.lookup,
+        true,
+        &mut |_| true,
+        &mut slices,
+        pos_equal_eps,
+    );
+
+    // keep slices on the boundary of the filled region, inverting them as required to put the
+    // filled region on the left
+    let mut kept_slices = Vec::with_capacity(slices.len());
+    let mut query_stack = Vec::new();
+    for mut slice in slices {
+        let (left_winding, right_winding) = winding_numbers_beside_slice(
+            pline,
+            &slice,
+            aabb_index,
+            &mut query_stack,
+            pos_equal_eps,
+        );
+        let left_filled = fill_rule.is_filled(left_winding);
+        if left_filled == fill_rule.is_filled(right_winding) {
+            continue;
+        }
+
+        slice.inverted = !left_filled;
+        kept_slices.push(slice);
+    }
+
+    if kept_slices.is_empty() {
+        return Vec::new();
+    }
+
+    // get the oriented slice polylines to remove duplicate overlapping slices and find the start
+    // and end directions for stitching
+    let slice_plines: Vec<_> = kept_slices
+        .iter()
+        .map(|s| s.to_polyline(pline, pos_equal_eps))
+        .collect();
+
+    let slice_midpoint = |sp: &Polyline<T>| seg_midpoint(sp[0], sp[1]);
+
+    let midpoints_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(slice_plines.len());
+        for sp in slice_plines.iter() {
+            let mp = slice_midpoint(sp);
+            builder.add(mp.x, mp.y, mp.x, mp.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut is_duplicate = vec![false; slice_plines.len()];
+    for (i, sp) in slice_plines.iter().enumerate() {
+        if is_duplicate[i] {
+            continue;
+        }
+        let mp = slice_midpoint(sp);
+        let start = sp[0].pos();
+        let end = sp.last().unwrap().pos();
+        for j in midpoints_index.query_with_stack(
+            mp.x - pos_equal_eps,
+            mp.y - pos_equal_eps,
+            mp.x + pos_equal_eps,
+            mp.y + pos_equal_eps,
+            &mut query_stack,
+        ) {
+            if j <= i {
+                continue;
+            }
+            let other = &slice_plines[j];
+            if other[0].pos().fuzzy_eq_eps(start, pos_equal_eps)
+                && other.last().unwrap().pos().fuzzy_eq_eps(end, pos_equal_eps)
+            {
+                is_duplicate[j] = true;
+            }
+        }
+    }
+
+    let mut unique_slices = Vec::with_capacity(kept_slices.len());
+    let mut start_dirs = Vec::with_capacity(kept_slices.len());
+    let mut end_dirs = Vec::with_capacity(kept_slices.len());
+    for ((slice, sp), duplicate) in kept_slices
+        .into_iter()
+        .zip(slice_plines.iter())
+        .zip(is_duplicate)
+    {
+        if duplicate {
+            continue;
+        }
+        let n = sp.len();
+        unique_slices.push(slice);
+        start_dirs.push(seg_tangent_vector(sp[0], sp[1], sp[0].pos()));
+        end_dirs.push(seg_tangent_vector(sp[n - 2], sp[n - 1], sp[n - 1].pos()));
+    }
+
+    let selector = SharpestTurnStitchSelector {
+        start_dirs,
+        end_dirs,
+    };
+
+    stitch_slices_with_sources(
+        &unique_slices,
+        |_| pline,
+        &selector,
+        options.slice_join_eps,
+        pos_equal_eps,
+        dangling_count,
+    )
+    .into_iter()
+    .map(|r| r.pline)
+    .collect()
+}
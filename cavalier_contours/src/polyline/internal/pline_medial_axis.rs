@@ -0,0 +1,764 @@
+use super::{
+    pline_arrangement::find_root,
+    pline_corner::{carrier_intersects, offset_carrier, SegCarrier},
+};
+use crate::{
+    core::{
+        math::{angle, angle_from_bulge, dist_squared, point_on_circle, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        seg_arc_radius_and_center, seg_closest_point, seg_length, IndexedPolyline, MedialAxis,
+        MedialAxisBranch, MedialAxisNode, MedialAxisOptions, PlineVertex, Polyline, Shape,
+    },
+};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+/// Boundary sample point used to construct the Voronoi diagram.
+#[derive(Debug, Copy, Clone)]
+struct BoundarySample<T>
+where
+    T: Real,
+{
+    pos: Vector2<T>,
+    loop_index: usize,
+    seq_index: usize,
+}
+
+/// Triangle of a Delaunay triangulation with its circumscribed circle.
+#[derive(Debug, Copy, Clone)]
+struct DelaunayTri<T>
+where
+    T: Real,
+{
+    /// Vertex indexes in counter clockwise order.
+    v: [usize; 3],
+    /// Neighboring triangle across the edge from `v[i]` to `v[(i + 1) % 3]`.
+    adj: [Option<usize>; 3],
+    center: Vector2<T>,
+    radius_sq: T,
+    /// False once the triangle has been replaced by a point insertion.
+    alive: bool,
+}
+
+impl<T> DelaunayTri<T>
+where
+    T: Real,
+{
+    fn new(v: [usize; 3], points: &[Vector2<T>]) -> Self {
+        let a = points[v[0]];
+        let ab = points[v[1]] - a;
+        let ac = points[v[2]] - a;
+        let d = T::two() * ab.perp_dot(ac);
+        if d == T::zero() {
+            // collinear, replaced by any point inserted next to it
+            return Self {
+                v,
+                adj: [None; 3],
+                center: a,
+                radius_sq: Real::max_value(),
+                alive: true,
+            };
+        }
+
+        let ab2 = ab.dot(ab);
+        let ac2 = ac.dot(ac);
+        let u = Vector2::new((ac.y * ab2 - ab.y * ac2) / d, (ab.x * ac2 - ac.x * ab2) / d);
+        Self {
+            v,
+            adj: [None; 3],
+            center: a + u,
+            radius_sq: u.dot(u),
+            alive: true,
+        }
+    }
+
+    /// Returns true if `p` is inside the circumscribed circle (by more than the relative
+    /// tolerance `eps`) or inside the triangle itself.
+    fn is_bad_for(&self, p: Vector2<T>, points: &[Vector2<T>], eps: T) -> bool {
+        let d = dist_squared(p, self.center);
+        if d < self.radius_sq - self.radius_sq * eps {
+            return true;
+        }
+        if d > self.radius_sq {
+            return false;
+        }
+
+        let [a, b, c] = [points[self.v[0]], points[self.v[1]], points[self.v[2]]];
+        let s1 = (b - a).perp_dot(p - a);
+        let s2 = (c - b).perp_dot(p - b);
+        let s3 = (a - c).perp_dot(p - c);
+        (s1 > T::zero() && s2 > T::zero() && s3 > T::zero())
+            || (s1 < T::zero() && s2 < T::zero() && s3 < T::zero())
+    }
+
+    /// Returns true if `p` is strictly to the right of the edge from `v[i]` to `v[(i + 1) % 3]`
+    /// (outside the triangle across that edge).
+    fn is_right_of_edge(&self, i: usize, p: Vector2<T>, points: &[Vector2<T>]) -> bool {
+        let a = points[self.v[i]];
+        let b = points[self.v[(i + 1) % 3]];
+        (b - a).perp_dot(p - a) < T::zero()
+    }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let e = |i: usize, j: usize| {
+            let (a, b) = (self.v[i], self.v[j]);
+            if a < b {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        };
+        [e(0, 1), e(1, 2), e(2, 0)]
+    }
+}
+
+/// Sample points along all the segments of the `loops` given, at most `spacing` apart (every
+/// vertex is sampled).
+fn sample_boundary<T>(loops: &[&IndexedPolyline<T>], spacing: T) -> Vec<BoundarySample<T>>
+where
+    T: Real,
+{
+    let mut samples = Vec::new();
+    for (loop_index, ipline) in loops.iter().enumerate() {
+        let pline = &ipline.polyline;
+        let mut seq_index = 0;
+        for (v1, v2) in pline.iter_segments() {
+            let count = (seg_length(v1, v2) / spacing)
+                .ceil()
+                .to_usize()
+                .unwrap_or(1)
+                .max(1);
+            let step = T::one() / T::from(count).unwrap();
+            let arc = if v1.bulge_is_zero() {
+                None
+            } else {
+                let (radius, center) = seg_arc_radius_and_center(v1, v2);
+                let start_angle = angle(center, v1.pos());
+                let sweep = angle_from_bulge(v1.bulge);
+                Some((radius, center, start_angle, sweep))
+            };
+
+            for i in 0..count {
+                let t = step * T::from(i).unwrap();
+                let pos = match arc {
+                    None => v1.pos() + (v2.pos() - v1.pos()).scale(t),
+                    Some((radius, center, start_angle, sweep)) => {
+                        point_on_circle(radius, center, start_angle + sweep * t)
+                    }
+                };
+                samples.push(BoundarySample {
+                    pos,
+                    loop_index,
+                    seq_index,
+                });
+                seq_index += 1;
+            }
+        }
+    }
+
+    samples
+}
+
+/// Compute the Delaunay triangulation of `points` (Bowyer-Watson algorithm with point location
+/// by walking the triangle neighbors), triangles are returned with their circumscribed circles.
+fn delaunay_triangulation<T>(points: &[Vector2<T>]) -> Vec<DelaunayTri<T>>
+where
+    T: Real,
+{
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    let mut min = points[0];
+    let mut max = points[0];
+    for p in points.iter() {
+        min.x = if p.x < min.x { p.x } else { min.x };
+        min.y = if p.y < min.y { p.y } else { min.y };
+        max.x = if p.x > max.x { p.x } else { max.x };
+        max.y = if p.y > max.y { p.y } else { max.y };
+    }
+
+    let size = {
+        let w = max.x - min.x;
+        let h = max.y - min.y;
+        let s = if w > h { w } else { h };
+        if s > T::zero() {
+            s
+        } else {
+            T::one()
+        }
+    };
+    let mid = (min + max).scale(T::from(0.5).unwrap());
+    let big = T::from(64.0).unwrap() * size;
+
+    // super triangle vertexes are appended after the input points
+    let mut all_points = points.to_vec();
+    all_points.push(Vector2::new(mid.x - big, mid.y - big));
+    all_points.push(Vector2::new(mid.x + big, mid.y - big));
+    all_points.push(Vector2::new(mid.x, mid.y + big));
+
+    // relative tolerance so co-circular points (e.g. sampled along an arc) are not treated as
+    // inside each others circles which would create overlapping triangles
+    let in_circle_eps = T::from(1e-9).unwrap();
+    let mut tris = vec![DelaunayTri::new([n, n + 1, n + 2], &all_points)];
+    let mut last_created = 0;
+    let mut cavity = Vec::new();
+    let mut stack = Vec::new();
+    let mut boundary = Vec::new();
+    for (i, &p) in points.iter().enumerate() {
+        // cavity of triangles whose circumscribed circle contains the point, grown from the
+        // triangle containing the point through neighbors
+        let containing = locate_tri(&tris, &all_points, last_created, p);
+        cavity.clear();
+        stack.clear();
+        tris[containing].alive = false;
+        cavity.push(containing);
+        stack.push(containing);
+        while let Some(ti) = stack.pop() {
+            for k in 0..3 {
+                if let Some(ni) = tris[ti].adj[k] {
+                    if tris[ni].alive && tris[ni].is_bad_for(p, &all_points, in_circle_eps) {
+                        tris[ni].alive = false;
+                        cavity.push(ni);
+                        stack.push(ni);
+                    }
+                }
+            }
+        }
+
+        // cavity boundary edges (counter clockwise) with the neighbor outside the cavity
+        boundary.clear();
+        for &ti in cavity.iter() {
+            let t = &tris[ti];
+            for k in 0..3 {
+                let outer = t.adj[k];
+                let outside_cavity = match outer {
+                    Some(ni) => tris[ni].alive,
+                    None => true,
+                };
+                if outside_cavity {
+                    boundary.push((t.v[k], t.v[(k + 1) % 3], outer));
+                }
+            }
+        }
+
+        // fan of new triangles connecting the boundary edges to the point
+        let first_new = tris.len();
+        for &(a, b, outer) in boundary.iter() {
+            let ti = tris.len();
+            let mut t = DelaunayTri::new([a, b, i], &all_points);
+            t.adj[0] = outer;
+            if let Some(ni) = outer {
+                let nt = &mut tris[ni];
+                if let Some(k) = (0..3).find(|&k| nt.v[k] == b && nt.v[(k + 1) % 3] == a) {
+                    nt.adj[k] = Some(ti);
+                }
+            }
+            tris.push(t);
+        }
+
+        // triangle (a, b, p) shares the edge (b, p) with the new triangle starting at b
+        for ti in first_new..tris.len() {
+            let b = tris[ti].v[1];
+            if let Some(tj) = (first_new..tris.len()).find(|&tj| tris[tj].v[0] == b) {
+                tris[ti].adj[1] = Some(tj);
+                tris[tj].adj[2] = Some(ti);
+            }
+        }
+
+        last_created = first_new;
+    }
+
+    tris.into_iter()
+        .filter(|t| t.alive && t.radius_sq < Real::max_value() && t.v.iter().all(|&v| v < n))
+        .collect()
+}
+
+/// Find a live triangle containing `p` by walking from the triangle at `start` across the edges
+/// `p` lies to the right of, falls back to testing every triangle if the walk does not terminate
+/// (possible with degenerate triangles).
+fn locate_tri<T>(
+    tris: &[DelaunayTri<T>],
+    points: &[Vector2<T>],
+    start: usize,
+    p: Vector2<T>,
+) -> usize
+where
+    T: Real,
+{
+    let mut current = start;
+    for _ in 0..tris.len() {
+        let t = &tris[current];
+        let next = (0..3)
+            .filter(|&k| t.is_right_of_edge(k, p, points))
+            .find_map(|k| t.adj[k]);
+        match next {
+            Some(ni) => current = ni,
+            None => return current,
+        }
+    }
+
+    tris.iter()
+        .position(|t| t.alive && (0..3).all(|k| !t.is_right_of_edge(k, p, points)))
+        .unwrap_or(current)
+}
+
+/// Closest boundary point to a position found by [closest_boundary_points].
+#[derive(Debug, Copy, Clone)]
+struct BoundaryPoint<T>
+where
+    T: Real,
+{
+    dist: T,
+    point: Vector2<T>,
+    loop_index: usize,
+    seg_index: usize,
+}
+
+/// Find the closest point on every boundary segment within `search_dist` of `pos`, sorted by
+/// distance.
+fn closest_boundary_points<T>(
+    loops: &[&IndexedPolyline<T>],
+    pos: Vector2<T>,
+    search_dist: T,
+    query_stack: &mut Vec<usize>,
+) -> Vec<BoundaryPoint<T>>
+where
+    T: Real,
+{
+    let mut result = Vec::new();
+    for (loop_index, ipline) in loops.iter().enumerate() {
+        let pline = &ipline.polyline;
+        let candidates = ipline.spatial_index.query_with_stack(
+            pos.x - search_dist,
+            pos.y - search_dist,
+            pos.x + search_dist,
+            pos.y + search_dist,
+            query_stack,
+        );
+
+        for seg_index in candidates {
+            let v1 = pline[seg_index];
+            let v2 = pline[pline.next_wrapping_index(seg_index)];
+            let point = seg_closest_point(v1, v2, pos);
+            let dist = (point - pos).length();
+            if !dist.is_finite() {
+                continue;
+            }
+            result.push(BoundaryPoint {
+                dist,
+                point,
+                loop_index,
+                seg_index,
+            });
+        }
+    }
+
+    result.sort_by(|a, b| a.dist.partial_cmp(&b.dist).unwrap_or(Ordering::Equal));
+    result
+}
+
+/// Offset carrier of the boundary feature closest to a medial axis point (segment interior or
+/// segment end point) by `offset` toward the inside of the shape.
+fn feature_carrier<T>(
+    loops: &[&IndexedPolyline<T>],
+    bp: &BoundaryPoint<T>,
+    offset: T,
+    pos_equal_eps: T,
+) -> Option<SegCarrier<T>>
+where
+    T: Real,
+{
+    let pline = &loops[bp.loop_index].polyline;
+    let v1 = pline[bp.seg_index];
+    let v2 = pline[pline.next_wrapping_index(bp.seg_index)];
+    if bp.point.fuzzy_eq_eps(v1.pos(), pos_equal_eps) {
+        return Some(SegCarrier::Circle(offset, v1.pos()));
+    }
+    if bp.point.fuzzy_eq_eps(v2.pos(), pos_equal_eps) {
+        return Some(SegCarrier::Circle(offset, v2.pos()));
+    }
+
+    // shape interior is always to the left (counter clockwise outer loops, clockwise holes)
+    offset_carrier(v1, v2, offset)
+}
+
+/// Move `pos` onto the bisector of its two closest boundary features, returns the new position
+/// and inscribed circle radius, or `None` if no bisector point is found within `max_move`.
+fn refine_axis_point<T>(
+    loops: &[&IndexedPolyline<T>],
+    closest: &[BoundaryPoint<T>],
+    pos: Vector2<T>,
+    max_move: T,
+    pos_equal_eps: T,
+) -> Option<(Vector2<T>, T)>
+where
+    T: Real,
+{
+    let first = closest.first()?;
+    let second = closest
+        .iter()
+        .find(|bp| (bp.point - first.point).length() > max_move)?;
+
+    let radius = (first.dist + second.dist) / T::two();
+    if radius.fuzzy_eq_zero() {
+        return None;
+    }
+
+    let c1 = feature_carrier(loops, first, radius, pos_equal_eps)?;
+    let c2 = feature_carrier(loops, second, radius, pos_equal_eps)?;
+    let candidates = match (c1, c2) {
+        (SegCarrier::Line(p0, p1), SegCarrier::Line(u0, u1))
+            if (p1 - p0).perp_dot(u1 - u0).fuzzy_eq_zero() =>
+        {
+            // parallel lines, bisector is the line half way between them
+            let dir = (p1 - p0).normalize();
+            let on_line = p0 + dir.scale((pos - p0).dot(dir));
+            let other = u0
+                + (u1 - u0)
+                    .normalize()
+                    .scale((on_line - u0).dot((u1 - u0).normalize()));
+            vec![(on_line + other).scale(T::from(0.5).unwrap())]
+        }
+        _ => carrier_intersects(c1, c2),
+    };
+
+    candidates
+        .into_iter()
+        .map(|p| (dist_squared(p, pos), p))
+        .filter(|(d, _)| *d <= max_move * max_move)
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+        .map(|(_, p)| (p, radius))
+}
+
+/// Compute the medial axis of the region bounded by the polylines of `shape` (counter clockwise
+/// outer loops and clockwise holes).
+pub fn shape_medial_axis<T>(shape: &Shape<T>, options: &MedialAxisOptions<T>) -> MedialAxis<T>
+where
+    T: Real,
+{
+    let plines_index = match &shape.plines_index {
+        Some(index) => index,
+        None => return MedialAxis::empty(),
+    };
+
+    // same order as the shape polylines index
+    let loops: Vec<&IndexedPolyline<T>> = shape
+        .ccw_plines
+        .iter()
+        .chain(shape.cw_plines.iter())
+        .collect();
+    let loops = &loops[..];
+
+    let min_x = plines_index.min_x();
+    let min_y = plines_index.min_y();
+    let max_x = plines_index.max_x();
+    let max_y = plines_index.max_y();
+
+    let spacing = match options.sample_spacing {
+        Some(s) if s > T::zero() => s,
+        _ => {
+            let w = max_x - min_x;
+            let h = max_y - min_y;
+            (if w > h { w } else { h }) / T::from(256.0).unwrap()
+        }
+    };
+
+    if spacing.fuzzy_eq_zero() {
+        return MedialAxis::empty();
+    }
+
+    let samples = sample_boundary(loops, spacing);
+    let mut loop_sample_counts = vec![0; loops.len()];
+    for s in samples.iter() {
+        loop_sample_counts[s.loop_index] += 1;
+    }
+
+    let points: Vec<Vector2<T>> = samples.iter().map(|s| s.pos).collect();
+    let tris = delaunay_triangulation(&points);
+
+    // only polylines whose bounding box contains the point can wind around it
+    let mut query_stack = Vec::new();
+    let mut seg_query_stack = Vec::new();
+    let mut inside = |p: Vector2<T>| {
+        plines_index
+            .query_with_stack(p.x, p.y, p.x, p.y, &mut query_stack)
+            .into_iter()
+            .map(|i| {
+                let ipline = shape.get_indexed_pline(i);
+                ipline.polyline.winding_number_indexed(
+                    p,
+                    &ipline.spatial_index,
+                    &mut seg_query_stack,
+                )
+            })
+            .sum::<i32>()
+            != 0
+    };
+    let tri_inside: Vec<bool> = tris.iter().map(|t| inside(t.center)).collect();
+
+    let mut edge_tris: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+    for (ti, t) in tris.iter().enumerate() {
+        if !tri_inside[ti] {
+            continue;
+        }
+        for e in t.edges().iter() {
+            edge_tris.entry(*e).or_default().push(ti);
+        }
+    }
+
+    let adjacent_samples = |a: usize, b: usize| {
+        let (sa, sb) = (&samples[a], &samples[b]);
+        if sa.loop_index != sb.loop_index {
+            return false;
+        }
+        let count = loop_sample_counts[sa.loop_index];
+        let diff = sa.seq_index.abs_diff(sb.seq_index);
+        diff == 1 || diff + 1 == count
+    };
+
+    // Voronoi edges separating non adjacent samples form the axis, triangles with coincident
+    // circumscribed circle centers (co-circular samples) are merged into one vertex
+    let mut parents: Vec<usize> = (0..tris.len()).collect();
+    let mut axis_edges = Vec::new();
+    for (&(a, b), edge_tri_list) in edge_tris.iter() {
+        if edge_tri_list.len() != 2 || adjacent_samples(a, b) {
+            continue;
+        }
+        let (t1, t2) = (edge_tri_list[0], edge_tri_list[1]);
+        if tris[t1]
+            .center
+            .fuzzy_eq_eps(tris[t2].center, options.pos_equal_eps)
+        {
+            let r1 = find_root(&mut parents, t1);
+            let r2 = find_root(&mut parents, t2);
+            parents[r2] = r1;
+        }
+        axis_edges.push((t1, t2));
+    }
+
+    // vertex graph keyed by root triangle index
+    let mut adjacency: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+    for &(t1, t2) in axis_edges.iter() {
+        let r1 = find_root(&mut parents, t1);
+        let r2 = find_root(&mut parents, t2);
+        adjacency.entry(r1).or_default();
+        adjacency.entry(r2).or_default();
+        if r1 != r2 {
+            adjacency.get_mut(&r1).unwrap().insert(r2);
+            adjacency.get_mut(&r2).unwrap().insert(r1);
+        }
+    }
+
+    prune_spurs(&mut adjacency, &tris, options.min_spur_length);
+
+    // compute vertex positions and radii
+    let mut vertex_data: BTreeMap<usize, (Vector2<T>, T)> = BTreeMap::new();
+    for (&v, neighbors) in adjacency.iter() {
+        let tri = &tris[v];
+        let search_dist = tri.radius_sq.sqrt() + spacing;
+        let closest = closest_boundary_points(loops, tri.center, search_dist, &mut query_stack);
+        let radius = closest.first().map(|bp| bp.dist).unwrap_or_else(T::zero);
+        let refined = if neighbors.len() == 2 {
+            refine_axis_point(loops, &closest, tri.center, spacing, options.pos_equal_eps)
+        } else {
+            None
+        };
+        vertex_data.insert(v, refined.unwrap_or((tri.center, radius)));
+    }
+
+    build_axis_graph(&adjacency, &vertex_data)
+}
+
+/// Remove spur branches (ending at a vertex with a single neighbor) shorter than `min_length`.
+fn prune_spurs<T>(
+    adjacency: &mut BTreeMap<usize, BTreeSet<usize>>,
+    tris: &[DelaunayTri<T>],
+    min_length: T,
+) where
+    T: Real,
+{
+    if min_length <= T::zero() {
+        return;
+    }
+
+    let ends: Vec<usize> = adjacency
+        .iter()
+        .filter(|(_, n)| n.len() == 1)
+        .map(|(&v, _)| v)
+        .collect();
+
+    for end in ends {
+        // walk from the end to the first junction
+        let mut path = vec![end];
+        let mut length = T::zero();
+        let mut prev = end;
+        let mut current = match adjacency[&end].iter().next() {
+            Some(&n) => n,
+            None => continue,
+        };
+        loop {
+            length = length + (tris[current].center - tris[prev].center).length();
+            if length >= min_length {
+                break;
+            }
+            let neighbors = &adjacency[&current];
+            if neighbors.len() != 2 {
+                if neighbors.len() > 2 {
+                    // reached a junction, remove the spur
+                    for w in path.windows(2) {
+                        adjacency.get_mut(&w[0]).unwrap().remove(&w[1]);
+                        adjacency.get_mut(&w[1]).unwrap().remove(&w[0]);
+                    }
+                    let last = *path.last().unwrap();
+                    adjacency.get_mut(&last).unwrap().remove(&current);
+                    adjacency.get_mut(&current).unwrap().remove(&last);
+                    for v in path.iter() {
+                        adjacency.remove(v);
+                    }
+                }
+                break;
+            }
+            let next = *neighbors.iter().find(|&&n| n != prev).unwrap();
+            path.push(current);
+            prev = current;
+            current = next;
+        }
+    }
+}
+
+/// Builder chaining the medial axis vertex graph into branches.
+struct AxisGraphBuilder<'a, T>
+where
+    T: Real,
+{
+    adjacency: &'a BTreeMap<usize, BTreeSet<usize>>,
+    vertex_data: &'a BTreeMap<usize, (Vector2<T>, T)>,
+    node_of_vertex: BTreeMap<usize, usize>,
+    visited_edges: BTreeSet<(usize, usize)>,
+    result: MedialAxis<T>,
+}
+
+impl<'a, T> AxisGraphBuilder<'a, T>
+where
+    T: Real,
+{
+    fn edge_key(a: usize, b: usize) -> (usize, usize) {
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    fn is_visited(&self, a: usize, b: usize) -> bool {
+        self.visited_edges.contains(&Self::edge_key(a, b))
+    }
+
+    fn add_node(&mut self, v: usize) -> usize {
+        if let Some(&i) = self.node_of_vertex.get(&v) {
+            return i;
+        }
+
+        let (pos, radius) = self.vertex_data[&v];
+        self.result.nodes.push(MedialAxisNode {
+            pos,
+            radius,
+            branches: Vec::new(),
+        });
+        let i = self.result.nodes.len() - 1;
+        self.node_of_vertex.insert(v, i);
+        i
+    }
+
+    /// Walk from vertex `start` through `first` until reaching a node vertex (or `start` again)
+    /// and add the branch.
+    fn walk_branch(&mut self, start: usize, first: usize) {
+        let mut path = vec![start];
+        let mut prev = start;
+        let mut current = first;
+        self.visited_edges.insert(Self::edge_key(start, first));
+        while self.adjacency[&current].len() == 2 && current != start {
+            path.push(current);
+            let next = *self.adjacency[&current]
+                .iter()
+                .find(|&&n| n != prev)
+                .unwrap();
+            self.visited_edges.insert(Self::edge_key(current, next));
+            prev = current;
+            current = next;
+        }
+        path.push(current);
+
+        let start_node = self.add_node(start);
+        let end_node = self.add_node(current);
+        let mut polyline = Polyline::with_capacity(path.len(), false);
+        let mut radii = Vec::with_capacity(path.len());
+        for v in path.iter() {
+            let (pos, radius) = self.vertex_data[v];
+            polyline.add_vertex(PlineVertex::from_vector2(pos, T::zero()));
+            radii.push(radius);
+        }
+
+        let branch_index = self.result.branches.len();
+        self.result.branches.push(MedialAxisBranch {
+            polyline,
+            radii,
+            start_node,
+            end_node,
+        });
+        self.result.nodes[start_node].branches.push(branch_index);
+        if end_node != start_node {
+            self.result.nodes[end_node].branches.push(branch_index);
+        }
+    }
+
+    fn build(mut self) -> MedialAxis<T> {
+        let adjacency = self.adjacency;
+        for (&v, neighbors) in adjacency.iter() {
+            if neighbors.len() == 2 {
+                continue;
+            }
+            self.add_node(v);
+            for &n in neighbors.iter() {
+                if !self.is_visited(v, n) {
+                    self.walk_branch(v, n);
+                }
+            }
+        }
+
+        // remaining edges form loops with no junctions
+        for (&v, neighbors) in adjacency.iter() {
+            if let Some(&n) = neighbors.iter().next() {
+                if !self.is_visited(v, n) {
+                    self.walk_branch(v, n);
+                }
+            }
+        }
+
+        self.result
+    }
+}
+
+/// Chain the vertex graph into branches between nodes (vertexes with other than 2 neighbors).
+fn build_axis_graph<T>(
+    adjacency: &BTreeMap<usize, BTreeSet<usize>>,
+    vertex_data: &BTreeMap<usize, (Vector2<T>, T)>,
+) -> MedialAxis<T>
+where
+    T: Real,
+{
+    AxisGraphBuilder {
+        adjacency,
+        vertex_data,
+        node_of_vertex: BTreeMap::new(),
+        visited_edges: BTreeSet::new(),
+        result: MedialAxis::empty(),
+    }
+    .build()
+}
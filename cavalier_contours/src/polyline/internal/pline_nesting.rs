@@ -0,0 +1,97 @@
+use crate::{
+    core::traits::Real,
+    polyline::{seg_midpoint, Polyline},
+};
+use static_aabb2d_index::{StaticAABB2DIndexBuilder, AABB};
+
+/// For each polyline find the smallest polyline containing it (`None` if not contained by any
+/// other polyline). Containment is tested with the winding number of a point on the polyline, the
+/// polylines must not intersect each other.
+pub(crate) fn direct_containers<T>(
+    plines: &[&Polyline<T>],
+    extents: &[AABB<T>],
+) -> Vec<Option<usize>>
+where
+    T: Real,
+{
+    let mut builder = StaticAABB2DIndexBuilder::new(extents.len());
+    for e in extents.iter() {
+        builder.add(e.min_x, e.min_y, e.max_x, e.max_y);
+    }
+
+    let index = match builder.build() {
+        Ok(i) => i,
+        Err(_) => return vec![None; plines.len()],
+    };
+
+    let abs_areas: Vec<T> = plines.iter().map(|p| p.area().abs()).collect();
+    let mut query_stack = Vec::new();
+    let mut result = Vec::with_capacity(plines.len());
+    for (i, pline) in plines.iter().enumerate() {
+        let point = seg_midpoint(pline[0], pline[1]);
+        let e = &extents[i];
+        let candidates =
+            index.query_with_stack(e.min_x, e.min_y, e.max_x, e.max_y, &mut query_stack);
+
+        let mut container = None;
+        for j in candidates {
+            if j == i || abs_areas[j] <= abs_areas[i] {
+                continue;
+            }
+
+            let ej = &extents[j];
+            if ej.min_x > e.min_x || ej.min_y > e.min_y || ej.max_x < e.max_x || ej.max_y < e.max_y
+            {
+                continue;
+            }
+
+            if plines[j].winding_number(point) == 0 {
+                continue;
+            }
+
+            container = match container {
+                Some(c) if abs_areas[c] <= abs_areas[j] => Some(c),
+                _ => Some(j),
+            };
+        }
+
+        result.push(container);
+    }
+
+    result
+}
+
+/// Find the polyline of the opposite sign directly enclosing each polyline, the first
+/// `pos_count` polylines are positive and the rest are negative. Returns the index (relative to
+/// the start of the opposite sign polylines) of the parent for the positive polylines followed by
+/// the parents for the negative polylines.
+pub(crate) fn opposite_sign_parents<T>(
+    plines: &[&Polyline<T>],
+    extents: &[AABB<T>],
+    pos_count: usize,
+) -> (Vec<Option<usize>>, Vec<Option<usize>>)
+where
+    T: Real,
+{
+    let containers = direct_containers(plines, extents);
+
+    // walk up the containers until a polyline of the opposite sign is found
+    let parent_of = |i: usize| {
+        let is_pos = i < pos_count;
+        let mut current = containers[i];
+        while let Some(c) = current {
+            if (c < pos_count) != is_pos {
+                break;
+            }
+            current = containers[c];
+        }
+        current
+    };
+
+    (
+        (0..pos_count)
+            .map(|i| parent_of(i).map(|c| c - pos_count))
+            .collect(),
+        (pos_count..containers.len()).map(parent_of).collect(),
+    )
+}
@@ -8,10 +8,14 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        internal::pline_intersects::{all_self_intersects_as_basic, find_intersects},
+        internal::{
+            pline_boolean::is_dangling_chain,
+            pline_intersects::{all_self_intersects_as_basic, find_intersects},
+        },
         pline_seg_intr, seg_arc_radius_and_center, seg_closest_point, seg_fast_approx_bounding_box,
-        seg_midpoint, FindIntersectsOptions, OpenPlineSlice, PlineOffsetOptions, PlineSegIntr,
-        PlineVertex, Polyline, PolylineSlice,
+        seg_length, seg_midpoint, seg_split_at_point, seg_tangent_vector, FindIntersectsOptions,
+        OffsetJoinStyle, OffsetPolylineWithSource, OffsetSegSource, OpenPlineSlice,
+        PlineOffsetOptions, PlineOpError, PlineSegIntr, PlineVertex, Polyline, PolylineSlice,
     },
 };
 use core::panic;
@@ -51,50 +55,55 @@ where
     };
     result.reserve(segment_count);
 
-    let process_line_seg = |v1: PlineVertex<T>, v2: PlineVertex<T>| -> RawPlineOffsetSeg<T> {
+    for (v1, v2) in polyline.iter_segments() {
+        result.push(raw_offset_seg(v1, v2, offset));
+    }
+
+    result
+}
+
+/// Create the raw parallel offset segment of the polyline segment `v1` to `v2` using the `offset`
+/// value given.
+pub(crate) fn raw_offset_seg<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    offset: T,
+) -> RawPlineOffsetSeg<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
         let line_v = v2.pos() - v1.pos();
         let offset_v = line_v.unit_perp().scale(offset);
-        RawPlineOffsetSeg {
+        return RawPlineOffsetSeg {
             v1: PlineVertex::from_vector2(v1.pos() + offset_v, T::zero()),
             v2: PlineVertex::from_vector2(v2.pos() + offset_v, T::zero()),
             orig_v2_pos: v2.pos(),
             collapsed_arc: false,
-        }
-    };
-
-    let process_arc_seg = |v1: PlineVertex<T>, v2: PlineVertex<T>| -> RawPlineOffsetSeg<T> {
-        let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
-        let offs = if v1.bulge_is_neg() { offset } else { -offset };
-        let radius_after_offset = arc_radius + offs;
-        let v1_to_center = (v1.pos() - arc_center).normalize();
-        let v2_to_center = (v2.pos() - arc_center).normalize();
-
-        let (new_v1_bulge, collapsed_arc) = if radius_after_offset.fuzzy_lt(T::zero()) {
-            // collapsed arc, offset arc start and end points towards arc center and turn into line
-            // handles case where offset vertexes are equal and simplifies path for clipping
-            // algorithm
-            (T::zero(), true)
-        } else {
-            (v1.bulge, false)
         };
+    }
 
-        RawPlineOffsetSeg {
-            v1: PlineVertex::from_vector2(v1_to_center.scale(offs) + v1.pos(), new_v1_bulge),
-            v2: PlineVertex::from_vector2(v2_to_center.scale(offs) + v2.pos(), v2.bulge),
-            orig_v2_pos: v2.pos(),
-            collapsed_arc,
-        }
+    let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+    let offs = if v1.bulge_is_neg() { offset } else { -offset };
+    let radius_after_offset = arc_radius + offs;
+    let v1_to_center = (v1.pos() - arc_center).normalize();
+    let v2_to_center = (v2.pos() - arc_center).normalize();
+
+    let (new_v1_bulge, collapsed_arc) = if radius_after_offset.fuzzy_lt(T::zero()) {
+        // collapsed arc, offset arc start and end points towards arc center and turn into line
+        // handles case where offset vertexes are equal and simplifies path for clipping
+        // algorithm
+        (T::zero(), true)
+    } else {
+        (v1.bulge, false)
     };
 
-    for (v1, v2) in polyline.iter_segments() {
-        if v1.bulge_is_zero() {
-            result.push(process_line_seg(v1, v2));
-        } else {
-            result.push(process_arc_seg(v1, v2));
-        }
+    RawPlineOffsetSeg {
+        v1: PlineVertex::from_vector2(v1_to_center.scale(offs) + v1.pos(), new_v1_bulge),
+        v2: PlineVertex::from_vector2(v2_to_center.scale(offs) + v2.pos(), v2.bulge),
+        orig_v2_pos: v2.pos(),
+        collapsed_arc,
     }
-
-    result
 }
 
 /// Test if parametric value `t` represents a false intersect or not. False intersect is defined as
@@ -144,13 +153,88 @@ fn connect_using_arc<T>(
 }
 
 /// Parameters passed to segment join functions used to form raw offset polyline.
-struct JoinParams<T> {
+struct JoinParams<T>
+where
+    T: Real,
+{
     /// If true then connection arcs should be counter clockwise, otherwise clockwise.
     connection_arcs_ccw: bool,
+    /// Style used to join segments that do not intersect.
+    join_style: OffsetJoinStyle<T>,
     /// Epsilon to use for testing if positions are fuzzy equal.
     pos_equal_eps: T,
 }
 
+/// Connect two raw offset segments using the join style in `params` and push the vertexes to the
+/// `result` output parameter.
+///
+/// Joins to/from collapsed arcs are always connected using an arc.
+fn connect_using_join<T>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    params: &JoinParams<T>,
+    result: &mut Polyline<T>,
+) where
+    T: Real,
+{
+    let connection_arcs_ccw = params.connection_arcs_ccw;
+    let pos_equal_eps = params.pos_equal_eps;
+    if s1.collapsed_arc || s2.collapsed_arc {
+        connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+        return;
+    }
+
+    let miter_limit = match params.join_style {
+        OffsetJoinStyle::Round => {
+            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            return;
+        }
+        OffsetJoinStyle::Miter { limit } => Some(limit),
+        OffsetJoinStyle::Bevel => None,
+    };
+
+    // both segment end points are offset distance away from the original vertex (along the
+    // segment normals at the vertex)
+    let center = s1.orig_v2_pos;
+    let sp = s1.v2.pos();
+    let ep = s2.v1.pos();
+    let radius = (sp - center).length();
+    let bisector = (sp - center) + (ep - center);
+    let bisector_length = bisector.length();
+
+    if bisector_length.fuzzy_eq_zero() {
+        // full reversal in direction, no corner to miter or bevel
+        connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+        return;
+    }
+
+    // ratio of miter length to offset distance is 1 / cos(theta / 2) where theta is the angle
+    // between the segment normals
+    let two = T::two();
+    let miter_point = match miter_limit {
+        Some(limit) if bisector_length * limit > two * radius => {
+            let miter_length = two * radius * radius / bisector_length;
+            Some(center + bisector.scale(miter_length / bisector_length))
+        }
+        _ => None,
+    };
+
+    if let Some(miter_point) = miter_point {
+        // line segments are extended to the miter point, arc segments are connected to the miter
+        // point with lines
+        if !s1.v1.bulge_is_zero() {
+            result.add_or_replace(sp.x, sp.y, T::zero(), pos_equal_eps);
+        }
+        result.add_or_replace(miter_point.x, miter_point.y, T::zero(), pos_equal_eps);
+        if !s2.v1.bulge_is_zero() {
+            result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+        }
+    } else {
+        result.add_or_replace(sp.x, sp.y, T::zero(), pos_equal_eps);
+        result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+    }
+}
+
 /// Join two adjacent raw offset segments where both segments are lines.
 fn line_line_join<T>(
     s1: &RawPlineOffsetSeg<T>,
@@ -199,7 +283,7 @@ fn line_line_join<T>(
             LineLineIntr::FalseIntersect { seg1_t, seg2_t } => {
                 if seg1_t > T::one() && is_false_intersect(seg2_t) {
                     // extend and join the lines together using arc
-                    connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+                    connect_using_join(s1, s2, params, result);
                 } else {
                     result.add_or_replace(v2.x, v2.y, T::zero(), pos_equal_eps);
                     result.add_or_replace(u1.x, u1.y, u1.bulge, pos_equal_eps);
@@ -258,7 +342,7 @@ fn line_arc_join<T>(
         }
 
         if t > T::one() && !true_arc_intr {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join(s1, s2, params, result);
             return;
         }
 
@@ -274,7 +358,7 @@ fn line_arc_join<T>(
 
     match line_circle_intr(v1.pos(), v2.pos(), arc_radius, arc_center) {
         LineCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join(s1, s2, params, result);
         }
         LineCircleIntr::TangentIntersect { t0 } => {
             process_intersect(t0, point_from_parametric(v1.pos(), v2.pos(), t0));
@@ -304,7 +388,6 @@ fn arc_line_join<T>(
 ) where
     T: Real,
 {
-    let connection_arcs_ccw = params.connection_arcs_ccw;
     let pos_equal_eps = params.pos_equal_eps;
     let v1 = &s1.v1;
     let v2 = &s1.v2;
@@ -344,12 +427,12 @@ fn arc_line_join<T>(
             return;
         }
 
-        connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+        connect_using_join(s1, s2, params, result);
     };
 
     match line_circle_intr(u1.pos(), u2.pos(), arc_radius, arc_center) {
         LineCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join(s1, s2, params, result);
         }
         LineCircleIntr::TangentIntersect { t0 } => {
             process_intersect(t0, point_from_parametric(u1.pos(), u2.pos(), t0));
@@ -384,7 +467,6 @@ fn arc_arc_join<T>(
 ) where
     T: Real,
 {
-    let connection_arcs_ccw = params.connection_arcs_ccw;
     let pos_equal_eps = params.pos_equal_eps;
     let v1 = &s1.v1;
     let v2 = &s1.v2;
@@ -406,7 +488,7 @@ fn arc_arc_join<T>(
 
     let mut process_intersect = |intersect: Vector2<T>, true_intersect: bool| {
         if !true_intersect {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join(s1, s2, params, result);
         } else {
             let prev_vertex = result.last().unwrap();
 
@@ -446,7 +528,7 @@ fn arc_arc_join<T>(
 
     match circle_circle_intr(arc1_radius, arc1_center, arc2_radius, arc2_center) {
         CircleCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join(s1, s2, params, result);
         }
         CircleCircleIntr::TangentIntersect { point } => {
             process_intersect(point, both_arcs_sweep_point(point));
@@ -478,62 +560,302 @@ fn arc_arc_join<T>(
     }
 }
 
+/// Create the raw offset polyline by joining all the raw offset segments together using the
+/// `join_style` given for segments that do not intersect.
 pub fn create_raw_offset_polyline<T>(
     polyline: &Polyline<T>,
     offset: T,
+    join_style: OffsetJoinStyle<T>,
     pos_equal_eps: T,
 ) -> Polyline<T>
 where
     T: Real,
+{
+    create_raw_offset_polyline_with_source(polyline, offset, join_style, pos_equal_eps).0
+}
+
+/// Same as [create_raw_offset_polyline] but also returns the source of each raw offset polyline
+/// vertex (source of the segment that starts at the vertex, the last vertex of an open polyline
+/// has the same source as the vertex before it).
+pub fn create_raw_offset_polyline_with_source<T>(
+    polyline: &Polyline<T>,
+    offset: T,
+    join_style: OffsetJoinStyle<T>,
+    pos_equal_eps: T,
+) -> (Polyline<T>, Vec<OffsetSegSource>)
+where
+    T: Real,
+{
+    raw_offset_polyline_with_source(polyline, |_| offset, join_style, pos_equal_eps)
+}
+
+/// Create the raw offset polyline using a different offset distance for each segment of the
+/// polyline. All the `offsets` must have the same sign, segments are indexed the same as
+/// [Polyline::iter_segment_indexes].
+///
+/// Segments that do not intersect are joined using the `join_style` given where the offset
+/// distance does not change. At convex vertexes where the offset distance changes a round join is
+/// always used, the joining arc has the larger of the two offset distances as its radius and the
+/// segment with the smaller offset distance is trimmed where it meets the arc.
+pub fn create_variable_raw_offset_polyline<T>(
+    polyline: &Polyline<T>,
+    offsets: &[T],
+    join_style: OffsetJoinStyle<T>,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    raw_offset_polyline_with_source(polyline, |i| offsets[i], join_style, pos_equal_eps).0
+}
+
+/// Test if the polyline turns away from the offset direction at vertex index `k` (the segment
+/// ending at `k` and the segment starting at `k` are joined on the outside of the corner).
+/// Collinear segments and full reversals in direction are considered convex.
+fn is_convex_offset_vertex<T>(polyline: &Polyline<T>, k: usize, offset: T) -> bool
+where
+    T: Real,
+{
+    let prev = polyline.prev_wrapping_index(k);
+    let next = polyline.next_wrapping_index(k);
+    let vk = polyline[k];
+    let t1 = seg_tangent_vector(polyline[prev], vk, vk.pos()).normalize();
+    let t2 = seg_tangent_vector(vk, polyline[next], vk.pos()).normalize();
+    let turn = t1.perp_dot(t2);
+    turn.fuzzy_eq_zero() || (turn < T::zero()) == (offset > T::zero())
+}
+
+/// Find the intersect between the segment `v1` to `v2` and the circle given which is closest to
+/// `v1` (if `from_start` is true) or closest to `v2` (if `from_start` is false) when traveling
+/// along the segment.
+fn seg_circle_intr_nearest_end<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    circle_center: Vector2<T>,
+    circle_radius: T,
+    from_start: bool,
+    pos_equal_eps: T,
+) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let mut intrs = Vec::with_capacity(2);
+    if v1.bulge_is_zero() {
+        let mut add_line_intr = |t: T| {
+            if !is_false_intersect(t) {
+                intrs.push(point_from_parametric(v1.pos(), v2.pos(), t));
+            }
+        };
+        match line_circle_intr(v1.pos(), v2.pos(), circle_radius, circle_center) {
+            LineCircleIntr::NoIntersect => {}
+            LineCircleIntr::TangentIntersect { t0 } => add_line_intr(t0),
+            LineCircleIntr::TwoIntersects { t0, t1 } => {
+                add_line_intr(t0);
+                add_line_intr(t1);
+            }
+        }
+    } else {
+        let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+        let mut add_arc_intr = |point: Vector2<T>| {
+            if point_within_arc_sweep(arc_center, v1.pos(), v2.pos(), v1.bulge_is_neg(), point) {
+                intrs.push(point);
+            }
+        };
+        match circle_circle_intr(arc_radius, arc_center, circle_radius, circle_center) {
+            CircleCircleIntr::NoIntersect | CircleCircleIntr::Overlapping => {}
+            CircleCircleIntr::TangentIntersect { point } => add_arc_intr(point),
+            CircleCircleIntr::TwoIntersects { point1, point2 } => {
+                add_arc_intr(point1);
+                add_arc_intr(point2);
+            }
+        }
+    }
+
+    let dist_along = |point: Vector2<T>| -> T {
+        let split = seg_split_at_point(v1, v2, point, pos_equal_eps);
+        if from_start {
+            seg_length(
+                split.updated_start,
+                PlineVertex::from_vector2(point, T::zero()),
+            )
+        } else {
+            seg_length(split.split_vertex, v2)
+        }
+    };
+
+    intrs
+        .into_iter()
+        .map(|p| (p, dist_along(p)))
+        .fold(None, |acc: Option<(Vector2<T>, T)>, (p, d)| match acc {
+            Some((_, acc_d)) if acc_d <= d => acc,
+            _ => Some((p, d)),
+        })
+        .map(|(p, _)| p)
+}
+
+/// Join two adjacent raw offset segments at a convex vertex where the segments have different
+/// offset distances (`abs_offset1` for `s1` and `abs_offset2` for `s2`).
+///
+/// The segments are connected by an arc around the original vertex with the larger offset distance
+/// as its radius, the segment with the smaller offset distance is trimmed where it meets the arc.
+fn variable_convex_join<T>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    abs_offset1: T,
+    abs_offset2: T,
+    params: &JoinParams<T>,
+    result: &mut Polyline<T>,
+) where
+    T: Real,
+{
+    let connection_arcs_ccw = params.connection_arcs_ccw;
+    let pos_equal_eps = params.pos_equal_eps;
+    let center = s1.orig_v2_pos;
+
+    if abs_offset1 < abs_offset2 {
+        // trim end of s1 where it enters the circle of the s2 offset distance
+        let s1_start = *result.last().unwrap();
+        let arc_start = match seg_circle_intr_nearest_end(
+            s1_start,
+            s1.v2,
+            center,
+            abs_offset2,
+            false,
+            pos_equal_eps,
+        ) {
+            Some(intr) => {
+                let split = seg_split_at_point(s1_start, s1.v2, intr, pos_equal_eps);
+                result.last_mut().unwrap().bulge = split.updated_start.bulge;
+                intr
+            }
+            None => {
+                // s1 lies entirely inside the circle, connect radially out to the circle
+                let sp = s1.v2.pos();
+                result.add_or_replace(sp.x, sp.y, T::zero(), pos_equal_eps);
+                center + (sp - center).normalize().scale(abs_offset2)
+            }
+        };
+
+        let ep = s2.v1.pos();
+        let bulge = bulge_for_connection(center, arc_start, ep, connection_arcs_ccw);
+        result.add_or_replace(arc_start.x, arc_start.y, bulge, pos_equal_eps);
+        result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+    } else {
+        // trim start of s2 where it leaves the circle of the s1 offset distance
+        let sp = s1.v2.pos();
+        match seg_circle_intr_nearest_end(s2.v1, s2.v2, center, abs_offset1, true, pos_equal_eps) {
+            Some(intr) => {
+                let bulge = bulge_for_connection(center, sp, intr, connection_arcs_ccw);
+                result.add_or_replace(sp.x, sp.y, bulge, pos_equal_eps);
+                let split = seg_split_at_point(s2.v1, s2.v2, intr, pos_equal_eps);
+                result.add_or_replace(intr.x, intr.y, split.split_vertex.bulge, pos_equal_eps);
+            }
+            None => {
+                // s2 lies entirely inside the circle, connect radially in from the circle
+                let ep = s2.v1.pos();
+                let arc_end = center + (ep - center).normalize().scale(abs_offset1);
+                let bulge = bulge_for_connection(center, sp, arc_end, connection_arcs_ccw);
+                result.add_or_replace(sp.x, sp.y, bulge, pos_equal_eps);
+                result.add_or_replace(arc_end.x, arc_end.y, T::zero(), pos_equal_eps);
+                result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+            }
+        }
+    }
+}
+
+/// Create the raw offset polyline and the source of each vertex using `offset_for_seg` to get the
+/// offset distance for each segment index.
+fn raw_offset_polyline_with_source<T, F>(
+    polyline: &Polyline<T>,
+    offset_for_seg: F,
+    join_style: OffsetJoinStyle<T>,
+    pos_equal_eps: T,
+) -> (Polyline<T>, Vec<OffsetSegSource>)
+where
+    T: Real,
+    F: Fn(usize) -> T,
 {
     if polyline.len() < 2 {
-        return Polyline::new();
+        return (Polyline::new(), Vec::new());
     }
 
-    let raw_offset_segs = create_untrimmed_raw_offset_segs(polyline, offset);
+    let raw_offset_segs: Vec<_> = polyline
+        .iter_segments()
+        .enumerate()
+        .map(|(i, (v1, v2))| raw_offset_seg(v1, v2, offset_for_seg(i)))
+        .collect();
     if raw_offset_segs.is_empty() {
-        return Polyline::new();
+        return (Polyline::new(), Vec::new());
     }
 
     // detect single collapsed arc segment
     if raw_offset_segs.len() == 1 && raw_offset_segs[0].collapsed_arc {
-        return Polyline::new();
+        return (Polyline::new(), Vec::new());
     }
 
-    let connection_arcs_ccw = offset < T::zero();
+    let connection_arcs_ccw = offset_for_seg(0) < T::zero();
     let join_params = JoinParams {
         connection_arcs_ccw,
+        join_style,
         pos_equal_eps,
     };
 
-    let join_seg_pair =
-        |s1: &RawPlineOffsetSeg<T>, s2: &RawPlineOffsetSeg<T>, result: &mut Polyline<T>| {
-            let s1_is_line = s1.v1.bulge_is_zero();
-            let s2_is_line = s2.v1.bulge_is_zero();
+    // joins the raw offset segments at source vertex index `k` (s1 ends and s2 starts at vertex
+    // `k`), vertexes added by the join are sourced from the join except for the last vertex which
+    // starts s2
+    let join_seg_pair = |k: usize,
+                         s1: &RawPlineOffsetSeg<T>,
+                         s2: &RawPlineOffsetSeg<T>,
+                         result: &mut Polyline<T>,
+                         sources: &mut Vec<OffsetSegSource>| {
+        let offset1 = offset_for_seg(polyline.prev_wrapping_index(k));
+        let offset2 = offset_for_seg(k);
+        let s1_is_line = s1.v1.bulge_is_zero();
+        let s2_is_line = s2.v1.bulge_is_zero();
+        if !offset1.fuzzy_eq(offset2)
+            && !s1.collapsed_arc
+            && !s2.collapsed_arc
+            && is_convex_offset_vertex(polyline, k, offset2)
+        {
+            variable_convex_join(s1, s2, offset1.abs(), offset2.abs(), &join_params, result);
+        } else {
             match (s1_is_line, s2_is_line) {
                 (true, true) => line_line_join(s1, s2, &join_params, result),
                 (true, false) => line_arc_join(s1, s2, &join_params, result),
                 (false, true) => arc_line_join(s1, s2, &join_params, result),
                 (false, false) => arc_arc_join(s1, s2, &join_params, result),
             }
-        };
+        }
+
+        if result.len() == sources.len() {
+            // last vertex was replaced by the start of s2
+            *sources.last_mut().unwrap() = OffsetSegSource::Segment(k);
+        } else {
+            while sources.len() < result.len() - 1 {
+                sources.push(OffsetSegSource::Join(k));
+            }
+            sources.push(OffsetSegSource::Segment(k));
+        }
+    };
 
     let mut result = Polyline::with_capacity(polyline.len(), polyline.is_closed());
+    let mut sources = Vec::with_capacity(polyline.len());
 
     // add the very first vertex
     result.add_vertex(raw_offset_segs.first().unwrap().v1);
+    sources.push(OffsetSegSource::Segment(0));
 
     // join first two segments and determine if first vertex was replaced (to know how to handle
     // last two segment joins for closed polyline)
-    let mut offset_seg_pairs = raw_offset_segs.windows(2);
-    if let Some([s1, s2]) = offset_seg_pairs.next() {
-        join_seg_pair(s1, s2, &mut result);
+    let mut offset_seg_pairs = raw_offset_segs.windows(2).enumerate();
+    if let Some((i, [s1, s2])) = offset_seg_pairs.next() {
+        join_seg_pair(i + 1, s1, s2, &mut result, &mut sources);
     }
 
     let first_vertex_replaced = result.len() == 1;
 
-    while let Some([s1, s2]) = offset_seg_pairs.next() {
-        join_seg_pair(s1, s2, &mut result);
+    while let Some((i, [s1, s2])) = offset_seg_pairs.next() {
+        join_seg_pair(i + 1, s1, s2, &mut result, &mut sources);
     }
 
     if polyline.is_closed() && result.len() > 1 {
@@ -543,14 +865,23 @@ where
 
         // temp polyline to capture results of joining (to avoid mutating result)
         let mut closing_part_result = Polyline::new();
+        let mut closing_part_sources = vec![*sources.last().unwrap()];
         closing_part_result.add_vertex(*result.last().unwrap());
-        join_seg_pair(s1, s2, &mut closing_part_result);
+        join_seg_pair(
+            0,
+            s1,
+            s2,
+            &mut closing_part_result,
+            &mut closing_part_sources,
+        );
 
         // update last vertexes
         *result.last_mut().unwrap() = closing_part_result[0];
+        *sources.last_mut().unwrap() = closing_part_sources[0];
         for v in closing_part_result.iter().skip(1) {
             result.add_vertex(*v);
         }
+        sources.extend(closing_part_sources.iter().skip(1));
 
         // update first vertex (only if it has not already been updated/replaced)
         if !first_vertex_replaced {
@@ -591,46 +922,280 @@ where
                 .fuzzy_eq_eps(result.last().unwrap().pos(), pos_equal_eps)
             {
                 result.remove_last();
+                sources.pop();
             }
 
             if result.len() > 1 && result[0].pos().fuzzy_eq_eps(result[1].pos(), pos_equal_eps) {
                 result.remove(0);
+                sources.remove(0);
             }
         }
     } else {
         // not closed polyline or less than 2 vertexes
         let last_raw_offset_vertex = raw_offset_segs.last().unwrap().v2;
         result.add_or_replace_vertex(last_raw_offset_vertex, pos_equal_eps);
+        if sources.len() < result.len() {
+            sources.push(*sources.last().unwrap());
+        }
     }
 
     // if due to joining of segments we are left with only 1 vertex then return empty polyline
     if result.len() == 1 {
         result.clear();
+        sources.clear();
     }
 
-    result
+    debug_assert_eq!(result.len(), sources.len());
+    (result, sources)
+}
+
+/// Test if `point` is at least `offset` distance (minus `offset_tol`) away from all segments of
+/// `polyline`, uses `aabb_index` to only test segments that are nearby.
+#[inline]
+pub fn point_valid_for_offset<T>(
+    polyline: &Polyline<T>,
+    offset: T,
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    query_stack: &mut Vec<usize>,
+    offset_tol: T,
+) -> bool
+where
+    T: Real,
+{
+    let abs_offset = offset.abs() - offset_tol;
+    let min_dist = abs_offset * abs_offset;
+    let mut point_valid = true;
+    let mut visitor = |i: usize| {
+        let j = polyline.next_wrapping_index(i);
+        let closest_point = seg_closest_point(polyline[i], polyline[j], point);
+        let dist = dist_squared(closest_point, point);
+        point_valid = dist > min_dist;
+        if point_valid {
+            Control::Continue
+        } else {
+            Control::Break(())
+        }
+    };
+
+    aabb_index.visit_query_with_stack(
+        point.x - abs_offset,
+        point.y - abs_offset,
+        point.x + abs_offset,
+        point.y + abs_offset,
+        &mut visitor,
+        query_stack,
+    );
+    point_valid
+}
+
+/// Same as [point_valid_for_offset_with_join] but with a different offset distance for each
+/// segment of `polyline` (`offsets` is indexed by segment start index).
+///
+/// Vertexes where the offset distance changes are always joined with a round join (see
+/// [create_variable_raw_offset_polyline]) so `join_style` only applies at vertexes where the
+/// offset distance does not change.
+pub fn point_valid_for_variable_offset<T>(
+    polyline: &Polyline<T>,
+    offsets: &[T],
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    query_stack: &mut Vec<usize>,
+    offset_tol: T,
+    join_style: OffsetJoinStyle<T>,
+) -> bool
+where
+    T: Real,
+{
+    let max_abs_offset = offsets.iter().fold(
+        T::zero(),
+        |acc, d| if d.abs() > acc { d.abs() } else { acc },
+    ) - offset_tol;
+    let last_index = polyline.len() - 1;
+    let use_join = !matches!(join_style, OffsetJoinStyle::Round);
+    let mut point_valid = true;
+    let mut visitor = |i: usize| {
+        let j = polyline.next_wrapping_index(i);
+        let abs_offset = offsets[i].abs() - offset_tol;
+        let closest_point = seg_closest_point(polyline[i], polyline[j], point);
+        let dist = dist_squared(closest_point, point);
+        if dist > abs_offset * abs_offset {
+            return Control::Continue;
+        }
+
+        // closest point at a segment end point is only invalid if within the join region at the
+        // vertex (if the join style applies at the vertex)
+        let vertex_index = if !use_join {
+            None
+        } else if closest_point.fuzzy_eq(polyline[i].pos()) {
+            Some(i)
+        } else if closest_point.fuzzy_eq(polyline[j].pos()) {
+            Some(j)
+        } else {
+            None
+        };
+
+        point_valid = match vertex_index {
+            Some(k) if polyline.is_closed() || (k != 0 && k != last_index) => {
+                let prev = polyline.prev_wrapping_index(k);
+                let next = polyline.next_wrapping_index(k);
+                let offset = offsets[k];
+                if offsets[prev].fuzzy_eq(offset) {
+                    let s1 = raw_offset_seg(polyline[prev], polyline[k], offset);
+                    let s2 = raw_offset_seg(polyline[k], polyline[next], offset);
+                    !point_within_join(&s1, &s2, join_style, point, offset_tol)
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+
+        if point_valid {
+            Control::Continue
+        } else {
+            Control::Break(())
+        }
+    };
+
+    aabb_index.visit_query_with_stack(
+        point.x - max_abs_offset,
+        point.y - max_abs_offset,
+        point.x + max_abs_offset,
+        point.y + max_abs_offset,
+        &mut visitor,
+        query_stack,
+    );
+    point_valid
+}
+
+/// Test if `point` lies strictly inside the join region formed at the original vertex between the
+/// two raw offset segments `s1` and `s2` (circle sector for round joins, kite for miter joins, and
+/// triangle for bevel joins). `offset_tol` is used as the fuzzy epsilon for the test.
+pub(crate) fn point_within_join<T>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    join_style: OffsetJoinStyle<T>,
+    point: Vector2<T>,
+    offset_tol: T,
+) -> bool
+where
+    T: Real,
+{
+    let center = s1.orig_v2_pos;
+    let sp = s1.v2.pos();
+    let ep = s2.v1.pos();
+    let radius = (sp - center).length();
+    let bisector = (sp - center) + (ep - center);
+    let bisector_length = bisector.length();
+
+    let within_circle = || {
+        let r = radius - offset_tol;
+        dist_squared(point, center) < r * r
+    };
+
+    if s1.collapsed_arc || s2.collapsed_arc || bisector_length.fuzzy_eq_zero() {
+        return within_circle();
+    }
+
+    let two = T::two();
+    let miter_point = match join_style {
+        OffsetJoinStyle::Round => return within_circle(),
+        OffsetJoinStyle::Miter { limit } if bisector_length * limit > two * radius => {
+            let miter_length = two * radius * radius / bisector_length;
+            Some(center + bisector.scale(miter_length / bisector_length))
+        }
+        _ => None,
+    };
+
+    // test point is inside the convex polygon (on the inner side of all edges)
+    let inside_edge = |a: Vector2<T>, b: Vector2<T>, sign: T| -> bool {
+        let edge = b - a;
+        let edge_length = edge.length();
+        edge_length.fuzzy_eq_zero() || sign * edge.perp_dot(point - a) / edge_length > offset_tol
+    };
+
+    let sign = if (sp - center).perp_dot(ep - center) > T::zero() {
+        T::one()
+    } else {
+        -T::one()
+    };
+
+    match miter_point {
+        Some(m) => {
+            inside_edge(center, sp, sign)
+                && inside_edge(sp, m, sign)
+                && inside_edge(m, ep, sign)
+                && inside_edge(ep, center, sign)
+        }
+        None => {
+            inside_edge(center, sp, sign)
+                && inside_edge(sp, ep, sign)
+                && inside_edge(ep, center, sign)
+        }
+    }
 }
 
-#[inline]
-fn point_valid_for_offset<T>(
+/// Same as [point_valid_for_offset] but takes into account the `join_style` used to form the
+/// offset. Points near a polyline vertex are only invalid if they are within the join region at
+/// the vertex (e.g. points cut off by a bevel join are valid even though they are closer than
+/// `offset` to the vertex).
+pub fn point_valid_for_offset_with_join<T>(
     polyline: &Polyline<T>,
     offset: T,
     aabb_index: &StaticAABB2DIndex<T>,
     point: Vector2<T>,
     query_stack: &mut Vec<usize>,
     offset_tol: T,
+    join_style: OffsetJoinStyle<T>,
 ) -> bool
 where
     T: Real,
 {
+    if matches!(join_style, OffsetJoinStyle::Round) {
+        return point_valid_for_offset(
+            polyline,
+            offset,
+            aabb_index,
+            point,
+            query_stack,
+            offset_tol,
+        );
+    }
+
     let abs_offset = offset.abs() - offset_tol;
     let min_dist = abs_offset * abs_offset;
+    let last_index = polyline.len() - 1;
     let mut point_valid = true;
     let mut visitor = |i: usize| {
         let j = polyline.next_wrapping_index(i);
         let closest_point = seg_closest_point(polyline[i], polyline[j], point);
         let dist = dist_squared(closest_point, point);
-        point_valid = dist > min_dist;
+        if dist > min_dist {
+            return Control::Continue;
+        }
+
+        // closest point at a segment end point is only invalid if within the join region at
+        // the vertex (end points of open polylines always have round caps)
+        let vertex_index = if closest_point.fuzzy_eq(polyline[i].pos()) {
+            Some(i)
+        } else if closest_point.fuzzy_eq(polyline[j].pos()) {
+            Some(j)
+        } else {
+            None
+        };
+
+        point_valid = match vertex_index {
+            Some(k) if polyline.is_closed() || (k != 0 && k != last_index) => {
+                let prev = polyline.prev_wrapping_index(k);
+                let next = polyline.next_wrapping_index(k);
+                let s1 = raw_offset_seg(polyline[prev], polyline[k], offset);
+                let s2 = raw_offset_seg(polyline[k], polyline[next], offset);
+                !point_within_join(&s1, &s2, join_style, point, offset_tol)
+            }
+            _ => false,
+        };
+
         if point_valid {
             Control::Continue
         } else {
@@ -649,6 +1214,46 @@ where
     point_valid
 }
 
+/// Test if `slice` traverses the same path in the same direction as a slice already in `slices`
+/// (can occur when miter or bevel join segments overlap other raw offset segments).
+pub(crate) fn is_duplicate_slice<T>(
+    raw_offset_polyline: &Polyline<T>,
+    slices: &[OpenPlineSlice<T>],
+    slice: &OpenPlineSlice<T>,
+    pos_equal_eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let slice_length = slice.path_length(raw_offset_polyline);
+    let half_length = slice_length / T::two();
+    slices.iter().any(|other| {
+        if !other
+            .updated_start
+            .pos()
+            .fuzzy_eq_eps(slice.updated_start.pos(), pos_equal_eps)
+            || !other.end_point.fuzzy_eq_eps(slice.end_point, pos_equal_eps)
+        {
+            return false;
+        }
+
+        if !other
+            .path_length(raw_offset_polyline)
+            .fuzzy_eq_eps(slice_length, pos_equal_eps)
+        {
+            return false;
+        }
+
+        match (
+            other.find_point_at_path_length(raw_offset_polyline, half_length),
+            slice.find_point_at_path_length(raw_offset_polyline, half_length),
+        ) {
+            (Ok((_, p1)), Ok((_, p2))) => p1.fuzzy_eq_eps(p2, pos_equal_eps),
+            _ => false,
+        }
+    })
+}
+
 pub fn slices_from_raw_offset<T>(
     original_polyline: &Polyline<T>,
     raw_offset_polyline: &Polyline<T>,
@@ -658,6 +1263,40 @@ pub fn slices_from_raw_offset<T>(
 ) -> Vec<OpenPlineSlice<T>>
 where
     T: Real,
+{
+    let point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
+        point_valid_for_offset_with_join(
+            original_polyline,
+            offset,
+            orig_polyline_index,
+            point,
+            query_stack,
+            options.offset_dist_eps,
+            options.join_style,
+        )
+    };
+
+    slices_from_raw_offset_with_validity(
+        original_polyline,
+        raw_offset_polyline,
+        orig_polyline_index,
+        point_valid_dist,
+        options,
+    )
+}
+
+/// Same as [slices_from_raw_offset] but uses `point_valid_dist` to test if a point is a valid
+/// distance from the original polyline.
+pub(crate) fn slices_from_raw_offset_with_validity<T, F>(
+    original_polyline: &Polyline<T>,
+    raw_offset_polyline: &Polyline<T>,
+    orig_polyline_index: &StaticAABB2DIndex<T>,
+    point_valid_dist: F,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<OpenPlineSlice<T>>
+where
+    T: Real,
+    F: Fn(Vector2<T>, &mut Vec<usize>) -> bool,
 {
     debug_assert!(
         raw_offset_polyline.is_closed(),
@@ -670,7 +1309,6 @@ where
     }
 
     let pos_equal_eps = options.pos_equal_eps;
-    let offset_dist_eps = options.offset_dist_eps;
 
     let raw_offset_index = raw_offset_polyline.create_approx_aabb_index().unwrap();
     let self_intrs =
@@ -679,14 +1317,7 @@ where
     let mut query_stack = Vec::new();
     if self_intrs.is_empty() {
         // no self intersects, test point on polyline is valid
-        if !point_valid_for_offset(
-            &original_polyline,
-            offset,
-            &orig_polyline_index,
-            raw_offset_polyline[0].pos(),
-            &mut query_stack,
-            offset_dist_eps,
-        ) {
+        if !point_valid_dist(raw_offset_polyline[0].pos(), &mut query_stack) {
             // not valid
             return result;
         }
@@ -751,17 +1382,6 @@ where
             has_intersect
         };
 
-    let point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
-        point_valid_for_offset(
-            original_polyline,
-            offset,
-            &orig_polyline_index,
-            point,
-            query_stack,
-            offset_dist_eps,
-        )
-    };
-
     let slice_is_valid = |slice: &OpenPlineSlice<T>, query_stack: &mut Vec<usize>| -> bool {
         if slice.end_index_offset == 0 {
             // slice all on one segment, test start, end, midpoint, and if it intersects the
@@ -831,6 +1451,12 @@ where
         }
     };
 
+    // only miter and bevel joins may create duplicate slices
+    let check_duplicates = !matches!(options.join_style, OffsetJoinStyle::Round);
+    let is_duplicate = |slices: &[OpenPlineSlice<T>], slice: &OpenPlineSlice<T>| -> bool {
+        check_duplicates && is_duplicate_slice(raw_offset_polyline, slices, slice, pos_equal_eps)
+    };
+
     for (&start_index, intr_list) in intersects_lookup.iter() {
         let mut intr_list_iter = intr_list.windows(2);
         while let Some(&[intr1, intr2]) = intr_list_iter.next() {
@@ -844,7 +1470,7 @@ where
             );
 
             if let Some(s) = slice {
-                if slice_is_valid(&s, &mut query_stack) {
+                if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                     result.push(s);
                 }
             }
@@ -871,7 +1497,7 @@ where
         );
 
         if let Some(s) = slice {
-            if slice_is_valid(&s, &mut query_stack) {
+            if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                 result.push(s);
             }
         }
@@ -970,6 +1596,45 @@ pub fn slices_from_dual_raw_offsets<T>(
 ) -> Vec<OpenPlineSlice<T>>
 where
     T: Real,
+{
+    let point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
+        point_valid_for_offset_with_join(
+            original_polyline,
+            offset,
+            orig_polyline_index,
+            point,
+            query_stack,
+            options.offset_dist_eps,
+            options.join_style,
+        )
+    };
+
+    slices_from_dual_raw_offsets_with_validity(
+        original_polyline,
+        raw_offset_polyline,
+        dual_raw_offset_polyline,
+        orig_polyline_index,
+        (offset.abs(), offset.abs()),
+        point_valid_dist,
+        options,
+    )
+}
+
+/// Same as [slices_from_dual_raw_offsets] but uses `point_valid_dist` to test if a point is a
+/// valid distance from the original polyline. `end_circle_radii` holds the offset distance at the
+/// start and end of the original polyline (only used for open polylines).
+pub(crate) fn slices_from_dual_raw_offsets_with_validity<T, F>(
+    original_polyline: &Polyline<T>,
+    raw_offset_polyline: &Polyline<T>,
+    dual_raw_offset_polyline: &Polyline<T>,
+    orig_polyline_index: &StaticAABB2DIndex<T>,
+    end_circle_radii: (T, T),
+    point_valid_dist: F,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<OpenPlineSlice<T>>
+where
+    T: Real,
+    F: Fn(Vector2<T>, &mut Vec<usize>) -> bool,
 {
     let mut result = Vec::new();
     if raw_offset_polyline.len() < 2 {
@@ -977,7 +1642,6 @@ where
     }
 
     let pos_equal_eps = options.pos_equal_eps;
-    let offset_dist_eps = options.offset_dist_eps;
 
     let raw_offset_index = raw_offset_polyline.create_approx_aabb_index().unwrap();
 
@@ -1007,11 +1671,10 @@ where
     if !original_polyline.is_closed() {
         // add intersects between circles generated at original open polyline end points and raw
         // offset polyline
-        let circle_radius = offset.abs();
         visit_circle_intersects(
             raw_offset_polyline,
             original_polyline[0].pos(),
-            circle_radius,
+            end_circle_radii.0,
             &raw_offset_index,
             &mut add_intr,
             &options,
@@ -1019,7 +1682,7 @@ where
         visit_circle_intersects(
             raw_offset_polyline,
             original_polyline.last().unwrap().pos(),
-            circle_radius,
+            end_circle_radii.1,
             &raw_offset_index,
             &mut add_intr,
             &options,
@@ -1043,14 +1706,7 @@ where
 
     if intersects_lookup.is_empty() {
         // test a point on raw offset polyline
-        if !point_valid_for_offset(
-            original_polyline,
-            offset,
-            &orig_polyline_index,
-            raw_offset_polyline[0].pos(),
-            &mut query_stack,
-            offset_dist_eps,
-        ) {
+        if !point_valid_dist(raw_offset_polyline[0].pos(), &mut query_stack) {
             return result;
         }
 
@@ -1099,17 +1755,6 @@ where
             has_intersect
         };
 
-    let point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
-        point_valid_for_offset(
-            original_polyline,
-            offset,
-            &orig_polyline_index,
-            point,
-            query_stack,
-            offset_dist_eps,
-        )
-    };
-
     let slice_is_valid = |slice: &OpenPlineSlice<T>, query_stack: &mut Vec<usize>| -> bool {
         if slice.end_index_offset == 0 {
             // slice all on one segment, test start, end, midpoint, and if it intersects the
@@ -1179,6 +1824,12 @@ where
         }
     };
 
+    // only miter and bevel joins may create duplicate slices
+    let check_duplicates = !matches!(options.join_style, OffsetJoinStyle::Round);
+    let is_duplicate = |slices: &[OpenPlineSlice<T>], slice: &OpenPlineSlice<T>| -> bool {
+        check_duplicates && is_duplicate_slice(raw_offset_polyline, slices, slice, pos_equal_eps)
+    };
+
     if !original_polyline.is_closed() {
         // build first slice that ends at the first intersect since we will not wrap back to
         // capture it as in the case of a closed polyline
@@ -1194,7 +1845,7 @@ where
         );
 
         if let Some(s) = slice {
-            if slice_is_valid(&s, &mut query_stack) {
+            if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                 result.push(s);
             }
         }
@@ -1213,7 +1864,7 @@ where
             );
 
             if let Some(s) = slice {
-                if slice_is_valid(&s, &mut query_stack) {
+                if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                     result.push(s);
                 }
             }
@@ -1239,7 +1890,7 @@ where
                     pos_equal_eps,
                 );
                 if let Some(s) = slice {
-                    if slice_is_valid(&s, &mut query_stack) {
+                    if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                         result.push(s);
                     }
                 }
@@ -1256,7 +1907,7 @@ where
         );
 
         if let Some(s) = slice {
-            if slice_is_valid(&s, &mut query_stack) {
+            if slice_is_valid(&s, &mut query_stack) && !is_duplicate(&result, &s) {
                 result.push(s);
             }
         }
@@ -1274,17 +1925,66 @@ pub fn stitch_slices_together<T>(
 ) -> Vec<Polyline<T>>
 where
     T: Real,
+{
+    stitch_slices_from_sources(
+        &[(raw_offset_pline, orig_max_index)],
+        slices,
+        |_| 0,
+        is_closed,
+        options.pos_equal_eps,
+        options.slice_join_eps,
+    )
+    .into_iter()
+    .map(|s| s.pline)
+    .collect()
+}
+
+/// Polyline formed by stitching slices together, see [stitch_slices_from_sources].
+#[derive(Debug, Clone)]
+pub(crate) struct StitchedPline<T> {
+    /// The stitched polyline.
+    pub pline: Polyline<T>,
+    /// Index of the source polyline segment that each vertex of `pline` starts (same length as
+    /// `pline`, for an open polyline the last vertex has the index of the last segment).
+    pub seg_indexes: Vec<usize>,
+}
+
+/// Same as [stitch_slices_together] but the slices may be from multiple source polylines.
+///
+/// `sources` holds each source polyline along with the max index used to compute forward wrapping
+/// index distances (see [stitch_slices_together]), `slice_source(i)` returns the index in
+/// `sources` of the polyline that `slices[i]` was created from. When more than one slice may be
+/// stitched on next the slices from the same source polyline are prioritized.
+///
+/// The source segment index of every stitched vertex is recorded (slices record the source segment
+/// they start on), so the segments of the result can be traced back to the source polylines.
+pub(crate) fn stitch_slices_from_sources<T, S>(
+    sources: &[(&Polyline<T>, usize)],
+    slices: &[OpenPlineSlice<T>],
+    slice_source: S,
+    is_closed: bool,
+    pos_equal_eps: T,
+    join_eps: T,
+) -> Vec<StitchedPline<T>>
+where
+    T: Real,
+    S: Fn(usize) -> usize,
 {
     let mut result = Vec::new();
     if slices.is_empty() {
         return result;
     }
 
-    let join_eps = options.slice_join_eps;
-    let pos_equal_eps = options.pos_equal_eps;
-
     if slices.len() == 1 {
-        let mut pline = slices[0].to_polyline(raw_offset_pline, pos_equal_eps);
+        let mut pline = Polyline::new();
+        let mut seg_indexes = Vec::new();
+        stitch_slice_onto(
+            &slices[0],
+            sources[slice_source(0)].0,
+            &mut pline,
+            &mut seg_indexes,
+            pos_equal_eps,
+        );
 
         if is_closed
             && pline[0]
@@ -1293,9 +1993,10 @@ where
         {
             pline.set_is_closed(true);
             pline.remove_last();
+            seg_indexes.pop();
         }
 
-        result.push(pline);
+        result.push(StitchedPline { pline, seg_indexes });
 
         return result;
     }
@@ -1326,6 +2027,7 @@ where
         visited_indexes[i] = true;
 
         let mut current_pline = Polyline::new();
+        let mut current_seg_indexes = Vec::new();
         let mut current_index = i;
         let initial_start_point = slices[i].updated_start.pos();
         let mut loop_count = 0;
@@ -1339,7 +2041,15 @@ where
 
             // append current slice to current pline
             let current_slice = &slices[current_index];
-            current_slice.stitch_onto(raw_offset_pline, &mut current_pline, pos_equal_eps);
+            let current_source = slice_source(current_index);
+            let (source_pline, orig_max_index) = sources[current_source];
+            stitch_slice_onto(
+                current_slice,
+                source_pline,
+                &mut current_pline,
+                &mut current_seg_indexes,
+                pos_equal_eps,
+            );
 
             let current_loop_start_index = current_slice.start_index;
             let current_end_point = current_slice.end_point;
@@ -1361,7 +2071,11 @@ where
 
             let get_index_dist = |i: usize| -> usize {
                 let slice = &slices[i];
-                if current_loop_start_index <= slice.start_index {
+                if slice_source(i) != current_source {
+                    // slices from other sources are stitched on after all slices from the same
+                    // source
+                    usize::MAX
+                } else if current_loop_start_index <= slice.start_index {
                     slice.start_index - current_loop_start_index
                 } else {
                     // forward wrapping distance (distance to end + distance to index)
@@ -1389,10 +2103,14 @@ where
                     let current_pline_ep = current_pline.last().unwrap().pos();
                     if is_closed && current_pline_sp.fuzzy_eq_eps(current_pline_ep, pos_equal_eps) {
                         current_pline.remove_last();
+                        current_seg_indexes.pop();
                         current_pline.set_is_closed(true);
                     }
 
-                    result.push(current_pline);
+                    result.push(StitchedPline {
+                        pline: current_pline,
+                        seg_indexes: current_seg_indexes,
+                    });
                 }
                 break;
             }
@@ -1400,6 +2118,7 @@ where
             // else continue stitching
             visited_indexes[query_results[0]] = true;
             current_pline.remove_last();
+            current_seg_indexes.pop();
             current_index = query_results[0];
         }
     }
@@ -1407,6 +2126,103 @@ where
     result
 }
 
+/// Same as [PolylineSlice::stitch_onto] but also pushes the `source` segment index of each vertex
+/// stitched onto `target` to `seg_indexes` (a vertex replacing the last vertex of `target` replaces
+/// its segment index).
+fn stitch_slice_onto<T>(
+    slice: &OpenPlineSlice<T>,
+    source: &Polyline<T>,
+    target: &mut Polyline<T>,
+    seg_indexes: &mut Vec<usize>,
+    pos_equal_eps: T,
+) where
+    T: Real,
+{
+    debug_assert!(!slice.inverted, "offset slices are never inverted");
+    target.reserve(slice.vertex_count());
+    let mut seg_index = slice.start_index;
+    let mut remaining = slice.end_index_offset;
+    let mut visitor = |v: PlineVertex<T>| {
+        let len = target.len();
+        target.add_or_replace_vertex(v, pos_equal_eps);
+        if target.len() > len {
+            seg_indexes.push(seg_index);
+        } else {
+            *seg_indexes.last_mut().unwrap() = seg_index;
+        }
+
+        // end point of the slice lies on the last segment
+        if remaining > 0 {
+            remaining -= 1;
+            seg_index = source.next_wrapping_index(seg_index);
+        }
+    };
+
+    slice.visit_vertexes(source, &mut visitor);
+}
+
+/// Create the parallel offset polylines from the `raw_offset` of `polyline` (created with
+/// [create_raw_offset_polyline]), each result records the raw offset segment index of its
+/// vertexes.
+fn offset_from_raw_offset<T>(
+    polyline: &Polyline<T>,
+    raw_offset: &Polyline<T>,
+    offset: T,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<StitchedPline<T>>
+where
+    T: Real,
+{
+    if raw_offset.is_empty() {
+        return Vec::new();
+    }
+
+    let constructed_index;
+    let index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    if polyline.is_closed() && !options.handle_self_intersects {
+        let slices = slices_from_raw_offset(polyline, raw_offset, index, offset, options);
+        stitch_slices_from_sources(
+            &[(raw_offset, raw_offset.len() - 1)],
+            &slices,
+            |_| 0,
+            true,
+            options.pos_equal_eps,
+            options.slice_join_eps,
+        )
+    } else {
+        // dual raw offset is only used to find intersects, always use round joins
+        let dual_raw_offset = create_raw_offset_polyline(
+            polyline,
+            -offset,
+            OffsetJoinStyle::Round,
+            options.pos_equal_eps,
+        );
+        let slices = slices_from_dual_raw_offsets(
+            polyline,
+            raw_offset,
+            &dual_raw_offset,
+            index,
+            offset,
+            options,
+        );
+
+        stitch_slices_from_sources(
+            &[(raw_offset, raw_offset.len())],
+            &slices,
+            |_| 0,
+            polyline.is_closed(),
+            options.pos_equal_eps,
+            options.slice_join_eps,
+        )
+    }
+}
+
 pub fn parallel_offset<T>(
     polyline: &Polyline<T>,
     offset: T,
@@ -1423,6 +2239,103 @@ where
         "bug: input assumed to not have repeat position vertexes"
     );
 
+    let raw_offset =
+        create_raw_offset_polyline(polyline, offset, options.join_style, options.pos_equal_eps);
+    let result: Vec<_> = offset_from_raw_offset(polyline, &raw_offset, offset, options)
+        .into_iter()
+        .map(|s| s.pline)
+        .collect();
+
+    debug_assert!(
+        result
+            .iter()
+            .all(|p| p.remove_repeat_pos(options.pos_equal_eps).len() == p.len()),
+        "bug: result should never have repeat position vertexes"
+    );
+
+    result
+}
+
+/// Same as [parallel_offset] but validates the input polyline and returns an error if the offset
+/// of a closed polyline failed to stitch together into closed polylines.
+///
+/// An empty result is returned only if the offset collapsed to nothing.
+pub fn try_parallel_offset<T>(
+    polyline: &Polyline<T>,
+    offset: T,
+    options: &PlineOffsetOptions<T>,
+) -> Result<Vec<Polyline<T>>, PlineOpError>
+where
+    T: Real,
+{
+    polyline.validate_as_input(0, options.pos_equal_eps)?;
+
+    let mut result = parallel_offset(polyline, offset, options);
+    if polyline.is_closed() {
+        // open results too short to be dangling chains are discarded
+        result.retain(|p| p.is_closed() || is_dangling_chain(p, options.slice_join_eps));
+        let count = result.iter().filter(|p| !p.is_closed()).count();
+        if count > 0 {
+            return Err(PlineOpError::DanglingSlices { count });
+        }
+    }
+
+    Ok(result)
+}
+
+/// Compute the parallel offset polylines of `polyline` using a different offset distance for each
+/// segment (`offsets` is indexed the same as [Polyline::iter_segment_indexes]).
+///
+/// All `offsets` must be finite, non-zero, and have the same sign. [PlineOffsetOptions::join_style]
+/// is used to join segments where the offset distance does not change, see
+/// [create_variable_raw_offset_polyline].
+///
+/// Returns [PlineOpError::OffsetCountMismatch] if the number of `offsets` does not match the
+/// segment count of `polyline` and [PlineOpError::InvalidOffset] for the first offset that is not
+/// finite, is zero, or has a different sign than the first offset.
+pub fn parallel_offset_variable<T>(
+    polyline: &Polyline<T>,
+    offsets: &[T],
+    options: &PlineOffsetOptions<T>,
+) -> Result<Vec<Polyline<T>>, PlineOpError>
+where
+    T: Real,
+{
+    if polyline.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    let segment_count = polyline.iter_segment_indexes().count();
+    if offsets.len() != segment_count {
+        return Err(PlineOpError::OffsetCountMismatch {
+            expected: segment_count,
+            actual: offsets.len(),
+        });
+    }
+
+    let is_pos = offsets[0] > T::zero();
+    if let Some(segment_index) = offsets
+        .iter()
+        .position(|&d| !d.is_finite() || d == T::zero() || (d > T::zero()) != is_pos)
+    {
+        return Err(PlineOpError::InvalidOffset { segment_index });
+    }
+
+    debug_assert!(
+        polyline.remove_repeat_pos(options.pos_equal_eps).len() == polyline.len(),
+        "bug: input assumed to not have repeat position vertexes"
+    );
+
+    let raw_offset = create_variable_raw_offset_polyline(
+        polyline,
+        offsets,
+        options.join_style,
+        options.pos_equal_eps,
+    );
+    if raw_offset.is_empty() {
+        return Ok(Vec::new());
+    }
+
     let constructed_index;
     let index = if let Some(x) = options.aabb_index {
         x
@@ -1431,38 +2344,110 @@ where
         &constructed_index
     };
 
-    let raw_offset = create_raw_offset_polyline(polyline, offset, options.pos_equal_eps);
-    let result = if raw_offset.is_empty() {
-        Vec::new()
-    } else if polyline.is_closed() && !options.handle_self_intersects {
-        let slices = slices_from_raw_offset(polyline, &raw_offset, index, offset, options);
-        stitch_slices_together(&raw_offset, &slices, true, raw_offset.len() - 1, options)
+    let point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
+        point_valid_for_variable_offset(
+            polyline,
+            offsets,
+            index,
+            point,
+            query_stack,
+            options.offset_dist_eps,
+            options.join_style,
+        )
+    };
+
+    if polyline.is_closed() && !options.handle_self_intersects {
+        let slices = slices_from_raw_offset_with_validity(
+            polyline,
+            &raw_offset,
+            index,
+            point_valid_dist,
+            options,
+        );
+        Ok(stitch_slices_together(
+            &raw_offset,
+            &slices,
+            true,
+            raw_offset.len() - 1,
+            options,
+        ))
     } else {
-        let dual_raw_offset = create_raw_offset_polyline(polyline, -offset, options.pos_equal_eps);
-        let slices = slices_from_dual_raw_offsets(
+        // dual raw offset is only used to find intersects, always use round joins
+        let dual_offsets: Vec<T> = offsets.iter().map(|&d| -d).collect();
+        let dual_raw_offset = create_variable_raw_offset_polyline(
+            polyline,
+            &dual_offsets,
+            OffsetJoinStyle::Round,
+            options.pos_equal_eps,
+        );
+        let end_circle_radii = (offsets[0].abs(), offsets[offsets.len() - 1].abs());
+        let slices = slices_from_dual_raw_offsets_with_validity(
             polyline,
             &raw_offset,
             &dual_raw_offset,
             index,
-            offset,
+            end_circle_radii,
+            point_valid_dist,
             options,
         );
 
-        stitch_slices_together(
+        Ok(stitch_slices_together(
             &raw_offset,
             &slices,
             polyline.is_closed(),
             raw_offset.len(),
             options,
-        )
-    };
+        ))
+    }
+}
 
+/// Same as [parallel_offset] but also returns the source of every offset polyline vertex.
+///
+/// Offset polylines are always formed from slices of the raw offset polyline, the raw offset
+/// segment each slice starts on is carried through stitching so the source of each offset polyline
+/// segment is the source of the raw offset segment it was sliced from.
+pub fn parallel_offset_with_source<T>(
+    polyline: &Polyline<T>,
+    offset: T,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<OffsetPolylineWithSource<T>>
+where
+    T: Real,
+{
+    if polyline.len() < 2 {
+        return Vec::new();
+    }
     debug_assert!(
-        result
-            .iter()
-            .all(|p| p.remove_repeat_pos(options.pos_equal_eps).len() == p.len()),
-        "bug: result should never have repeat position vertexes"
+        polyline.remove_repeat_pos(options.pos_equal_eps).len() == polyline.len(),
+        "bug: input assumed to not have repeat position vertexes"
     );
 
-    result
+    let (raw_offset, raw_sources) = create_raw_offset_polyline_with_source(
+        polyline,
+        offset,
+        options.join_style,
+        options.pos_equal_eps,
+    );
+
+    offset_from_raw_offset(polyline, &raw_offset, offset, options)
+        .into_iter()
+        .map(|stitched| {
+            let pline = stitched.pline;
+            let mut sources: Vec<_> = stitched
+                .seg_indexes
+                .iter()
+                .map(|&i| raw_sources[i])
+                .collect();
+
+            if !pline.is_closed() {
+                let n = sources.len();
+                sources[n - 1] = sources[n - 2];
+            }
+
+            OffsetPolylineWithSource {
+                polyline: pline,
+                sources,
+            }
+        })
+        .collect()
 }
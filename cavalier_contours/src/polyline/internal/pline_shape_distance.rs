@@ -0,0 +1,374 @@
+use crate::{
+    core::{
+        math::{angle, angle_from_bulge, point_on_circle, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        seg_arc_radius_and_center, seg_closest_point, seg_length, PlineShapeDistanceOptions,
+        PlineVertex, Polyline, ShapeDistanceResult,
+    },
+};
+use static_aabb2d_index::{Control, NeighborPriorityQueue, StaticAABB2DIndex};
+
+/// Point sampled along a polyline segment.
+#[derive(Debug, Copy, Clone)]
+struct SegSample<T>
+where
+    T: Real,
+{
+    pos: Vector2<T>,
+    seg_start_index: usize,
+    /// Parametric position along the segment (0 at the start vertex, 1 at the end vertex).
+    t: T,
+    /// Parametric distance to the neighboring samples along the segment.
+    step: T,
+}
+
+/// Returns the point at the parametric position `t` along the segment (arcs are parameterized by
+/// sweep angle).
+fn seg_point_at<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, t: T) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        return v1.pos() + (v2.pos() - v1.pos()).scale(t);
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    point_on_circle(radius, center, start_angle + angle_from_bulge(v1.bulge) * t)
+}
+
+/// Sample points in order along all the segments of `pline`, at most `spacing` apart. Every vertex
+/// is sampled and the end of the path is always the last sample (for closed polylines this is the
+/// first vertex position repeated).
+fn sample_pline<T>(pline: &Polyline<T>, spacing: T) -> Vec<SegSample<T>>
+where
+    T: Real,
+{
+    let mut samples = Vec::new();
+    if pline.len() == 1 {
+        samples.push(SegSample {
+            pos: pline[0].pos(),
+            seg_start_index: 0,
+            t: T::zero(),
+            step: T::zero(),
+        });
+        return samples;
+    }
+
+    let mut last_seg = 0;
+    for (i, j) in pline.iter_segment_indexes() {
+        let v1 = pline[i];
+        let v2 = pline[j];
+        let count = (seg_length(v1, v2) / spacing)
+            .ceil()
+            .to_usize()
+            .unwrap_or(1)
+            .max(1);
+        let step = T::one() / T::from(count).unwrap();
+        for k in 0..count {
+            let t = step * T::from(k).unwrap();
+            samples.push(SegSample {
+                pos: seg_point_at(v1, v2, t),
+                seg_start_index: i,
+                t,
+                step,
+            });
+        }
+        last_seg = i;
+    }
+
+    let step = match samples.last() {
+        Some(s) => s.step,
+        None => T::one(),
+    };
+    samples.push(SegSample {
+        pos: pline[pline.next_wrapping_index(last_seg)].pos(),
+        seg_start_index: last_seg,
+        t: T::one(),
+        step,
+    });
+
+    samples
+}
+
+/// Finds the closest point on a polyline to query points using a spatial index of its segments.
+struct ClosestPointFinder<'a, T>
+where
+    T: Real,
+{
+    pline: &'a Polyline<T>,
+    aabb_index: Option<StaticAABB2DIndex<T>>,
+    queue: NeighborPriorityQueue<T>,
+}
+
+impl<'a, T> ClosestPointFinder<'a, T>
+where
+    T: Real,
+{
+    fn new(pline: &'a Polyline<T>) -> Self {
+        Self {
+            pline,
+            aabb_index: pline.create_approx_aabb_index(),
+            queue: NeighborPriorityQueue::new(),
+        }
+    }
+
+    /// Returns the closest segment start index, closest point, and distance to `point`. The search
+    /// stops early once a point within `stop_dist` is found (the result is then not necessarily the
+    /// closest but is within `stop_dist`).
+    fn find(&mut self, point: Vector2<T>, stop_dist: T) -> (usize, Vector2<T>, T) {
+        let pline = self.pline;
+        let index = match &self.aabb_index {
+            Some(index) => index,
+            None => {
+                let p = pline[0].pos();
+                return (0, p, (point - p).length());
+            }
+        };
+
+        let stop_dist_squared = stop_dist * stop_dist;
+        let mut result = (0, pline[0].pos(), Real::max_value());
+        let mut visitor = |k: usize, box_dist_squared: T| {
+            if box_dist_squared >= result.2 {
+                return Control::Break(());
+            }
+
+            let cp = seg_closest_point(pline[k], pline[pline.next_wrapping_index(k)], point);
+            let dist_squared = (cp - point).length_squared();
+            if dist_squared < result.2 {
+                result = (k, cp, dist_squared);
+                if dist_squared <= stop_dist_squared {
+                    return Control::Break(());
+                }
+            }
+
+            Control::Continue
+        };
+        index.visit_neighbors_with_queue(point.x, point.y, &mut visitor, &mut self.queue);
+
+        (result.0, result.1, result.2.sqrt())
+    }
+}
+
+/// Returns the sample spacing to use for the polylines given.
+fn sample_spacing<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineShapeDistanceOptions<T>,
+) -> T
+where
+    T: Real,
+{
+    match options.sample_spacing {
+        Some(s) if s > T::zero() => s,
+        _ => {
+            let mut size = T::zero();
+            for e in [pline1.extents(), pline2.extents()].iter().flatten() {
+                size = num_traits::real::Real::max(
+                    num_traits::real::Real::max(size, e.max_x - e.min_x),
+                    e.max_y - e.min_y,
+                );
+            }
+            size / T::from(1024.0).unwrap()
+        }
+    }
+}
+
+/// Compute the directed Hausdorff distance from `pline1` to `pline2`, the maximum distance from
+/// any point on `pline1` to the closest point on `pline2`.
+///
+/// `pline1` is sampled at most [PlineShapeDistanceOptions::sample_spacing] apart and the closest
+/// point on `pline2` to each sample is found using a spatial index (exact for lines and arcs). The
+/// search for a sample stops as soon as it is found to be closer than the current maximum. The
+/// farthest sample is then refined by a golden section search between its neighboring samples. The
+/// value returned is achieved by the witness points so it never overestimates the true distance
+/// and underestimates it by at most half the sample spacing.
+///
+/// Returns `None` if either polyline is empty.
+pub fn directed_hausdorff_distance<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineShapeDistanceOptions<T>,
+) -> Option<ShapeDistanceResult<T>>
+where
+    T: Real,
+{
+    if pline1.is_empty() || pline2.is_empty() {
+        return None;
+    }
+
+    let spacing = sample_spacing(pline1, pline2, options);
+    let samples = sample_pline(pline1, spacing);
+    let mut finder = ClosestPointFinder::new(pline2);
+
+    let mut farthest = 0;
+    let (k, cp, dist) = finder.find(samples[0].pos, T::zero());
+    let mut result = ShapeDistanceResult {
+        seg_start_index1: samples[0].seg_start_index,
+        point1: samples[0].pos,
+        seg_start_index2: k,
+        point2: cp,
+        distance: dist,
+    };
+
+    for (i, s) in samples.iter().enumerate().skip(1) {
+        let (k, cp, dist) = finder.find(s.pos, result.distance);
+        if dist > result.distance {
+            farthest = i;
+            result = ShapeDistanceResult {
+                seg_start_index1: s.seg_start_index,
+                point1: s.pos,
+                seg_start_index2: k,
+                point2: cp,
+                distance: dist,
+            };
+        }
+    }
+
+    // refine between the neighboring samples of the farthest sample
+    let s = samples[farthest];
+    if s.step > T::zero() {
+        let v1 = pline1[s.seg_start_index];
+        let v2 = pline1[pline1.next_wrapping_index(s.seg_start_index)];
+        let mut eval = |t: T| {
+            let p = seg_point_at(v1, v2, t);
+            let (k, cp, dist) = finder.find(p, T::zero());
+            (p, k, cp, dist)
+        };
+
+        let inv_phi = T::from(0.618_033_988_749_894_9).unwrap();
+        let mut a = num_traits::real::Real::max(s.t - s.step, T::zero());
+        let mut b = num_traits::real::Real::min(s.t + s.step, T::one());
+        let mut c = b - (b - a) * inv_phi;
+        let mut d = a + (b - a) * inv_phi;
+        let mut fc = eval(c);
+        let mut fd = eval(d);
+        for _ in 0..40 {
+            if fc.3 > fd.3 {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - (b - a) * inv_phi;
+                fc = eval(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + (b - a) * inv_phi;
+                fd = eval(d);
+            }
+        }
+
+        for (p, k, cp, dist) in [fc, fd].iter().copied() {
+            if dist > result.distance {
+                result = ShapeDistanceResult {
+                    seg_start_index1: s.seg_start_index,
+                    point1: p,
+                    seg_start_index2: k,
+                    point2: cp,
+                    distance: dist,
+                };
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Compute the (symmetric) Hausdorff distance between `pline1` and `pline2`, the maximum of the
+/// directed Hausdorff distances in both directions (see [directed_hausdorff_distance]). The witness
+/// `point1` is always on `pline1` and `point2` is always on `pline2`.
+///
+/// Returns `None` if either polyline is empty.
+pub fn hausdorff_distance<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineShapeDistanceOptions<T>,
+) -> Option<ShapeDistanceResult<T>>
+where
+    T: Real,
+{
+    let forward = directed_hausdorff_distance(pline1, pline2, options)?;
+    let backward = directed_hausdorff_distance(pline2, pline1, options)?;
+    if forward.distance >= backward.distance {
+        return Some(forward);
+    }
+
+    Some(ShapeDistanceResult {
+        seg_start_index1: backward.seg_start_index2,
+        point1: backward.point2,
+        seg_start_index2: backward.seg_start_index1,
+        point2: backward.point1,
+        distance: backward.distance,
+    })
+}
+
+/// Compute the discrete Fréchet distance between `pline1` and `pline2`.
+///
+/// Both polylines are sampled in order along their path at most
+/// [PlineShapeDistanceOptions::sample_spacing] apart (every vertex is sampled) and the discrete
+/// Fréchet distance between the two sample sequences is computed by dynamic programming (quadratic
+/// in the number of samples). Unlike the Hausdorff distance the direction and start point of the
+/// polylines matter: closed polylines are traversed starting and ending at their first vertex. The
+/// witness points are the pair of samples that realize the distance.
+///
+/// Returns `None` if either polyline is empty.
+pub fn frechet_distance<T>(
+    pline1: &Polyline<T>,
+    pline2: &Polyline<T>,
+    options: &PlineShapeDistanceOptions<T>,
+) -> Option<ShapeDistanceResult<T>>
+where
+    T: Real,
+{
+    if pline1.is_empty() || pline2.is_empty() {
+        return None;
+    }
+
+    let spacing = sample_spacing(pline1, pline2, options);
+    let samples1 = sample_pline(pline1, spacing);
+    let samples2 = sample_pline(pline2, spacing);
+
+    // each cell holds the coupling distance and the sample index pair realizing it
+    let n = samples2.len();
+    let mut prev: Vec<(T, usize, usize)> = Vec::with_capacity(n);
+    let mut curr: Vec<(T, usize, usize)> = Vec::with_capacity(n);
+    for (i, s1) in samples1.iter().enumerate() {
+        curr.clear();
+        for (j, s2) in samples2.iter().enumerate() {
+            let best_prev = match (i, j) {
+                (0, 0) => None,
+                (0, _) => Some(curr[j - 1]),
+                (_, 0) => Some(prev[0]),
+                _ => {
+                    let mut best = prev[j - 1];
+                    for &c in [prev[j], curr[j - 1]].iter() {
+                        if c.0 < best.0 {
+                            best = c;
+                        }
+                    }
+                    Some(best)
+                }
+            };
+
+            let dist = (s2.pos - s1.pos).length();
+            let cell = match best_prev {
+                Some(c) if c.0 > dist => c,
+                _ => (dist, i, j),
+            };
+            curr.push(cell);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let (distance, i, j) = prev[n - 1];
+    Some(ShapeDistanceResult {
+        seg_start_index1: samples1[i].seg_start_index,
+        point1: samples1[i].pos,
+        seg_start_index2: samples2[j].seg_start_index,
+        point2: samples2[j].pos,
+        distance,
+    })
+}
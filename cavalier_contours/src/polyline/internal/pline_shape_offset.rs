@@ -0,0 +1,508 @@
+use super::pline_offset::{point_valid_for_offset_with_join, stitch_slices_from_sources};
+use crate::{
+    core::{
+        math::{dist_squared, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        pline_seg_intr, seg_fast_approx_bounding_box, seg_midpoint, FindIntersectsOptions,
+        IndexedPolyline, OffsetLoopNode, OffsetLoopTree, OpenPlineSlice, PlineOffsetOptions,
+        PlineSegIntr, PlineVertex, Polyline, PolylineSlice, Shape, ShapeIterativeOffsetOptions,
+        ShapeOffsetOptions,
+    },
+};
+use static_aabb2d_index::{Control, StaticAABB2DIndexBuilder};
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// Compute the parallel offset of `shape`, see [Shape::parallel_offset_opt].
+pub fn shape_parallel_offset<T>(
+    shape: &Shape<T>,
+    offset: T,
+    options: &ShapeOffsetOptions<T>,
+) -> Shape<T>
+where
+    T: Real,
+{
+    let plines_index = match shape.plines_index {
+        Some(ref i) => i,
+        None => return Shape::empty(),
+    };
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let offset_dist_eps = options.offset_dist_eps;
+    let slice_join_eps = options.slice_join_eps;
+
+    // offset every polyline on its own (resulting loops are free of self intersects and valid
+    // relative to the polyline they were created from)
+    let mut offset_loops = Vec::new();
+    for (ipline, is_ccw) in shape
+        .ccw_plines
+        .iter()
+        .map(|p| (p, true))
+        .chain(shape.cw_plines.iter().map(|p| (p, false)))
+    {
+        let pline_options = PlineOffsetOptions {
+            aabb_index: Some(&ipline.spatial_index),
+            handle_self_intersects: false,
+            pos_equal_eps,
+            slice_join_eps,
+            offset_dist_eps,
+            join_style: options.join_style,
+        };
+
+        for offset_pline in ipline.polyline.parallel_offset_opt(offset, &pline_options) {
+            // skip any open polylines (failed to stitch closed) and any loops that inverted
+            // orientation (collapsed regions)
+            if !offset_pline.is_closed() || offset_pline.len() < 2 {
+                continue;
+            }
+            let area = offset_pline.area();
+            if (is_ccw && area < T::zero()) || (!is_ccw && area > T::zero()) {
+                continue;
+            }
+
+            offset_loops.push(IndexedPolyline::new(offset_pline));
+        }
+    }
+
+    if offset_loops.is_empty() {
+        return Shape::empty();
+    }
+
+    // spatial index of all the offset loop extents to find which loops may intersect
+    let offset_loops_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(offset_loops.len());
+        for l in offset_loops.iter() {
+            let i = &l.spatial_index;
+            builder.add(i.min_x(), i.min_y(), i.max_x(), i.max_y());
+        }
+        builder.build().unwrap()
+    };
+
+    // intersects for each offset loop, keyed by loop index then by segment start index
+    let mut intersects_lookups =
+        vec![BTreeMap::<usize, Vec<Vector2<T>>>::new(); offset_loops.len()];
+    let mut query_stack = Vec::with_capacity(8);
+    let fuzz = T::fuzzy_epsilon();
+    for (i, loop1) in offset_loops.iter().enumerate() {
+        let i1 = &loop1.spatial_index;
+        let candidates = offset_loops_index.query_with_stack(
+            i1.min_x() - fuzz,
+            i1.min_y() - fuzz,
+            i1.max_x() + fuzz,
+            i1.max_y() + fuzz,
+            &mut query_stack,
+        );
+
+        for j in candidates {
+            if j <= i {
+                // only process each pair once
+                continue;
+            }
+
+            let loop2 = &offset_loops[j];
+            let intrs = loop1.polyline.find_intersects_opt(
+                &loop2.polyline,
+                &FindIntersectsOptions {
+                    pline1_aabb_index: Some(&loop1.spatial_index),
+                    pos_equal_eps,
+                },
+            );
+
+            for intr in intrs.basic_intersects {
+                intersects_lookups[i]
+                    .entry(intr.start_index1)
+                    .or_default()
+                    .push(intr.point);
+                intersects_lookups[j]
+                    .entry(intr.start_index2)
+                    .or_default()
+                    .push(intr.point);
+            }
+
+            // overlapping intersects are sliced at their end points
+            for intr in intrs.overlapping_intersects {
+                for &pt in [intr.point1, intr.point2].iter() {
+                    intersects_lookups[i]
+                        .entry(intr.start_index1)
+                        .or_default()
+                        .push(pt);
+                    intersects_lookups[j]
+                        .entry(intr.start_index2)
+                        .or_default()
+                        .push(pt);
+                }
+            }
+        }
+    }
+
+    // helper to test if a point is a valid distance from all the original shape polylines
+    let mut pline_query_stack = Vec::with_capacity(8);
+    let mut point_valid_dist = |point: Vector2<T>, query_stack: &mut Vec<usize>| -> bool {
+        let abs_offset = offset.abs();
+        let mut valid = true;
+        let mut visitor = |i: usize| {
+            let ipline = shape.get_indexed_pline(i);
+            valid = point_valid_for_offset_with_join(
+                &ipline.polyline,
+                offset,
+                &ipline.spatial_index,
+                point,
+                &mut pline_query_stack,
+                offset_dist_eps,
+                options.join_style,
+            );
+            if valid {
+                Control::Continue
+            } else {
+                Control::Break(())
+            }
+        };
+
+        plines_index.visit_query_with_stack(
+            point.x - abs_offset,
+            point.y - abs_offset,
+            point.x + abs_offset,
+            point.y + abs_offset,
+            &mut visitor,
+            query_stack,
+        );
+
+        valid
+    };
+
+    // helper to test if a segment intersects any of the original shape polylines
+    let mut seg_query_stack = Vec::with_capacity(8);
+    let mut intersects_original_plines =
+        |v1: PlineVertex<T>, v2: PlineVertex<T>, query_stack: &mut Vec<usize>| -> bool {
+            let bb = seg_fast_approx_bounding_box(v1, v2);
+            let mut has_intersect = false;
+            let mut visitor = |i: usize| {
+                let ipline = shape.get_indexed_pline(i);
+                let pline = &ipline.polyline;
+                let mut seg_visitor = |k: usize| {
+                    let u1 = pline[k];
+                    let u2 = pline[pline.next_wrapping_index(k)];
+                    has_intersect =
+                        !matches!(pline_seg_intr(v1, v2, u1, u2), PlineSegIntr::NoIntersect);
+                    if has_intersect {
+                        Control::Break(())
+                    } else {
+                        Control::Continue
+                    }
+                };
+
+                ipline.spatial_index.visit_query_with_stack(
+                    bb.min_x - fuzz,
+                    bb.min_y - fuzz,
+                    bb.max_x + fuzz,
+                    bb.max_y + fuzz,
+                    &mut seg_visitor,
+                    &mut seg_query_stack,
+                );
+
+                if has_intersect {
+                    Control::Break(())
+                } else {
+                    Control::Continue
+                }
+            };
+
+            plines_index.visit_query_with_stack(
+                bb.min_x - fuzz,
+                bb.min_y - fuzz,
+                bb.max_x + fuzz,
+                bb.max_y + fuzz,
+                &mut visitor,
+                query_stack,
+            );
+
+            has_intersect
+        };
+
+    // offset loops that are retained whole (no intersects with other offset loops)
+    let mut retain_whole = vec![false; offset_loops.len()];
+    // slices of offset loops that intersect other offset loops along with the index of the loop
+    // each slice is from
+    let mut slices = Vec::new();
+    let mut slice_loops = Vec::new();
+
+    for (loop_idx, (offset_loop, intersects_lookup)) in offset_loops
+        .iter()
+        .zip(intersects_lookups.iter_mut())
+        .enumerate()
+    {
+        let pline = &offset_loop.polyline;
+
+        // sort intersects by distance from segment start vertex
+        for (&i, intr_list) in intersects_lookup.iter_mut() {
+            let start_pos = pline[i].pos();
+            intr_list.sort_unstable_by(|&si1, &si2| {
+                let dist1 = dist_squared(si1, start_pos);
+                let dist2 = dist_squared(si2, start_pos);
+                dist1.partial_cmp(&dist2).unwrap()
+            });
+        }
+
+        let intersect_count: usize = intersects_lookup.values().map(|l| l.len()).sum();
+        if intersect_count < 2 {
+            // no intersects (or just a single tangent intersect) with other loops, entire loop
+            // is either valid or not
+            if point_valid_dist(pline[0].pos(), &mut query_stack)
+                && point_valid_dist(seg_midpoint(pline[0], pline[1]), &mut query_stack)
+            {
+                // retain loop (and its spatial index) as is
+                retain_whole[loop_idx] = true;
+            }
+            continue;
+        }
+
+        let mut slice_is_valid =
+            |slice: &OpenPlineSlice<T>, query_stack: &mut Vec<usize>| -> bool {
+                let mut seg_visitor = |v1: PlineVertex<T>, v2: PlineVertex<T>| {
+                    if !point_valid_dist(v1.pos(), query_stack)
+                        || !point_valid_dist(seg_midpoint(v1, v2), query_stack)
+                        || intersects_original_plines(v1, v2, query_stack)
+                    {
+                        return Control::Break(());
+                    }
+
+                    Control::Continue
+                };
+
+                match slice.visit_segs(pline, &mut seg_visitor) {
+                    Control::Continue => point_valid_dist(slice.end_point, query_stack),
+                    Control::Break(_) => false,
+                }
+            };
+
+        for (&start_index, intr_list) in intersects_lookup.iter() {
+            let mut intr_list_iter = intr_list.windows(2);
+            while let Some(&[intr1, intr2]) = intr_list_iter.next() {
+                let slice = OpenPlineSlice::from_slice_points(
+                    pline,
+                    intr1,
+                    start_index,
+                    intr2,
+                    start_index,
+                    pos_equal_eps,
+                );
+
+                if let Some(s) = slice {
+                    if slice_is_valid(&s, &mut query_stack) {
+                        slices.push(s);
+                        slice_loops.push(loop_idx);
+                    }
+                }
+            }
+
+            // build the slice between the last intersect in the intr_list and the next
+            // intersect found (wrapping around the loop)
+            let next_index = pline.next_wrapping_index(start_index);
+            let (found_index, next_intr_list) =
+                if let Some(list) = intersects_lookup.range(next_index..).next() {
+                    list
+                } else {
+                    intersects_lookup.range(..=start_index).next().unwrap()
+                };
+
+            let slice = OpenPlineSlice::from_slice_points(
+                pline,
+                *intr_list.last().unwrap(),
+                start_index,
+                next_intr_list[0],
+                *found_index,
+                pos_equal_eps,
+            );
+
+            if let Some(s) = slice {
+                if slice_is_valid(&s, &mut query_stack) {
+                    slices.push(s);
+                    slice_loops.push(loop_idx);
+                }
+            }
+        }
+    }
+
+    let sources: Vec<_> = offset_loops
+        .iter()
+        .map(|l| (&l.polyline, l.polyline.len() - 1))
+        .collect();
+    let stitched = stitch_slices_from_sources(
+        &sources,
+        &slices,
+        |i| slice_loops[i],
+        true,
+        pos_equal_eps,
+        slice_join_eps,
+    );
+
+    let mut result_ccw_plines = Vec::new();
+    let mut result_cw_plines = Vec::new();
+    let whole_loops = offset_loops
+        .into_iter()
+        .zip(retain_whole)
+        .filter_map(|(l, retain)| if retain { Some(l) } else { None });
+    let stitched = stitched.into_iter().map(|s| IndexedPolyline::new(s.pline));
+    for ipline in whole_loops.chain(stitched) {
+        if ipline.polyline.area() < T::zero() {
+            result_cw_plines.push(ipline);
+        } else {
+            result_ccw_plines.push(ipline);
+        }
+    }
+
+    Shape::from_indexed_plines(result_ccw_plines, result_cw_plines)
+}
+
+/// Repeatedly offset `shape` by `step` building the tree of offset loops, see
+/// [Shape::iterative_offset_opt].
+pub fn shape_iterative_offset<T>(
+    shape: &Shape<T>,
+    step: T,
+    options: &ShapeIterativeOffsetOptions<T>,
+) -> OffsetLoopTree<T>
+where
+    T: Real,
+{
+    let mut nodes: Vec<OffsetLoopNode<T>> = shape
+        .iter_plines()
+        .map(|pline| OffsetLoopNode {
+            polyline: pline.clone(),
+            pass: 0,
+            parent: None,
+            children: Vec::new(),
+        })
+        .collect();
+
+    let mut pass_count = if nodes.is_empty() { 0 } else { 1 };
+
+    let max_pass_count = options.max_pass_count.unwrap_or(usize::MAX);
+
+    if step.fuzzy_eq_zero() {
+        return OffsetLoopTree { nodes, pass_count };
+    }
+
+    let mut query_stack = Vec::with_capacity(8);
+    // index of the first node of the previous pass
+    let mut prev_start = 0;
+    let mut prev_shape = Cow::Borrowed(shape);
+    while pass_count > 0 && pass_count <= max_pass_count {
+        let next_shape = shape_parallel_offset(&prev_shape, step, &options.offset_options);
+        if next_shape.is_empty() {
+            break;
+        }
+
+        // loops with the same orientation as the step sign shrink each pass, without any the
+        // remaining loops grow on every pass and never become empty
+        let has_shrinking = if step > T::zero() {
+            !next_shape.ccw_plines.is_empty()
+        } else {
+            !next_shape.cw_plines.is_empty()
+        };
+        if !has_shrinking && options.max_pass_count.is_none() {
+            break;
+        }
+
+        let next_start = nodes.len();
+        for (pline, is_ccw) in next_shape
+            .ccw_plines
+            .iter()
+            .map(|p| (&p.polyline, true))
+            .chain(next_shape.cw_plines.iter().map(|p| (&p.polyline, false)))
+        {
+            let shrinking = is_ccw == (step > T::zero());
+            let parent = find_parent_loop(
+                &prev_shape,
+                pline,
+                is_ccw,
+                shrinking,
+                step.abs(),
+                options.offset_options.offset_dist_eps,
+                &mut query_stack,
+            )
+            .map(|i| prev_start + i);
+            let node_index = nodes.len();
+            if let Some(p) = parent {
+                nodes[p].children.push(node_index);
+            }
+            nodes.push(OffsetLoopNode {
+                polyline: pline.clone(),
+                pass: pass_count,
+                parent,
+                children: Vec::new(),
+            });
+        }
+
+        pass_count += 1;
+        prev_start = next_start;
+        prev_shape = Cow::Owned(next_shape);
+    }
+
+    OffsetLoopTree { nodes, pass_count }
+}
+
+/// Find the loop in `prev_shape` that `pline` was offset from, returns the index of the loop in
+/// the [Shape::plines_index] order of `prev_shape`.
+///
+/// If `shrinking` is true then the innermost loop with the same orientation that contains `pline`
+/// is found, otherwise the closest loop with the same orientation (within `step` distance plus
+/// `dist_eps`) is found.
+fn find_parent_loop<T>(
+    prev_shape: &Shape<T>,
+    pline: &Polyline<T>,
+    is_ccw: bool,
+    shrinking: bool,
+    step: T,
+    dist_eps: T,
+    query_stack: &mut Vec<usize>,
+) -> Option<usize>
+where
+    T: Real,
+{
+    let plines_index = prev_shape.plines_index.as_ref()?;
+    let ccw_count = prev_shape.ccw_plines.len();
+    // test point in the middle of the first segment (start point may be where slices were joined)
+    let point = seg_midpoint(pline[0], pline[1]);
+    let search_dist = if shrinking {
+        T::zero()
+    } else {
+        step + dist_eps
+    };
+
+    let candidates = plines_index.query_with_stack(
+        point.x - search_dist,
+        point.y - search_dist,
+        point.x + search_dist,
+        point.y + search_dist,
+        query_stack,
+    );
+
+    let mut result = None;
+    let mut best = Real::max_value();
+    for i in candidates {
+        if (i < ccw_count) != is_ccw {
+            continue;
+        }
+
+        let prev_pline = &prev_shape.get_indexed_pline(i).polyline;
+        let value = if shrinking {
+            if prev_pline.winding_number(point) == 0 {
+                continue;
+            }
+            prev_pline.area().abs()
+        } else {
+            match prev_pline.closest_point(point) {
+                Some(cp) => cp.distance,
+                None => continue,
+            }
+        };
+
+        if value < best {
+            best = value;
+            result = Some(i);
+        }
+    }
+
+    result
+}
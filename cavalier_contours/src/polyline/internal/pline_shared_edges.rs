@@ -0,0 +1,171 @@
+use super::{
+    pline_boolean::batch_union,
+    pline_intersects::{find_intersects, sort_and_join_overlapping_intersects},
+};
+use crate::{
+    core::traits::Real,
+    polyline::{
+        FindIntersectsOptions, PlineSharedEdgesOptions, Polyline, PolylineSlice, Shape,
+        ShapeBooleanOptions, SharedEdge,
+    },
+};
+use static_aabb2d_index::StaticAABB2DIndexBuilder;
+
+/// Find all the boundary sections shared by the closed polylines given (sections where two of the
+/// polylines have coincident segments).
+///
+/// Polylines with overlapping extents are found using one spatial index of all the polyline
+/// extents, the overlapping intersects between each pair are then joined together into the shared
+/// sections. Each shared section is returned as an open polyline following the direction of the
+/// polyline with the lower index. If two polylines completely overlap the shared section is the
+/// whole polyline (first vertex position repeated at the end).
+///
+/// Open polylines and polylines with less than 2 vertexes are ignored.
+pub fn find_shared_edges<T>(
+    plines: &[Polyline<T>],
+    options: &PlineSharedEdgesOptions<T>,
+) -> Vec<SharedEdge<T>>
+where
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let mut result = Vec::new();
+
+    let valid: Vec<usize> = (0..plines.len())
+        .filter(|&i| plines[i].is_closed() && plines[i].len() > 1)
+        .collect();
+
+    let extents_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(valid.len());
+        for &i in valid.iter() {
+            let e = plines[i].extents().unwrap();
+            builder.add(e.min_x, e.min_y, e.max_x, e.max_y);
+        }
+
+        match builder.build() {
+            Ok(index) => index,
+            Err(_) => return result,
+        }
+    };
+
+    let mut query_stack = Vec::new();
+    for (k, &i) in valid.iter().enumerate() {
+        let pline1 = &plines[i];
+        let pline1_aabb_index = pline1.create_approx_aabb_index().unwrap();
+        let mut candidates = extents_index.query_with_stack(
+            pline1_aabb_index.min_x(),
+            pline1_aabb_index.min_y(),
+            pline1_aabb_index.max_x(),
+            pline1_aabb_index.max_y(),
+            &mut query_stack,
+        );
+        // process each pair once, in order of index
+        candidates.retain(|&c| c > k);
+        candidates.sort_unstable();
+
+        for c in candidates {
+            let j = valid[c];
+            let pline2 = &plines[j];
+            let mut intrs = find_intersects(
+                pline1,
+                pline2,
+                &FindIntersectsOptions {
+                    pline1_aabb_index: Some(&pline1_aabb_index),
+                    pos_equal_eps,
+                },
+            );
+
+            let overlapping_slices = sort_and_join_overlapping_intersects(
+                &mut intrs.overlapping_intersects,
+                pline1,
+                pline2,
+                pos_equal_eps,
+            );
+
+            for slice in overlapping_slices {
+                // overlapping slices follow the direction of the second polyline
+                let mut pline = slice.to_polyline(pline2, pos_equal_eps);
+                if slice.opposing_directions {
+                    pline.invert_direction();
+                }
+
+                result.push(SharedEdge {
+                    pline,
+                    pline_index1: i,
+                    pline_index2: j,
+                    opposing_directions: slice.opposing_directions,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// Dissolve the shared edges between the closed polylines given, merging the regions which are
+/// adjacent along shared edges (see [find_shared_edges]) into single loops.
+///
+/// Each group of polylines connected by shared edges is unioned using [batch_union], polylines
+/// that do not share any edges are moved into the result as is. The polylines given are expected
+/// to not overlap each other (e.g. tiles or nested parts) other than along shared edges. The
+/// orientation of the polylines given is ignored.
+///
+/// Open polylines and polylines with less than 2 vertexes are ignored.
+pub fn dissolve_shared_edges<T>(
+    plines: Vec<Polyline<T>>,
+    options: &PlineSharedEdgesOptions<T>,
+) -> Shape<T>
+where
+    T: Real,
+{
+    let shared_edges = find_shared_edges(&plines, options);
+
+    let mut adjacent = vec![Vec::new(); plines.len()];
+    for edge in shared_edges.iter() {
+        adjacent[edge.pline_index1].push(edge.pline_index2);
+        adjacent[edge.pline_index2].push(edge.pline_index1);
+    }
+
+    let union_options = ShapeBooleanOptions {
+        pos_equal_eps: options.pos_equal_eps,
+        slice_join_eps: options.slice_join_eps,
+    };
+
+    let mut plines: Vec<Option<Polyline<T>>> = plines.into_iter().map(Some).collect();
+    let mut result_plines = Vec::with_capacity(plines.len());
+    let mut visited = vec![false; plines.len()];
+    let mut group = Vec::new();
+    for i in 0..plines.len() {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        // find all polylines connected by shared edges
+        group.clear();
+        group.push(i);
+        let mut k = 0;
+        while k < group.len() {
+            for &j in adjacent[group[k]].iter() {
+                if !visited[j] {
+                    visited[j] = true;
+                    group.push(j);
+                }
+            }
+            k += 1;
+        }
+
+        if group.len() == 1 {
+            result_plines.push(plines[i].take().unwrap());
+            continue;
+        }
+
+        let merged = batch_union(
+            group.iter().map(|&j| plines[j].take().unwrap()),
+            &union_options,
+        );
+        result_plines.extend(merged.into_plines());
+    }
+
+    Shape::from_unoriented_plines(result_plines)
+}
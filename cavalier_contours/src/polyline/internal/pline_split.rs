@@ -0,0 +1,249 @@
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        seg_point_at_length, seg_split_at_point, PlineLengthTable, PlineSplitOptions, PlineVertex,
+        Polyline,
+    },
+};
+
+/// Wrap `length` into the range `[0, total)` (lengths already in the range are left unchanged).
+fn wrap_length<T>(length: T, total: T) -> T
+where
+    T: Real,
+{
+    if length >= T::zero() && length < total {
+        return length;
+    }
+
+    let wrapped = length - (length / total).floor() * total;
+    if wrapped >= total {
+        T::zero()
+    } else {
+        wrapped
+    }
+}
+
+/// Clamp `length` to the range `[0, total]`.
+fn clamp_length<T>(length: T, total: T) -> T
+where
+    T: Real,
+{
+    num_traits::real::Real::min(num_traits::real::Real::max(length, T::zero()), total)
+}
+
+/// Construct the open polyline following the path of the source polyline from path length
+/// `start_length` to `end_length`.
+///
+/// `start_length` must be in the range `[0, total_length]`. For closed polylines `end_length` may
+/// extend past the total length (up to one full loop past `start_length`) to wrap around through
+/// the first vertex. Returns `None` if the path is collapsed (shorter than `pos_equal_eps`).
+fn sub_path<T>(
+    table: &PlineLengthTable<T>,
+    start_length: T,
+    end_length: T,
+    pos_equal_eps: T,
+) -> Option<Polyline<T>>
+where
+    T: Real,
+{
+    if end_length - start_length <= pos_equal_eps {
+        return None;
+    }
+
+    let pline = table.pline();
+    let total = table.total_length();
+    let (start_index, start_offset) = table.seg_at_length(start_length)?;
+    let wraps = end_length > total;
+    let (end_index, end_offset) = if wraps {
+        table.seg_at_length(end_length - total)?
+    } else {
+        table.seg_at_length(end_length)?
+    };
+
+    let traverse_count = if wraps {
+        pline.len() - start_index + end_index
+    } else {
+        end_index - start_index
+    };
+
+    let start_v1 = pline[start_index];
+    let start_v2 = pline[pline.next_wrapping_index(start_index)];
+    let start_point = seg_point_at_length(start_v1, start_v2, start_offset);
+    let end_v1 = pline[end_index];
+    let end_v2 = pline[pline.next_wrapping_index(end_index)];
+    let end_point = seg_point_at_length(end_v1, end_v2, end_offset);
+
+    let mut result = Polyline::with_capacity(traverse_count + 2, false);
+    let start_split = seg_split_at_point(start_v1, start_v2, start_point, pos_equal_eps);
+    if traverse_count == 0 {
+        let split =
+            seg_split_at_point(start_split.split_vertex, start_v2, end_point, pos_equal_eps);
+        result.add_vertex(split.updated_start);
+    } else {
+        result.add_vertex(start_split.split_vertex);
+        let mut index = pline.next_wrapping_index(start_index);
+        for _ in 1..traverse_count {
+            result.add_or_replace_vertex(pline[index], pos_equal_eps);
+            index = pline.next_wrapping_index(index);
+        }
+
+        let split = seg_split_at_point(end_v1, end_v2, end_point, pos_equal_eps);
+        result.add_or_replace_vertex(split.updated_start, pos_equal_eps);
+    }
+
+    result.add_or_replace_vertex(
+        PlineVertex::from_vector2(end_point, T::zero()),
+        pos_equal_eps,
+    );
+
+    if result.len() < 2 {
+        return None;
+    }
+
+    Some(result)
+}
+
+/// Construct the open polyline following the path of `pline` from path length `start_length` to
+/// `end_length`.
+///
+/// For open polylines the lengths are clamped to the path and `None` is returned if
+/// `start_length >= end_length`. For closed polylines the lengths wrap around the loop (lengths
+/// outside of `[0, total_length]` are wrapped into range) and if `end_length < start_length` the
+/// result passes through the first vertex of `pline`, `None` is returned if the lengths are equal.
+/// `None` is also returned if `pline` has less than 2 vertexes or the result would be shorter than
+/// [PlineSplitOptions::pos_equal_eps].
+pub fn sub_polyline<T>(
+    pline: &Polyline<T>,
+    start_length: T,
+    end_length: T,
+    options: &PlineSplitOptions<T>,
+) -> Option<Polyline<T>>
+where
+    T: Real,
+{
+    if pline.len() < 2 {
+        return None;
+    }
+
+    let table = pline.length_table();
+    let total = table.total_length();
+    if !pline.is_closed() {
+        return sub_path(
+            &table,
+            clamp_length(start_length, total),
+            clamp_length(end_length, total),
+            options.pos_equal_eps,
+        );
+    }
+
+    if total <= options.pos_equal_eps {
+        return None;
+    }
+
+    let start_length = wrap_length(start_length, total);
+    let mut end_length = if end_length >= T::zero() && end_length <= total {
+        end_length
+    } else {
+        wrap_length(end_length, total)
+    };
+
+    if end_length < start_length {
+        end_length = end_length + total;
+    }
+
+    sub_path(&table, start_length, end_length, options.pos_equal_eps)
+}
+
+/// Split `pline` at all the path `lengths` given.
+///
+/// Open polylines are split into the pieces between the lengths (clamped to the path), closed
+/// polylines are split into the pieces between the lengths going around the loop (wrapped into
+/// range), with the last piece passing through the first vertex. A closed polyline split at a
+/// single length is opened at that length. Pieces shorter than [PlineSplitOptions::pos_equal_eps]
+/// are discarded. If no lengths are given then the polyline is returned unchanged. Returns an empty
+/// `Vec` if `pline` has less than 2 vertexes.
+pub fn split_at_lengths<T>(
+    pline: &Polyline<T>,
+    lengths: &[T],
+    options: &PlineSplitOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let mut result = Vec::new();
+    if pline.len() < 2 {
+        return result;
+    }
+
+    if lengths.is_empty() {
+        result.push(pline.clone());
+        return result;
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let table = pline.length_table();
+    let total = table.total_length();
+
+    let mut cuts: Vec<T> = if pline.is_closed() {
+        if total <= pos_equal_eps {
+            return result;
+        }
+        lengths.iter().map(|&l| wrap_length(l, total)).collect()
+    } else {
+        lengths.iter().map(|&l| clamp_length(l, total)).collect()
+    };
+    cuts.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    cuts.dedup_by(|b, a| *b - *a <= pos_equal_eps);
+
+    if pline.is_closed() {
+        if cuts.len() > 1 && cuts[0] + total - cuts[cuts.len() - 1] <= pos_equal_eps {
+            // last cut is at the first cut going around the loop
+            cuts.pop();
+        }
+
+        for (i, &start) in cuts.iter().enumerate() {
+            let end = match cuts.get(i + 1) {
+                Some(&end) => end,
+                None => cuts[0] + total,
+            };
+            result.extend(sub_path(&table, start, end, pos_equal_eps));
+        }
+    } else {
+        let mut start = T::zero();
+        for &end in cuts.iter().chain(std::iter::once(&total)) {
+            if let Some(piece) = sub_path(&table, start, end, pos_equal_eps) {
+                result.push(piece);
+                start = end;
+            }
+        }
+    }
+
+    result
+}
+
+/// Open the closed polyline `pline` at `point` on the segment starting at `seg_start_index`, the
+/// result is an open polyline that starts at `point`, follows the whole loop, and ends back at
+/// `point`. Returns `None` if `pline` is open, has less than 2 vertexes, or has a path length
+/// shorter than [PlineSplitOptions::pos_equal_eps].
+pub fn open_at_point<T>(
+    pline: &Polyline<T>,
+    seg_start_index: usize,
+    point: Vector2<T>,
+    options: &PlineSplitOptions<T>,
+) -> Option<Polyline<T>>
+where
+    T: Real,
+{
+    if !pline.is_closed() || pline.len() < 2 {
+        return None;
+    }
+
+    let table = pline.length_table();
+    let total = table.total_length();
+    if total <= options.pos_equal_eps {
+        return None;
+    }
+
+    let length = wrap_length(table.length_at_point(seg_start_index, point), total);
+    sub_path(&table, length, length + total, options.pos_equal_eps)
+}
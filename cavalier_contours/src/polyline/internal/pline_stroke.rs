@@ -0,0 +1,212 @@
+use crate::{
+    core::traits::Real,
+    polyline::{
+        internal::{
+            pline_fill_rule::resolve_self_intersects,
+            pline_offset::{create_raw_offset_polyline, raw_offset_seg},
+        },
+        seg_tangent_vector, FillRule, OffsetJoinStyle, PlineResolveOptions, PlineStrokeOptions,
+        Polyline, StrokeCapStyle,
+    },
+};
+use std::borrow::Cow;
+
+/// Extend the start and end of the open `polyline` tangentially by `dist` (used to form square
+/// caps).
+fn extend_ends<T>(polyline: &Polyline<T>, dist: T) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = polyline.clone();
+
+    let start_dir = seg_tangent_vector(result[0], result[1], result[0].pos()).normalize();
+    let start_point = result[0].pos() - start_dir.scale(dist);
+    if result[0].bulge_is_zero() {
+        result[0].x = start_point.x;
+        result[0].y = start_point.y;
+    } else {
+        result.insert(0, start_point.x, start_point.y, T::zero());
+    }
+
+    let ln = result.len();
+    let end_dir =
+        seg_tangent_vector(result[ln - 2], result[ln - 1], result[ln - 1].pos()).normalize();
+    let end_point = result[ln - 1].pos() + end_dir.scale(dist);
+    if result[ln - 2].bulge_is_zero() {
+        result.set_vertex(ln - 1, end_point.x, end_point.y, T::zero());
+    } else {
+        result[ln - 1].bulge = T::zero();
+        result.add(end_point.x, end_point.y, T::zero());
+    }
+
+    result
+}
+
+/// Create the raw offset of one side of an open polyline for stroking, unlike
+/// [create_raw_offset_polyline] a single collapsed arc segment is kept (as a line) so the stroke
+/// outline can still be formed.
+fn raw_offset_side<T>(
+    polyline: &Polyline<T>,
+    offset: T,
+    join_style: OffsetJoinStyle<T>,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let result = create_raw_offset_polyline(polyline, offset, join_style, pos_equal_eps);
+    if !result.is_empty() || polyline.len() != 2 {
+        return result;
+    }
+
+    let seg = raw_offset_seg(polyline[0], polyline[1], offset);
+    let mut result = Polyline::new();
+    result.add_vertex(seg.v1);
+    result.add_or_replace_vertex(seg.v2, pos_equal_eps);
+    if result.len() == 1 {
+        result.clear();
+    }
+    result
+}
+
+/// Form the closed outline of the stroke of the open `polyline` by joining the raw offsets of both
+/// sides with end caps. Resulting outline has counter clockwise direction but may self intersect,
+/// the stroke is the region where the winding number of the outline is not zero.
+fn create_raw_stroke_outline<T>(
+    polyline: &Polyline<T>,
+    half_width: T,
+    join_style: OffsetJoinStyle<T>,
+    round_caps: bool,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let right = raw_offset_side(polyline, -half_width, join_style, pos_equal_eps);
+    let left = raw_offset_side(polyline, half_width, join_style, pos_equal_eps);
+    if right.is_empty() || left.is_empty() {
+        return Polyline::new();
+    }
+
+    // half circle (bulge of 1) or straight line (bulge of 0) cap
+    let cap_bulge = if round_caps { T::one() } else { T::zero() };
+    join_sides(&right, &left, cap_bulge, pos_equal_eps)
+}
+
+/// Form the closed outline of the stroke of the closed `polyline` from the closed raw offsets of
+/// both sides. The sides are joined by a seam of two coincident lines (traversed in opposite
+/// directions so they cancel) to form a single outline, the stroke is the region where the winding
+/// number of the outline is not zero.
+fn create_closed_stroke_outline<T>(
+    polyline: &Polyline<T>,
+    half_width: T,
+    join_style: OffsetJoinStyle<T>,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let right = create_raw_offset_polyline(polyline, -half_width, join_style, pos_equal_eps);
+    let left = create_raw_offset_polyline(polyline, half_width, join_style, pos_equal_eps);
+    if right.len() < 2 || left.len() < 2 {
+        // one side collapsed, outline is the other side (oriented to have non zero winding inside)
+        return if right.len() < 2 { left } else { right };
+    }
+
+    // close each side by repeating its start vertex so the seam lines connect the start vertexes
+    let close_side = |side: &Polyline<T>| {
+        let mut closed = side.clone();
+        closed.set_is_closed(false);
+        closed.add_vertex(side[0]);
+        closed
+    };
+
+    join_sides(
+        &close_side(&right),
+        &close_side(&left),
+        T::zero(),
+        pos_equal_eps,
+    )
+}
+
+/// Join the `right` side traversed forward and the `left` side traversed in reverse into a closed
+/// polyline, connecting the ends of the sides with segments that have `cap_bulge`.
+fn join_sides<T>(
+    right: &Polyline<T>,
+    left: &Polyline<T>,
+    cap_bulge: T,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = Polyline::with_capacity(right.len() + left.len(), true);
+    for v in right.iter() {
+        result.add_or_replace_vertex(*v, pos_equal_eps);
+    }
+    result.last_mut().unwrap().bulge = cap_bulge;
+
+    // left side traversed in reverse direction
+    for i in (0..left.len()).rev() {
+        let bulge = if i > 0 { -left[i - 1].bulge } else { cap_bulge };
+        result.add_or_replace(left[i].x, left[i].y, bulge, pos_equal_eps);
+    }
+
+    result
+}
+
+/// Stroke `polyline` with the `width` given, returning the closed polylines that bound the stroke.
+///
+/// Boundaries are returned with counter clockwise direction and holes are returned with clockwise
+/// direction. See [Polyline::stroke_opt] for more details.
+pub fn stroke<T>(
+    polyline: &Polyline<T>,
+    width: T,
+    options: &PlineStrokeOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let half_width = width.abs() / T::two();
+    if polyline.len() < 2 || half_width.fuzzy_eq_zero() {
+        return Vec::new();
+    }
+    debug_assert!(
+        polyline.remove_repeat_pos(options.pos_equal_eps).len() == polyline.len(),
+        "bug: input assumed to not have repeat position vertexes"
+    );
+
+    let outline = if polyline.is_closed() {
+        create_closed_stroke_outline(
+            polyline,
+            half_width,
+            options.join_style,
+            options.pos_equal_eps,
+        )
+    } else {
+        let pline = match options.cap_style {
+            StrokeCapStyle::Square => Cow::Owned(extend_ends(polyline, half_width)),
+            _ => Cow::Borrowed(polyline),
+        };
+
+        create_raw_stroke_outline(
+            &pline,
+            half_width,
+            options.join_style,
+            matches!(options.cap_style, StrokeCapStyle::Round),
+            options.pos_equal_eps,
+        )
+    };
+
+    if outline.len() < 2 {
+        return Vec::new();
+    }
+
+    let resolve_options = PlineResolveOptions {
+        aabb_index: None,
+        pos_equal_eps: options.pos_equal_eps,
+        slice_join_eps: options.slice_join_eps,
+    };
+
+    resolve_self_intersects(&outline, FillRule::NonZero, &resolve_options)
+}
@@ -1,18 +1,34 @@
 use super::{
     internal::{
-        pline_boolean::polyline_boolean,
+        pline_boolean::{
+            polyline_boolean, polyline_boolean_divide, polyline_imprint, try_polyline_boolean,
+        },
+        pline_clip::clip_open,
+        pline_corner::{cut_all_corners, cut_corner, CornerOp},
+        pline_fill_rule::{resolve_self_intersects, try_resolve_self_intersects},
         pline_intersects::{
             find_intersects, visit_global_self_intersects, visit_local_self_intersects,
         },
-        pline_offset::parallel_offset,
+        pline_offset::{
+            parallel_offset, parallel_offset_variable, parallel_offset_with_source,
+            try_parallel_offset,
+        },
+        pline_shape_distance::{directed_hausdorff_distance, frechet_distance, hausdorff_distance},
+        pline_split::{open_at_point, split_at_lengths, sub_polyline},
+        pline_stroke::stroke,
     },
     pline_seg::{
-        arc_seg_bounding_box, seg_arc_radius_and_center, seg_closest_point,
-        seg_fast_approx_bounding_box, seg_length,
+        arc_seg_bounding_box, seg_arc_radius_and_center, seg_closest_point, seg_closest_points,
+        seg_fast_approx_bounding_box, seg_length, seg_split_at_point,
     },
-    seg_bounding_box, BooleanOp, BooleanResult, ClosestPointResult, FindIntersectsOptions,
-    PlineBooleanOptions, PlineIntersectVisitor, PlineIntersectsCollection, PlineOffsetOptions,
-    PlineOrientation, PlineSelfIntersectOptions, PlineVertex, SelfIntersectsInclude,
+    seg_bounding_box, BooleanDivideResult, BooleanOp, BooleanResult, ClosestPointResult, FillRule,
+    FindIntersectsOptions, MedialAxis, MedialAxisOptions, MinDistanceResult,
+    OffsetPolylineWithSource, PlineBooleanOptions, PlineClipOptions, PlineCornerError,
+    PlineCornerOptions, PlineCornerResult, PlineIntersectVisitor, PlineIntersectsCollection,
+    PlineLengthTable, PlineMinDistanceOptions, PlineOffsetOptions, PlineOpError, PlineOrientation,
+    PlineResolveOptions, PlineSelfIntersectOptions, PlineShapeDistanceOptions, PlineSplitOptions,
+    PlineStation, PlineStrokeOptions, PlineVertex, SelfIntersectsInclude, Shape,
+    ShapeDistanceResult, ShapeOffsetOptions,
 };
 use crate::core::{
     math::{
@@ -462,6 +478,40 @@ where
         result.map_or_else(|| Cow::Borrowed(self), Cow::Owned)
     }
 
+    /// Validate the polyline as `input` to a fallible operation, checks the polyline has at
+    /// least 2 vertexes, all vertex values are finite, and there are no repeat position vertexes.
+    pub(crate) fn validate_as_input(
+        &self,
+        input: usize,
+        pos_equal_eps: T,
+    ) -> Result<(), PlineOpError> {
+        if self.len() < 2 {
+            return Err(PlineOpError::TooFewVertexes { input });
+        }
+
+        if let Some(vertex_index) = self
+            .iter()
+            .position(|v| !v.x.is_finite() || !v.y.is_finite() || !v.bulge.is_finite())
+        {
+            return Err(PlineOpError::NonFiniteVertex {
+                input,
+                vertex_index,
+            });
+        }
+
+        if let Some((_, vertex_index)) = self
+            .iter_segment_indexes()
+            .find(|&(i, j)| self[i].pos().fuzzy_eq_eps(self[j].pos(), pos_equal_eps))
+        {
+            return Err(PlineOpError::RepeatPositionVertexes {
+                input,
+                vertex_index,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Remove all redundant vertexes from the polyline.
     ///
     /// Redundant vertexes can arise with multiple vertexes on top of each other, along a straight
@@ -958,6 +1008,150 @@ where
         PlineSegIndexIterator::new(self.vertex_data.len(), self.is_closed)
     }
 
+    /// Compute the morphological opening of the region bounded by the polyline using default
+    /// options.
+    ///
+    /// See [Polyline::morph_open_opt] for more information.
+    pub fn morph_open(&self, radius: T) -> Shape<T> {
+        self.morph_open_opt(radius, &Default::default())
+    }
+
+    /// Compute the morphological opening of the region bounded by the polyline with options
+    /// given.
+    ///
+    /// The region is offset inward by `radius` and then back outward by `radius`, rounding off
+    /// convex corners and removing features narrower than two times `radius`. The result may be
+    /// split into multiple polylines so it is returned as a [Shape], see [Shape::morph_open_opt].
+    ///
+    /// The polyline must be closed, its orientation is ignored. An empty shape is returned if the
+    /// polyline is open or has less than 2 vertexes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // two squares joined by a narrow neck
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (4.0, 1.5, 0.0),
+    ///     (6.0, 1.5, 0.0),
+    ///     (6.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (6.0, 4.0, 0.0),
+    ///     (6.0, 2.5, 0.0),
+    ///     (4.0, 2.5, 0.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// // neck is removed splitting the result into two polylines
+    /// let opened = pline.morph_open(1.0);
+    /// assert_eq!(opened.ccw_plines.len(), 2);
+    /// assert!(opened.cw_plines.is_empty());
+    /// ```
+    pub fn morph_open_opt(&self, radius: T, options: &ShapeOffsetOptions<T>) -> Shape<T> {
+        self.region_shape().morph_open_opt(radius, options)
+    }
+
+    /// Compute the morphological closing of the region bounded by the polyline using default
+    /// options.
+    ///
+    /// See [Polyline::morph_close_opt] for more information.
+    pub fn morph_close(&self, radius: T) -> Shape<T> {
+        self.morph_close_opt(radius, &Default::default())
+    }
+
+    /// Compute the morphological closing of the region bounded by the polyline with options
+    /// given.
+    ///
+    /// The region is offset outward by `radius` and then back inward by `radius`, rounding off
+    /// concave corners and filling gaps narrower than two times `radius`. The result may contain
+    /// holes so it is returned as a [Shape], see [Shape::morph_close_opt].
+    ///
+    /// The polyline must be closed, its orientation is ignored. An empty shape is returned if the
+    /// polyline is open or has less than 2 vertexes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // narrow slot cut into a rectangle
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.5, 0.0, 0.0),
+    ///     (4.5, 3.0, 0.0),
+    ///     (5.5, 3.0, 0.0),
+    ///     (5.5, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// // slot is filled except for a small rounded notch where it opens to the outside
+    /// let closed = pline.morph_close(1.0);
+    /// assert_eq!(closed.ccw_plines.len(), 1);
+    /// assert!(closed.ccw_plines[0].polyline.area() > 39.9);
+    /// ```
+    pub fn morph_close_opt(&self, radius: T, options: &ShapeOffsetOptions<T>) -> Shape<T> {
+        self.region_shape().morph_close_opt(radius, options)
+    }
+
+    /// Create a shape from the region bounded by the polyline (counter clockwise orientation).
+    pub(crate) fn region_shape(&self) -> Shape<T> {
+        if !self.is_closed() || self.len() < 2 {
+            return Shape::empty();
+        }
+
+        let mut pline = self.clone();
+        if pline.area() < T::zero() {
+            pline.invert_direction();
+        }
+
+        Shape::from_plines(std::iter::once(pline))
+    }
+
+    /// Compute the medial axis of the region bounded by the polyline using default options.
+    ///
+    /// See [Polyline::medial_axis_opt] for more information.
+    pub fn medial_axis(&self) -> MedialAxis<T> {
+        self.medial_axis_opt(&Default::default())
+    }
+
+    /// Compute the medial axis of the region bounded by the polyline with options given.
+    ///
+    /// The result is an approximation built from points sampled along the polyline, positions and
+    /// radii are within about half of [MedialAxisOptions::sample_spacing] of the exact axis (and
+    /// typically much closer), see [Shape::medial_axis_opt] for the error bound.
+    ///
+    /// The medial axis is returned as a graph of open polylines with the inscribed circle radius
+    /// at each vertex, see [Shape::medial_axis_opt].
+    ///
+    /// The polyline must be closed, its orientation is ignored. An empty medial axis is returned
+    /// if the polyline is open, has less than 2 vertexes, or has a vertex value that is not
+    /// finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rect: Polyline<f64> =
+    ///     pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 2.0, 0.0), (0.0, 2.0, 0.0)];
+    /// let axis = rect.medial_axis();
+    /// // widest part of the rectangle is 2
+    /// assert!(axis.max_radius().unwrap().fuzzy_eq(1.0));
+    /// ```
+    pub fn medial_axis_opt(&self, options: &MedialAxisOptions<T>) -> MedialAxis<T> {
+        if self
+            .iter()
+            .any(|v| !v.x.is_finite() || !v.y.is_finite() || !v.bulge.is_finite())
+        {
+            return MedialAxis::empty();
+        }
+
+        self.region_shape().medial_axis_opt(options)
+    }
+
     /// Compute the parallel offset polylines of the polyline using default options.
     ///
     /// `offset` determines what offset polylines are generated, if it is positive then the
@@ -1017,6 +1211,400 @@ where
         parallel_offset(self, offset, options)
     }
 
+    /// Compute the parallel offset polylines of the polyline using default options, returning an
+    /// error for invalid input.
+    ///
+    /// See [Polyline::try_parallel_offset_opt] for more information.
+    pub fn try_parallel_offset(&self, offset: T) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        self.try_parallel_offset_opt(offset, &Default::default())
+    }
+
+    /// Compute the parallel offset polylines of the polyline with options given, returning an
+    /// error for invalid input.
+    ///
+    /// Same as [Polyline::parallel_offset_opt] except the input is validated (at least 2
+    /// vertexes, finite values, and no repeat position vertexes) and an error is returned if the
+    /// offset of a closed polyline failed to stitch together into closed polylines. An empty
+    /// result means the offset collapsed to nothing. See [PlineOpError] for all errors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let pline = pline_closed![(0.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// assert_eq!(pline.try_parallel_offset(0.2).unwrap().len(), 1);
+    /// // offset collapses the circle
+    /// assert!(pline.try_parallel_offset(1.0).unwrap().is_empty());
+    ///
+    /// let invalid = pline_closed![(0.0, 0.0, 1.0), (1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// assert_eq!(
+    ///     invalid.try_parallel_offset(0.2).unwrap_err(),
+    ///     PlineOpError::RepeatPositionVertexes { input: 0, vertex_index: 2 }
+    /// );
+    /// ```
+    pub fn try_parallel_offset_opt(
+        &self,
+        offset: T,
+        options: &PlineOffsetOptions<T>,
+    ) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        try_parallel_offset(self, offset, options)
+    }
+
+    /// Compute the parallel offset polylines of the polyline along with the source of every offset
+    /// polyline vertex using default options.
+    ///
+    /// See [Polyline::parallel_offset_with_source_opt] for more information.
+    pub fn parallel_offset_with_source(&self, offset: T) -> Vec<OffsetPolylineWithSource<T>> {
+        self.parallel_offset_with_source_opt(offset, &Default::default())
+    }
+
+    /// Compute the parallel offset polylines of the polyline along with the source of every offset
+    /// polyline vertex with options given.
+    ///
+    /// Offset polylines are the same as returned by [Polyline::parallel_offset_opt], for each
+    /// offset polyline vertex the source of the segment that starts at the vertex is given as
+    /// either the index of the source polyline segment it was offset from or the index of the
+    /// source polyline vertex it was generated to join offset segments at (see
+    /// [OffsetSegSource]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (4.0, 2.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// // negative offset of counter clockwise polyline is outward (round joins at every corner)
+    /// let results = rectangle.parallel_offset_with_source(-1.0);
+    /// assert_eq!(results.len(), 1);
+    /// let result = &results[0];
+    /// assert_eq!(result.polyline.len(), 8);
+    /// let segment_count = result
+    ///     .sources
+    ///     .iter()
+    ///     .filter(|s| matches!(s, OffsetSegSource::Segment(_)))
+    ///     .count();
+    /// let join_count = result
+    ///     .sources
+    ///     .iter()
+    ///     .filter(|s| matches!(s, OffsetSegSource::Join(_)))
+    ///     .count();
+    /// assert_eq!(segment_count, 4);
+    /// assert_eq!(join_count, 4);
+    /// ```
+    pub fn parallel_offset_with_source_opt(
+        &self,
+        offset: T,
+        options: &PlineOffsetOptions<T>,
+    ) -> Vec<OffsetPolylineWithSource<T>> {
+        parallel_offset_with_source(self, offset, options)
+    }
+
+    /// Compute the parallel offset polylines of the polyline using a different offset distance
+    /// for each segment and default options.
+    ///
+    /// See [Polyline::parallel_offset_variable_opt] for more information.
+    pub fn parallel_offset_variable(
+        &self,
+        offsets: &[T],
+    ) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        self.parallel_offset_variable_opt(offsets, &Default::default())
+    }
+
+    /// Compute the parallel offset polylines of the polyline using a different offset distance
+    /// for each segment with options given.
+    ///
+    /// `offsets` holds one offset distance per segment, indexed the same as
+    /// [Polyline::iter_segment_indexes]. All offsets must be non-zero and have the same sign
+    /// (positive is to the left of the segment tangent vectors, negative is to the right).
+    /// Segments are joined using [PlineOffsetOptions::join_style] where the offset distance does
+    /// not change, where the offset distance changes at a convex vertex a round join is always
+    /// used with the joining arc using the larger offset distance.
+    ///
+    /// Returns [PlineOpError::OffsetCountMismatch] if the number of `offsets` does not match the
+    /// number of segments and [PlineOpError::InvalidOffset] if an offset is not finite, is zero,
+    /// or does not have the same sign as the first offset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// // inward offset, bottom side by 1 and all other sides by 2
+    /// let results = rectangle
+    ///     .parallel_offset_variable_opt(&[1.0, 2.0, 2.0, 2.0], &Default::default())
+    ///     .unwrap();
+    /// assert_eq!(results.len(), 1);
+    /// let extents = results[0].extents().unwrap();
+    /// assert!(extents.min_x.fuzzy_eq(2.0));
+    /// assert!(extents.min_y.fuzzy_eq(1.0));
+    /// assert!(extents.max_x.fuzzy_eq(8.0));
+    /// assert!(extents.max_y.fuzzy_eq(8.0));
+    /// // offsets must have the same sign
+    /// assert_eq!(
+    ///     rectangle.parallel_offset_variable(&[1.0, -2.0, 2.0, 2.0]).unwrap_err(),
+    ///     PlineOpError::InvalidOffset { segment_index: 1 }
+    /// );
+    /// ```
+    pub fn parallel_offset_variable_opt(
+        &self,
+        offsets: &[T],
+        options: &PlineOffsetOptions<T>,
+    ) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        parallel_offset_variable(self, offsets, options)
+    }
+
+    /// Stroke the polyline with the `width` given using default options.
+    ///
+    /// See [Polyline::stroke_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// let mut pline = Polyline::new();
+    /// pline.add(0.0, 0.0, 0.0);
+    /// pline.add(10.0, 0.0, 0.0);
+    /// let outlines = pline.stroke(2.0);
+    /// assert_eq!(outlines.len(), 1);
+    /// // rectangle with half circle caps at both ends
+    /// let expected_area = 20.0 + std::f64::consts::PI;
+    /// assert!(outlines[0].area().fuzzy_eq(expected_area));
+    /// ```
+    pub fn stroke(&self, width: T) -> Vec<Polyline<T>> {
+        self.stroke_opt(width, &Default::default())
+    }
+
+    /// Stroke the polyline with the `width` given and options, returning the closed polylines
+    /// which bound the area within `width / 2` of the polyline.
+    ///
+    /// The raw parallel offsets of both sides are joined into a single outline (with end caps
+    /// according to [PlineStrokeOptions::cap_style] for open polylines, end caps do not apply to
+    /// closed polylines) which is then resolved using [FillRule::NonZero], so self overlapping
+    /// parts of the stroke are merged and the result has no self intersects.
+    ///
+    /// Returned boundaries have counter clockwise direction and holes (e.g. formed by an open
+    /// polyline that crosses itself or by stroking a closed polyline) have clockwise direction.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineStrokeOptions](crate::polyline::PlineStrokeOptions) for specific parameters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// let mut pline = Polyline::new();
+    /// pline.add(0.0, 0.0, 0.0);
+    /// pline.add(10.0, 0.0, 0.0);
+    /// let options = PlineStrokeOptions {
+    ///     cap_style: StrokeCapStyle::Square,
+    ///     ..Default::default()
+    /// };
+    /// let outlines = pline.stroke_opt(2.0, &options);
+    /// assert_eq!(outlines.len(), 1);
+    /// assert!(outlines[0].area().fuzzy_eq(24.0));
+    /// ```
+    pub fn stroke_opt(&self, width: T, options: &PlineStrokeOptions<T>) -> Vec<Polyline<T>> {
+        stroke(self, width, options)
+    }
+
+    /// Fillet the corner at vertex `index` with a tangent arc of `radius` using default options.
+    ///
+    /// See [Polyline::fillet_vertex_opt] for more information.
+    pub fn fillet_vertex(&self, index: usize, radius: T) -> Result<Polyline<T>, PlineCornerError> {
+        self.fillet_vertex_opt(index, radius, &Default::default())
+    }
+
+    /// Fillet the corner at vertex `index` with a tangent arc of `radius` with options given.
+    ///
+    /// The corner vertex is replaced by an arc tangent to both adjacent segments (lines or arcs),
+    /// the adjacent segments are trimmed to the arc tangent points. The vertex indexes after
+    /// `index` are shifted by one in the returned polyline. The polyline is returned unchanged if
+    /// `radius` is not positive.
+    ///
+    /// An error is returned if the vertex is not a corner or if no fillet of `radius` fits between
+    /// the adjacent segments, see [PlineCornerError].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline<f64> = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let filleted = square.fillet_vertex(1, 2.0).unwrap();
+    /// assert_eq!(filleted.len(), 5);
+    /// let quarter_circle_bulge = (std::f64::consts::PI / 8.0).tan();
+    /// assert!(filleted[1].fuzzy_eq(PlineVertex::new(8.0, 0.0, quarter_circle_bulge)));
+    /// assert!(filleted[2].fuzzy_eq(PlineVertex::new(10.0, 2.0, 0.0)));
+    ///
+    /// assert_eq!(
+    ///     square.fillet_vertex(1, 20.0).unwrap_err(),
+    ///     PlineCornerError::SegmentTooShort { vertex_index: 1 }
+    /// );
+    /// ```
+    pub fn fillet_vertex_opt(
+        &self,
+        index: usize,
+        radius: T,
+        options: &PlineCornerOptions<T>,
+    ) -> Result<Polyline<T>, PlineCornerError> {
+        cut_corner(self, index, CornerOp::Fillet(radius), options)
+    }
+
+    /// Chamfer the corner at vertex `index` at `distance` from the corner using default options.
+    ///
+    /// See [Polyline::chamfer_vertex_opt] for more information.
+    pub fn chamfer_vertex(
+        &self,
+        index: usize,
+        distance: T,
+    ) -> Result<Polyline<T>, PlineCornerError> {
+        self.chamfer_vertex_opt(index, distance, &Default::default())
+    }
+
+    /// Chamfer the corner at vertex `index` at `distance` from the corner with options given.
+    ///
+    /// The corner vertex is replaced by a line segment going from the point at `distance` before
+    /// the corner to the point at `distance` after the corner (distances are measured along the
+    /// adjacent segments). The vertex indexes after `index` are shifted by one in the returned
+    /// polyline. The polyline is returned unchanged if `distance` is not positive.
+    ///
+    /// An error is returned if the vertex is not a corner or if an adjacent segment is shorter
+    /// than `distance`, see [PlineCornerError].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// let mut pline = Polyline::new();
+    /// pline.add(0.0, 0.0, 0.0);
+    /// pline.add(10.0, 0.0, 0.0);
+    /// pline.add(10.0, 10.0, 0.0);
+    /// let chamfered = pline.chamfer_vertex(1, 2.0).unwrap();
+    /// assert_eq!(chamfered.len(), 4);
+    /// assert!(chamfered[1].fuzzy_eq(PlineVertex::new(8.0, 0.0, 0.0)));
+    /// assert!(chamfered[2].fuzzy_eq(PlineVertex::new(10.0, 2.0, 0.0)));
+    ///
+    /// // start of open polyline is not a corner
+    /// assert_eq!(
+    ///     pline.chamfer_vertex(0, 2.0).unwrap_err(),
+    ///     PlineCornerError::NotACorner { vertex_index: 0 }
+    /// );
+    /// ```
+    pub fn chamfer_vertex_opt(
+        &self,
+        index: usize,
+        distance: T,
+        options: &PlineCornerOptions<T>,
+    ) -> Result<Polyline<T>, PlineCornerError> {
+        cut_corner(self, index, CornerOp::Chamfer(distance), options)
+    }
+
+    /// Fillet all the corners of the polyline with tangent arcs of `radius` using default options.
+    ///
+    /// See [Polyline::fillet_all_opt] for more information.
+    pub fn fillet_all(&self, radius: T) -> PlineCornerResult<T> {
+        self.fillet_all_opt(radius, &Default::default())
+    }
+
+    /// Fillet all the corners of the polyline with tangent arcs of `radius` with options given.
+    ///
+    /// Corners are filleted in vertex order (see [Polyline::fillet_vertex_opt]), corners where
+    /// the direction changes by no more than [PlineCornerOptions::angle_threshold] are left
+    /// unchanged. Corners that could not be filleted (including corners whose fillet would
+    /// overlap the fillet of the previous corner) are left unchanged and reported in
+    /// [PlineCornerResult::skipped].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let result = rectangle.fillet_all(1.0);
+    /// assert!(result.skipped.is_empty());
+    /// assert_eq!(result.polyline.len(), 8);
+    /// let expected_area = 40.0 - 4.0 + std::f64::consts::PI;
+    /// assert!(result.polyline.area().fuzzy_eq(expected_area));
+    ///
+    /// // short sides only fit one fillet of radius 3, the last two corners are left unchanged
+    /// let result = rectangle.fillet_all(3.0);
+    /// assert_eq!(result.polyline.len(), 6);
+    /// assert_eq!(
+    ///     result.skipped,
+    ///     vec![
+    ///         PlineCornerError::SegmentTooShort { vertex_index: 2 },
+    ///         PlineCornerError::SegmentTooShort { vertex_index: 3 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn fillet_all_opt(
+        &self,
+        radius: T,
+        options: &PlineCornerOptions<T>,
+    ) -> PlineCornerResult<T> {
+        cut_all_corners(self, CornerOp::Fillet(radius), options)
+    }
+
+    /// Chamfer all the corners of the polyline at `distance` from the corners using default
+    /// options.
+    ///
+    /// See [Polyline::chamfer_all_opt] for more information.
+    pub fn chamfer_all(&self, distance: T) -> PlineCornerResult<T> {
+        self.chamfer_all_opt(distance, &Default::default())
+    }
+
+    /// Chamfer all the corners of the polyline at `distance` from the corners with options given.
+    ///
+    /// Corners are chamfered in vertex order (see [Polyline::chamfer_vertex_opt]), corners where
+    /// the direction changes by no more than [PlineCornerOptions::angle_threshold] are left
+    /// unchanged. Corners that could not be chamfered (including corners whose chamfer would
+    /// overlap the chamfer of the previous corner) are left unchanged and reported in
+    /// [PlineCornerResult::skipped].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let result = rectangle.chamfer_all(1.0);
+    /// assert!(result.skipped.is_empty());
+    /// assert_eq!(result.polyline.len(), 8);
+    /// assert!(result.polyline.area().fuzzy_eq(40.0 - 2.0));
+    /// ```
+    pub fn chamfer_all_opt(
+        &self,
+        distance: T,
+        options: &PlineCornerOptions<T>,
+    ) -> PlineCornerResult<T> {
+        cut_all_corners(self, CornerOp::Chamfer(distance), options)
+    }
+
     /// Perform a boolean `operation` between this polyline and another using default options.
     ///
     /// See [Polyline::boolean_opt] for more information.
@@ -1087,41 +1675,212 @@ where
         polyline_boolean(self, other, operation, &options)
     }
 
-    /// Visit self intersects of the polyline using default options.
-    pub fn visit_self_intersects<C, V>(&self, visitor: &mut V) -> C
-    where
-        C: ControlFlow,
-        V: PlineIntersectVisitor<T, C>,
-    {
-        self.visit_self_intersects_opt(visitor, &Default::default())
+    /// Perform a boolean `operation` between this polyline and another using default options,
+    /// returning an error for invalid input.
+    ///
+    /// See [Polyline::try_boolean_opt] for more information.
+    pub fn try_boolean(
+        &self,
+        other: &Polyline<T>,
+        operation: BooleanOp,
+    ) -> Result<BooleanResult<T>, PlineOpError> {
+        self.try_boolean_opt(other, operation, &Default::default())
     }
 
-    /// Visit self intersects of the polyline using options provided.
-    pub fn visit_self_intersects_opt<C, V>(
+    /// Perform a boolean `operation` between this polyline and another with options provided,
+    /// returning an error for invalid input.
+    ///
+    /// Same as [Polyline::boolean_opt] except both polylines are validated (closed, at least 2
+    /// vertexes, finite values, and no repeat position vertexes) and an error is returned if
+    /// slices failed to stitch together into closed polylines. See [PlineOpError] for all errors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (-1.0, -2.0, 0.0),
+    ///     (3.0, -2.0, 0.0),
+    ///     (3.0, 2.0, 0.0),
+    ///     (-1.0, 2.0, 0.0),
+    /// ];
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let result = rectangle.try_boolean(&circle, BooleanOp::Not).unwrap();
+    /// assert_eq!(result.pos_plines.len(), 1);
+    ///
+    /// let mut open_circle = circle.clone();
+    /// open_circle.set_is_closed(false);
+    /// assert_eq!(
+    ///     rectangle.try_boolean(&open_circle, BooleanOp::Not).unwrap_err(),
+    ///     PlineOpError::OpenInput { input: 1 }
+    /// );
+    /// ```
+    pub fn try_boolean_opt(
         &self,
-        visitor: &mut V,
-        options: &PlineSelfIntersectOptions<T>,
-    ) -> C
-    where
-        C: ControlFlow,
-        V: PlineIntersectVisitor<T, C>,
-    {
-        if self.len() < 2 {
-            return C::continuing();
-        }
-
-        if options.include == SelfIntersectsInclude::Local {
-            // local intersects only
-            return visit_local_self_intersects(self, visitor, options.pos_equal_eps);
-        }
+        other: &Polyline<T>,
+        operation: BooleanOp,
+        options: &PlineBooleanOptions<T>,
+    ) -> Result<BooleanResult<T>, PlineOpError> {
+        try_polyline_boolean(self, other, operation, options)
+    }
 
-        let constructed_index;
-        let index = if let Some(x) = options.aabb_index {
-            x
-        } else {
-            constructed_index = self.create_approx_aabb_index().unwrap();
-            &constructed_index
-        };
+    /// Divide this polyline by another using default options.
+    ///
+    /// See [Polyline::boolean_divide_opt] for more information.
+    pub fn boolean_divide(&self, other: &Polyline<T>) -> BooleanDivideResult<T> {
+        self.boolean_divide_opt(other, &Default::default())
+    }
+
+    /// Divide this polyline into the pieces inside `other` and the pieces outside `other` with
+    /// options provided.
+    ///
+    /// Returns the same results as performing [BooleanOp::And] and [BooleanOp::Not] with
+    /// [Polyline::boolean_opt] but the intersects between the polylines are only found once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let circle = pline_closed![(-1.0, 2.0, 1.0), (1.0, 2.0, 1.0)];
+    /// let result = rectangle.boolean_divide(&circle);
+    /// assert_eq!(result.inside.pos_plines.len(), 1);
+    /// assert_eq!(result.outside.pos_plines.len(), 1);
+    /// let inside_area = result.inside.pos_plines[0].pline.area().abs();
+    /// let outside_area = result.outside.pos_plines[0].pline.area().abs();
+    /// assert!((inside_area + outside_area).fuzzy_eq(rectangle.area()));
+    /// ```
+    pub fn boolean_divide_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineBooleanOptions<T>,
+    ) -> BooleanDivideResult<T> {
+        polyline_boolean_divide(self, other, options)
+    }
+
+    /// Imprint the intersects with another polyline onto this polyline using default options.
+    ///
+    /// See [Polyline::imprint_opt] for more information.
+    pub fn imprint(&self, other: &Polyline<T>) -> Polyline<T> {
+        self.imprint_opt(other, &Default::default())
+    }
+
+    /// Imprint the intersects with another polyline onto this polyline with options provided.
+    ///
+    /// Returns a copy of this polyline with a vertex added at every point where `other` intersects
+    /// it (including the end points of overlapping segments), the shape of the polyline is not
+    /// changed. Both polylines may be open or closed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// let line = pline_open![(0.0, -2.0, 0.0), (0.0, 2.0, 0.0)];
+    /// let result = circle.imprint(&line);
+    /// assert_eq!(result.len(), 4);
+    /// assert!(result[1].pos().fuzzy_eq(Vector2::new(0.0, -1.0)));
+    /// assert!(result[3].pos().fuzzy_eq(Vector2::new(0.0, 1.0)));
+    /// assert!(result.area().fuzzy_eq(circle.area()));
+    /// ```
+    pub fn imprint_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineBooleanOptions<T>,
+    ) -> Polyline<T> {
+        polyline_imprint(self, other, options)
+    }
+
+    /// Clip this polyline against the closed `region` polyline using default options, returning
+    /// the pieces inside the region if `keep_inside` is true or the pieces outside the region
+    /// otherwise.
+    ///
+    /// See [Polyline::clip_open_opt] for more information.
+    pub fn clip_open(&self, region: &Polyline<T>, keep_inside: bool) -> Vec<Polyline<T>> {
+        self.clip_open_opt(region, keep_inside, &Default::default())
+    }
+
+    /// Clip this polyline against the closed `region` polyline with options provided, returning
+    /// the pieces inside the region if `keep_inside` is true or the pieces outside the region
+    /// otherwise.
+    ///
+    /// The polyline is treated as an open path (if closed it starts and ends at the first vertex).
+    /// Pieces that lie along the region boundary are considered inside the region. The pieces are
+    /// returned as open polylines in the order they appear along this polyline. Returns an empty
+    /// vector if `region` is not closed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let line = pline_open![(-5.0, 5.0, 0.0), (15.0, 5.0, 0.0)];
+    /// let inside = line.clip_open(&square, true);
+    /// assert_eq!(inside.len(), 1);
+    /// assert!(inside[0].path_length().fuzzy_eq(10.0));
+    /// let outside = line.clip_open(&square, false);
+    /// assert_eq!(outside.len(), 2);
+    /// assert!(outside[0].path_length().fuzzy_eq(5.0));
+    /// ```
+    pub fn clip_open_opt(
+        &self,
+        region: &Polyline<T>,
+        keep_inside: bool,
+        options: &PlineClipOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        let region = Shape::from_plines(std::iter::once(region.clone()));
+        clip_open(self, &region, keep_inside, options)
+    }
+
+    /// Visit self intersects of the polyline using default options.
+    pub fn visit_self_intersects<C, V>(&self, visitor: &mut V) -> C
+    where
+        C: ControlFlow,
+        V: PlineIntersectVisitor<T, C>,
+    {
+        self.visit_self_intersects_opt(visitor, &Default::default())
+    }
+
+    /// Visit self intersects of the polyline using options provided.
+    pub fn visit_self_intersects_opt<C, V>(
+        &self,
+        visitor: &mut V,
+        options: &PlineSelfIntersectOptions<T>,
+    ) -> C
+    where
+        C: ControlFlow,
+        V: PlineIntersectVisitor<T, C>,
+    {
+        if self.len() < 2 {
+            return C::continuing();
+        }
+
+        if options.include == SelfIntersectsInclude::Local {
+            // local intersects only
+            return visit_local_self_intersects(self, visitor, options.pos_equal_eps);
+        }
+
+        let constructed_index;
+        let index = if let Some(x) = options.aabb_index {
+            x
+        } else {
+            constructed_index = self.create_approx_aabb_index().unwrap();
+            &constructed_index
+        };
 
         if options.include == SelfIntersectsInclude::Global {
             // global intersects only
@@ -1138,6 +1897,88 @@ where
         visit_global_self_intersects(self, index, visitor, options.pos_equal_eps)
     }
 
+    /// Resolve the self intersects of this closed polyline according to `fill_rule` using default
+    /// options.
+    ///
+    /// See [Polyline::resolve_self_intersects_opt] for more information.
+    pub fn resolve_self_intersects(&self, fill_rule: FillRule) -> Vec<Polyline<T>> {
+        self.resolve_self_intersects_opt(fill_rule, &Default::default())
+    }
+
+    /// Resolve the self intersects of this closed polyline according to `fill_rule` with options
+    /// provided.
+    ///
+    /// Returns simple (non-self intersecting) closed polylines which bound the region filled
+    /// according to `fill_rule`. Polylines are oriented with the filled region on their left, so
+    /// outer boundaries are counter clockwise and holes are clockwise. Polylines returned may touch
+    /// each other at a point but do not overlap. Returns an empty vector if this polyline is open.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // bow tie with left triangle counter clockwise and right triangle clockwise
+    /// let bow_tie = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let result = bow_tie.resolve_self_intersects(FillRule::NonZero);
+    /// assert_eq!(result.len(), 2);
+    /// assert!(result.iter().all(|p| p.area().fuzzy_eq(25.0)));
+    /// // only keep counter clockwise region
+    /// let result = bow_tie.resolve_self_intersects(FillRule::Positive);
+    /// assert_eq!(result.len(), 1);
+    /// assert!(result[0].area().fuzzy_eq(25.0));
+    /// assert!(result[0].extents().unwrap().max_x.fuzzy_eq(5.0));
+    /// ```
+    pub fn resolve_self_intersects_opt(
+        &self,
+        fill_rule: FillRule,
+        options: &PlineResolveOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        resolve_self_intersects(self, fill_rule, options)
+    }
+
+    /// Resolve the self intersects of this closed polyline according to `fill_rule` using default
+    /// options, returning an error for invalid input.
+    ///
+    /// See [Polyline::try_resolve_self_intersects_opt] for more information.
+    pub fn try_resolve_self_intersects(
+        &self,
+        fill_rule: FillRule,
+    ) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        self.try_resolve_self_intersects_opt(fill_rule, &Default::default())
+    }
+
+    /// Resolve the self intersects of this closed polyline according to `fill_rule` with options
+    /// provided, returning an error for invalid input.
+    ///
+    /// Same as [Polyline::resolve_self_intersects_opt] except the polyline is validated (closed,
+    /// at least 2 vertexes, finite values, and no repeat position vertexes) and an error is
+    /// returned if slices failed to stitch together into closed polylines. See [PlineOpError] for
+    /// all errors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// let open = pline_open![(0.0, 0.0, 0.0), (10.0, 10.0, 0.0), (10.0, 0.0, 0.0)];
+    /// assert_eq!(
+    ///     open.try_resolve_self_intersects(FillRule::NonZero).unwrap_err(),
+    ///     PlineOpError::OpenInput { input: 0 }
+    /// );
+    /// ```
+    pub fn try_resolve_self_intersects_opt(
+        &self,
+        fill_rule: FillRule,
+        options: &PlineResolveOptions<T>,
+    ) -> Result<Vec<Polyline<T>>, PlineOpError> {
+        try_resolve_self_intersects(self, fill_rule, options)
+    }
+
     /// Find all intersects between two polylines using default options.
     pub fn find_intersects(&self, other: &Polyline<T>) -> PlineIntersectsCollection<T> {
         self.find_intersects_opt(other, &Default::default())
@@ -1286,6 +2127,247 @@ where
         Some(result)
     }
 
+    /// Find the minimum distance between this polyline and another using default options.
+    ///
+    /// See [Polyline::min_distance_opt] for more information.
+    pub fn min_distance(&self, other: &Polyline<T>) -> Option<MinDistanceResult<T>> {
+        self.min_distance_opt(other, &Default::default())
+    }
+
+    /// Find the minimum distance between this polyline and another with options provided.
+    ///
+    /// Returns the distance along with the closest point and closest segment start index on each
+    /// polyline. Segment pairs are pruned using a spatial index of this polyline's segments and the
+    /// exact closest points are found for each remaining segment pair (line-line, line-arc, and
+    /// arc-arc). If the polylines intersect then the distance is zero and both points are an
+    /// intersect point. If either polyline is empty then `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let square: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let circle = pline_closed![(13.0, 5.0, 1.0), (15.0, 5.0, 1.0)];
+    /// let result = square.min_distance(&circle).unwrap();
+    /// assert!(result.distance.fuzzy_eq(3.0));
+    /// assert_eq!(result.seg_start_index1, 1);
+    /// assert!(result.point1.fuzzy_eq(Vector2::new(10.0, 5.0)));
+    /// assert!(result.point2.fuzzy_eq(Vector2::new(13.0, 5.0)));
+    /// ```
+    pub fn min_distance_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineMinDistanceOptions<T>,
+    ) -> Option<MinDistanceResult<T>> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        if self.len() == 1 || other.len() == 1 {
+            let (pline, point, swapped) = if self.len() == 1 {
+                (other, self[0].pos(), false)
+            } else {
+                (self, other[0].pos(), true)
+            };
+
+            let cp = pline.closest_point(point).unwrap();
+            let result = if swapped {
+                MinDistanceResult {
+                    seg_start_index1: cp.seg_start_index,
+                    point1: cp.seg_point,
+                    seg_start_index2: 0,
+                    point2: point,
+                    distance: cp.distance,
+                }
+            } else {
+                MinDistanceResult {
+                    seg_start_index1: 0,
+                    point1: point,
+                    seg_start_index2: cp.seg_start_index,
+                    point2: cp.seg_point,
+                    distance: cp.distance,
+                }
+            };
+
+            return Some(result);
+        }
+
+        let constructed_index;
+        let index = if let Some(x) = options.pline1_aabb_index {
+            x
+        } else {
+            constructed_index = self.create_aabb_index().unwrap();
+            &constructed_index
+        };
+
+        // initial upper bound on the distance from the first vertex of other
+        let cp = self.closest_point(other[0].pos()).unwrap();
+        let mut result = MinDistanceResult {
+            seg_start_index1: cp.seg_start_index,
+            point1: cp.seg_point,
+            seg_start_index2: 0,
+            point2: other[0].pos(),
+            distance: cp.distance,
+        };
+
+        let mut query_stack = Vec::new();
+        for (i, j) in other.iter_segment_indexes() {
+            if result.distance <= T::zero() {
+                break;
+            }
+
+            let u1 = other[i];
+            let u2 = other[j];
+            let bb = seg_fast_approx_bounding_box(u1, u2);
+            let d = result.distance;
+            index.visit_query_with_stack(
+                bb.min_x - d,
+                bb.min_y - d,
+                bb.max_x + d,
+                bb.max_y + d,
+                &mut |k: usize| {
+                    let v1 = self[k];
+                    let v2 = self[self.next_wrapping_index(k)];
+                    let (p1, p2) = seg_closest_points(v1, v2, u1, u2);
+                    let dist = (p2 - p1).length();
+                    if dist < result.distance {
+                        result = MinDistanceResult {
+                            seg_start_index1: k,
+                            point1: p1,
+                            seg_start_index2: i,
+                            point2: p2,
+                            distance: dist,
+                        };
+                    }
+                },
+                &mut query_stack,
+            );
+        }
+
+        Some(result)
+    }
+
+    /// Compute the directed Hausdorff distance from this polyline to another using default
+    /// options.
+    ///
+    /// See [Polyline::directed_hausdorff_distance_opt] for more information.
+    pub fn directed_hausdorff_distance(
+        &self,
+        other: &Polyline<T>,
+    ) -> Option<ShapeDistanceResult<T>> {
+        self.directed_hausdorff_distance_opt(other, &Default::default())
+    }
+
+    /// Compute the directed Hausdorff distance from this polyline to another with options
+    /// provided.
+    ///
+    /// The directed Hausdorff distance is the maximum distance from any point on this polyline to
+    /// the closest point on `other`, `point1` of the result is the farthest point found on this
+    /// polyline and `point2` is the closest point to it on `other`. This polyline is sampled (see
+    /// [PlineShapeDistanceOptions::sample_spacing]) so the distance returned may underestimate the
+    /// true distance by at most half the sample spacing. If either polyline is empty then `None`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_open;
+    /// let line: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let short_line = pline_open![(2.0, 1.0, 0.0), (8.0, 1.0, 0.0)];
+    /// // every point on the short line is 1 away from the long line
+    /// let result = short_line.directed_hausdorff_distance(&line).unwrap();
+    /// assert!(result.distance.fuzzy_eq(1.0));
+    /// // ends of the long line are farther from the short line
+    /// let result = line.directed_hausdorff_distance(&short_line).unwrap();
+    /// assert!(result.distance.fuzzy_eq(5.0f64.sqrt()));
+    /// ```
+    pub fn directed_hausdorff_distance_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineShapeDistanceOptions<T>,
+    ) -> Option<ShapeDistanceResult<T>> {
+        directed_hausdorff_distance(self, other, options)
+    }
+
+    /// Compute the Hausdorff distance between this polyline and another using default options.
+    ///
+    /// See [Polyline::hausdorff_distance_opt] for more information.
+    pub fn hausdorff_distance(&self, other: &Polyline<T>) -> Option<ShapeDistanceResult<T>> {
+        self.hausdorff_distance_opt(other, &Default::default())
+    }
+
+    /// Compute the Hausdorff distance between this polyline and another with options provided.
+    ///
+    /// The Hausdorff distance is the maximum of the directed Hausdorff distances in both
+    /// directions (see [Polyline::directed_hausdorff_distance_opt]), it measures how far the two
+    /// shapes deviate from each other regardless of vertex count, start index, or direction.
+    /// `point1` of the result is always on this polyline and `point2` is always on `other`. If
+    /// either polyline is empty then `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    /// // same circle starting at a different point and split into more segments
+    /// let same_circle = pline_closed![(5.0, 5.0, 0.414213562373095), (0.0, 0.0, 1.0), (10.0, 0.0, 0.414213562373095)];
+    /// assert!(circle.hausdorff_distance(&same_circle).unwrap().distance.fuzzy_eq(0.0));
+    /// let larger_circle = pline_closed![(-1.0, 0.0, 1.0), (11.0, 0.0, 1.0)];
+    /// assert!(circle.hausdorff_distance(&larger_circle).unwrap().distance.fuzzy_eq(1.0));
+    /// ```
+    pub fn hausdorff_distance_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineShapeDistanceOptions<T>,
+    ) -> Option<ShapeDistanceResult<T>> {
+        hausdorff_distance(self, other, options)
+    }
+
+    /// Compute the discrete Fréchet distance between this polyline and another using default
+    /// options.
+    ///
+    /// See [Polyline::frechet_distance_opt] for more information.
+    pub fn frechet_distance(&self, other: &Polyline<T>) -> Option<ShapeDistanceResult<T>> {
+        self.frechet_distance_opt(other, &Default::default())
+    }
+
+    /// Compute the discrete Fréchet distance between this polyline and another with options
+    /// provided.
+    ///
+    /// The Fréchet distance also accounts for the order of points along the paths (it is always
+    /// at least the Hausdorff distance), both polylines are sampled (see
+    /// [PlineShapeDistanceOptions::sample_spacing]) and the discrete Fréchet distance between the
+    /// samples is returned along with the pair of samples realizing it. Closed polylines are
+    /// traversed starting and ending at their first vertex so the start index and direction
+    /// matter. If either polyline is empty then `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_open;
+    /// let line: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let mut reversed = line.clone();
+    /// reversed.invert_direction();
+    /// assert!(line.frechet_distance(&reversed).unwrap().distance.fuzzy_eq(10.0));
+    /// assert!(line.hausdorff_distance(&reversed).unwrap().distance.fuzzy_eq(0.0));
+    /// ```
+    pub fn frechet_distance_opt(
+        &self,
+        other: &Polyline<T>,
+        options: &PlineShapeDistanceOptions<T>,
+    ) -> Option<ShapeDistanceResult<T>> {
+        frechet_distance(self, other, options)
+    }
+
     /// Returns the total path length of the polyline.
     ///
     /// # Examples
@@ -1307,6 +2389,405 @@ where
             .fold(T::zero(), |acc, (v1, v2)| acc + seg_length(v1, v2))
     }
 
+    /// Create a table of the cumulative path lengths of the polyline for fast repeated queries by
+    /// path length (see [PlineLengthTable]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    /// let table = polyline.length_table();
+    /// assert!(table.total_length().fuzzy_eq(10.0 + 2.0 * std::f64::consts::PI));
+    /// assert!(table.point_at_length(3.7).unwrap().fuzzy_eq(Vector2::new(3.7, 0.0)));
+    /// let (seg_index, seg_offset) = table.seg_at_length(12.0).unwrap();
+    /// assert_eq!(seg_index, 1);
+    /// assert!(seg_offset.fuzzy_eq(2.0));
+    /// ```
+    pub fn length_table(&self) -> PlineLengthTable<'_, T> {
+        PlineLengthTable::new(self)
+    }
+
+    /// Find the point at the path `length` along the polyline.
+    ///
+    /// `length` is clamped to the range `[0, path_length]`, arcs are evaluated exactly from their
+    /// bulge. Returns `None` if the polyline has less than 2 vertexes. Use [Polyline::length_table]
+    /// for repeated queries on the same polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle with radius 1 centered at (1, 0)
+    /// let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let quarter = std::f64::consts::PI / 2.0;
+    /// assert!(circle.point_at_length(quarter).unwrap().fuzzy_eq(Vector2::new(1.0, -1.0)));
+    /// assert!(circle.point_at_length(3.0 * quarter).unwrap().fuzzy_eq(Vector2::new(1.0, 1.0)));
+    /// ```
+    pub fn point_at_length(&self, length: T) -> Option<Vector2<T>> {
+        self.length_table().point_at_length(length)
+    }
+
+    /// Find the unit tangent direction vector at the path `length` along the polyline.
+    ///
+    /// `length` is clamped to the range `[0, path_length]`. At a vertex the tangent of the segment
+    /// starting at the vertex is returned (except at the end of the path). Returns `None` if the
+    /// polyline has less than 2 vertexes or the path length is zero. Use
+    /// [Polyline::length_table] for repeated queries on the same polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle with radius 1 centered at (1, 0)
+    /// let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let quarter = std::f64::consts::PI / 2.0;
+    /// assert!(circle.tangent_at_length(0.0).unwrap().fuzzy_eq(Vector2::new(0.0, -1.0)));
+    /// assert!(circle.tangent_at_length(quarter).unwrap().fuzzy_eq(Vector2::new(1.0, 0.0)));
+    /// ```
+    pub fn tangent_at_length(&self, length: T) -> Option<Vector2<T>> {
+        self.length_table().tangent_at_length(length)
+    }
+
+    /// Find the path length from the start of the polyline to `point` on the segment starting at
+    /// `seg_start_index`, e.g. the result of [Polyline::closest_point]. Use
+    /// [Polyline::length_table] for repeated queries on the same polyline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seg_start_index` is not the start of a segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    /// let closest = polyline.closest_point(Vector2::new(12.0, 4.0)).unwrap();
+    /// let length = polyline.length_at_point(closest.seg_start_index, closest.seg_point);
+    /// assert!(length.fuzzy_eq(14.0));
+    /// ```
+    pub fn length_at_point(&self, seg_start_index: usize, point: Vector2<T>) -> T {
+        self.length_table().length_at_point(seg_start_index, point)
+    }
+
+    /// Split the polyline at the path `length` using default options.
+    ///
+    /// See [Polyline::split_at_length_opt] for more information.
+    pub fn split_at_length(&self, length: T) -> Vec<Polyline<T>> {
+        self.split_at_length_opt(length, &Default::default())
+    }
+
+    /// Split the polyline at the path `length` with options provided.
+    ///
+    /// An open polyline is split into the (up to 2) open polylines before and after the split
+    /// point (`length` is clamped to the path). A closed polyline is opened at the split point
+    /// (`length` wraps around the loop), returning a single open polyline that starts and ends at
+    /// the split point. Pieces shorter than [PlineSplitOptions::pos_equal_eps] are discarded, an
+    /// empty `Vec` is returned if the polyline has less than 2 vertexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    /// let pieces = polyline.split_at_length(3.7);
+    /// assert_eq!(pieces.len(), 2);
+    /// assert!(pieces[0].path_length().fuzzy_eq(3.7));
+    /// assert!(pieces[1].path_length().fuzzy_eq(polyline.path_length() - 3.7));
+    /// ```
+    pub fn split_at_length_opt(
+        &self,
+        length: T,
+        options: &PlineSplitOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        split_at_lengths(self, &[length], options)
+    }
+
+    /// Split the polyline at all the `points` given using default options.
+    ///
+    /// See [Polyline::split_at_points_opt] for more information.
+    pub fn split_at_points(&self, points: &[(usize, Vector2<T>)]) -> Vec<Polyline<T>> {
+        self.split_at_points_opt(points, &Default::default())
+    }
+
+    /// Split the polyline at all the `points` given with options provided.
+    ///
+    /// Each point is given as the start index of the segment it lies on and its position (e.g.
+    /// from [Polyline::closest_point] or intersects), points may be given in any order. An open
+    /// polyline is split into the pieces between the points, a closed polyline is split into the
+    /// pieces between the points going around the loop (the last piece passes through the first
+    /// vertex, a single point opens the loop). Pieces shorter than
+    /// [PlineSplitOptions::pos_equal_eps] are discarded. If no points are given then the polyline
+    /// is returned unchanged, an empty `Vec` is returned if the polyline has less than 2 vertexes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any segment start index is not the start of a segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let pieces = square.split_at_points(&[(2, Vector2::new(5.0, 10.0)), (0, Vector2::new(5.0, 0.0))]);
+    /// assert_eq!(pieces.len(), 2);
+    /// assert!(pieces[0][0].pos().fuzzy_eq(Vector2::new(5.0, 0.0)));
+    /// assert!(pieces[0].path_length().fuzzy_eq(20.0));
+    /// assert!(pieces[1][0].pos().fuzzy_eq(Vector2::new(5.0, 10.0)));
+    /// assert!(pieces[1].path_length().fuzzy_eq(20.0));
+    /// ```
+    pub fn split_at_points_opt(
+        &self,
+        points: &[(usize, Vector2<T>)],
+        options: &PlineSplitOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        if self.len() < 2 {
+            return Vec::new();
+        }
+
+        let table = self.length_table();
+        let lengths: Vec<T> = points
+            .iter()
+            .map(|&(seg_start_index, point)| table.length_at_point(seg_start_index, point))
+            .collect();
+        split_at_lengths(self, &lengths, options)
+    }
+
+    /// Get the part of the polyline between path lengths using default options.
+    ///
+    /// See [Polyline::sub_polyline_opt] for more information.
+    pub fn sub_polyline(&self, start_length: T, end_length: T) -> Option<Polyline<T>> {
+        self.sub_polyline_opt(start_length, end_length, &Default::default())
+    }
+
+    /// Get the part of the polyline between path lengths with options provided.
+    ///
+    /// Returns an open polyline following the path from `start_length` to `end_length`, arcs are
+    /// trimmed exactly. For open polylines the lengths are clamped to the path and `None` is
+    /// returned if `start_length >= end_length`. For closed polylines the lengths wrap around the
+    /// loop and if `end_length < start_length` the result passes through the first vertex. `None`
+    /// is also returned if the polyline has less than 2 vertexes or the result would be shorter
+    /// than [PlineSplitOptions::pos_equal_eps].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // wraps around through the first vertex
+    /// let part = square.sub_polyline(35.0, 5.0).unwrap();
+    /// assert!(!part.is_closed());
+    /// assert_eq!(part.len(), 3);
+    /// assert!(part[0].pos().fuzzy_eq(Vector2::new(0.0, 5.0)));
+    /// assert!(part[1].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    /// assert!(part[2].pos().fuzzy_eq(Vector2::new(5.0, 0.0)));
+    /// ```
+    pub fn sub_polyline_opt(
+        &self,
+        start_length: T,
+        end_length: T,
+        options: &PlineSplitOptions<T>,
+    ) -> Option<Polyline<T>> {
+        sub_polyline(self, start_length, end_length, options)
+    }
+
+    /// Open this closed polyline at a point using default options.
+    ///
+    /// See [Polyline::open_at_point_opt] for more information.
+    pub fn open_at_point(&self, seg_start_index: usize, point: Vector2<T>) -> Option<Polyline<T>> {
+        self.open_at_point_opt(seg_start_index, point, &Default::default())
+    }
+
+    /// Open this closed polyline at a point with options provided.
+    ///
+    /// Returns an open polyline that starts at `point` (on the segment starting at
+    /// `seg_start_index`), follows the whole loop in the same direction, and ends back at `point`.
+    /// Returns `None` if the polyline is open, has less than 2 vertexes, or has a path length
+    /// shorter than [PlineSplitOptions::pos_equal_eps].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seg_start_index` is not the start of a segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let opened = circle.open_at_point(0, Vector2::new(1.0, -1.0)).unwrap();
+    /// assert!(!opened.is_closed());
+    /// assert_eq!(opened.len(), 4);
+    /// assert!(opened[0].pos().fuzzy_eq(Vector2::new(1.0, -1.0)));
+    /// assert!(opened.last().unwrap().pos().fuzzy_eq(Vector2::new(1.0, -1.0)));
+    /// assert!(opened.path_length().fuzzy_eq(circle.path_length()));
+    /// ```
+    pub fn open_at_point_opt(
+        &self,
+        seg_start_index: usize,
+        point: Vector2<T>,
+        options: &PlineSplitOptions<T>,
+    ) -> Option<Polyline<T>> {
+        open_at_point(self, seg_start_index, point, options)
+    }
+
+    /// Iterate through stations (positions and unit tangents) spaced evenly along the polyline
+    /// path, `step` apart.
+    ///
+    /// For open polylines the stations are at path lengths `0, step, 2 * step, ...` up to the end
+    /// of the path (the end is only included if the path length is a multiple of `step`). For
+    /// closed polylines the spacing is adjusted to the closest value to `step` that divides the
+    /// path length evenly, so the spacing across the first vertex (seam) matches and the station at
+    /// the end of the path is not repeated. Arcs are evaluated exactly from their bulge. Nothing is
+    /// yielded if `step` is not positive or the polyline has less than 2 vertexes or zero path
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // step adjusted to 40 / 13
+    /// let stations: Vec<_> = square.resample_by_distance(3.0).collect();
+    /// assert_eq!(stations.len(), 13);
+    /// assert!(stations[1].length.fuzzy_eq(40.0 / 13.0));
+    /// assert!(stations[4].point.fuzzy_eq(Vector2::new(10.0, 40.0 * 4.0 / 13.0 - 10.0)));
+    /// assert!(stations[4].tangent.fuzzy_eq(Vector2::new(0.0, 1.0)));
+    /// ```
+    pub fn resample_by_distance(&self, step: T) -> impl Iterator<Item = PlineStation<T>> + '_ {
+        PlineResampleIterator::new(self.length_table(), step)
+    }
+
+    /// Divide the polyline path into `n` equal lengths, returning the stations (positions and
+    /// unit tangents) at the divisions.
+    ///
+    /// For open polylines `n + 1` stations are returned (including both ends). For closed
+    /// polylines `n` stations are returned, starting at the first vertex, since the station at the
+    /// end of the path is the same as the first station (seam). Returns an empty `Vec` if `n` is
+    /// zero or the polyline has less than 2 vertexes or zero path length. Use
+    /// [Polyline::insert_stations] to insert the stations as vertexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline: Polyline<f64> = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    /// let stations = polyline.divide(4);
+    /// assert_eq!(stations.len(), 5);
+    /// assert!(stations[1].point.fuzzy_eq(Vector2::new(5.0, 0.0)));
+    /// assert!(stations[3].point.fuzzy_eq(Vector2::new(10.0, 5.0)));
+    /// assert!(stations[3].tangent.fuzzy_eq(Vector2::new(0.0, 1.0)));
+    /// ```
+    pub fn divide(&self, n: usize) -> Vec<PlineStation<T>> {
+        let table = self.length_table();
+        let total = table.total_length();
+        if n == 0 || total == T::zero() {
+            return Vec::new();
+        }
+
+        let count = if self.is_closed() { n } else { n + 1 };
+        let step = total / T::from(n).unwrap();
+        (0..count)
+            .filter_map(|i| table.station_at_length(step * T::from(i).unwrap()))
+            .collect()
+    }
+
+    /// Insert stations as vertexes using default options.
+    ///
+    /// See [Polyline::insert_stations_opt] for more information.
+    pub fn insert_stations(&self, stations: &[PlineStation<T>]) -> Polyline<T> {
+        self.insert_stations_opt(stations, &Default::default())
+    }
+
+    /// Insert stations (e.g. from [Polyline::divide] or [Polyline::resample_by_distance]) as
+    /// vertexes with options provided.
+    ///
+    /// Returns a copy of the polyline with a vertex added at each station point, segments are
+    /// split using [seg_split_at_point] so arcs keep their exact shape. Stations at existing
+    /// vertexes (fuzzy compared using [PlineSplitOptions::pos_equal_eps]) do not add vertexes. The
+    /// stations may be given in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let result = circle.insert_stations(&circle.divide(4));
+    /// assert_eq!(result.len(), 4);
+    /// assert!(result[1].pos().fuzzy_eq(Vector2::new(1.0, -1.0)));
+    /// assert!(result.area().fuzzy_eq(circle.area()));
+    /// ```
+    pub fn insert_stations_opt(
+        &self,
+        stations: &[PlineStation<T>],
+        options: &PlineSplitOptions<T>,
+    ) -> Polyline<T> {
+        let pos_equal_eps = options.pos_equal_eps;
+        let mut sorted = stations.to_vec();
+        sorted.sort_unstable_by(|a, b| {
+            a.seg_start_index
+                .cmp(&b.seg_start_index)
+                .then(a.length.partial_cmp(&b.length).unwrap())
+        });
+
+        let mut result = Polyline::with_capacity(self.len() + sorted.len(), self.is_closed());
+        let mut k = 0;
+        for (i, j) in self.iter_segment_indexes() {
+            let mut current = self[i];
+            let v2 = self[j];
+            while k < sorted.len() && sorted[k].seg_start_index == i {
+                let split = seg_split_at_point(current, v2, sorted[k].point, pos_equal_eps);
+                result.add_or_replace_vertex(split.updated_start, pos_equal_eps);
+                current = split.split_vertex;
+                k += 1;
+            }
+            result.add_or_replace_vertex(current, pos_equal_eps);
+        }
+
+        if self.is_closed() {
+            if result.len() > 1
+                && result[0]
+                    .pos()
+                    .fuzzy_eq_eps(result.last().unwrap().pos(), pos_equal_eps)
+            {
+                // station inserted at the end of the closing segment
+                result.remove_last();
+            }
+        } else if let Some(&last) = self.last() {
+            result.add_or_replace_vertex(last, pos_equal_eps);
+        }
+
+        result
+    }
+
     /// Helper function for processing a line segment when computing the winding number.
     fn process_line_winding(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>) -> i32 {
         let mut result = 0;
@@ -1490,6 +2971,42 @@ where
         winding
     }
 
+    /// Same as [Polyline::winding_number] but uses the spatial index of the polyline segments
+    /// (`aabb_index`) to only process the segments that may cross the ray cast from `point` in the
+    /// +x direction.
+    pub(crate) fn winding_number_indexed(
+        &self,
+        point: Vector2<T>,
+        aabb_index: &StaticAABB2DIndex<T>,
+        query_stack: &mut Vec<usize>,
+    ) -> i32 {
+        if !self.is_closed || self.len() < 2 || point.x > aabb_index.max_x() {
+            return 0;
+        }
+
+        let mut winding = 0;
+        let mut visitor = |i: usize| {
+            let v1 = self[i];
+            let v2 = self[self.next_wrapping_index(i)];
+            if v1.bulge_is_zero() {
+                winding += Self::process_line_winding(v1, v2, point);
+            } else {
+                winding += Self::process_arc_winding(v1, v2, point);
+            }
+        };
+
+        aabb_index.visit_query_with_stack(
+            point.x,
+            point.y,
+            aabb_index.max_x(),
+            point.y,
+            &mut visitor,
+            query_stack,
+        );
+
+        winding
+    }
+
     /// Returns a new polyline with all arc segments converted to line segments with some
     /// `error_distance` or None if T fails to cast to or from usize.
     ///
@@ -1630,6 +3147,73 @@ where
     }
 }
 
+/// An iterator that yields stations evenly spaced along a polyline path.
+struct PlineResampleIterator<'a, T>
+where
+    T: Real,
+{
+    table: PlineLengthTable<'a, T>,
+    step: T,
+    pos: usize,
+    count: usize,
+}
+
+impl<'a, T> PlineResampleIterator<'a, T>
+where
+    T: Real,
+{
+    fn new(table: PlineLengthTable<'a, T>, step: T) -> PlineResampleIterator<'a, T> {
+        let total = table.total_length();
+        let mut result = PlineResampleIterator {
+            table,
+            step,
+            pos: 0,
+            count: 0,
+        };
+
+        let step_is_valid = step > T::zero() && step <= Real::max_value();
+        if !step_is_valid || total == T::zero() {
+            return result;
+        }
+
+        if result.table.pline().is_closed() {
+            // adjust step to evenly divide the loop
+            let n = num_traits::real::Real::max((total / step).round(), T::one());
+            result.step = total / n;
+            result.count = n.to_usize().unwrap_or(0);
+        } else {
+            let n = ((total + T::fuzzy_epsilon()) / step).floor();
+            result.count = n.to_usize().map(|n| n + 1).unwrap_or(0);
+        }
+
+        result
+    }
+}
+
+impl<'a, T> Iterator for PlineResampleIterator<'a, T>
+where
+    T: Real,
+{
+    type Item = PlineStation<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.count {
+            return None;
+        }
+
+        let length = self.step * T::from(self.pos).unwrap();
+        self.pos += 1;
+        self.table.station_at_length(length)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
 /// An iterator that traverses all segment vertex pair index positions.
 struct PlineSegIndexIterator {
     pos: usize,
@@ -1,9 +1,9 @@
-use super::PlineVertex;
+use super::{pline_seg_intr, PlineSegIntr, PlineVertex};
 use crate::core::{
     math::{
-        angle, angle_is_within_sweep, bulge_from_angle, delta_angle, delta_angle_signed,
-        dist_squared, line_seg_closest_point, midpoint, min_max, point_on_circle,
-        point_within_arc_sweep, Vector2,
+        angle, angle_from_bulge, angle_is_within_sweep, bulge_from_angle, delta_angle,
+        delta_angle_signed, dist_squared, line_seg_closest_point, midpoint, min_max,
+        normalize_radians, point_on_circle, point_within_arc_sweep, Vector2,
     },
     traits::Real,
 };
@@ -236,6 +236,133 @@ where
     v2.pos()
 }
 
+/// Find the closest points between the polyline segment defined by `v1` to `v2` and the polyline
+/// segment defined by `u1` to `u2`.
+///
+/// Returns the closest point on the first segment and the closest point on the second segment. If
+/// the segments intersect then both points are the same intersect point. If there are multiple
+/// closest point pairs then one is chosen (which is chosen is not defined).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::polyline::*;
+/// // line segment from (0, 3) to (4, 3)
+/// let v1 = PlineVertex::new(0.0, 3.0, 0.0);
+/// let v2 = PlineVertex::new(4.0, 3.0, 0.0);
+/// // counter clockwise half circle arc going from (0, 0) to (4, 0) bulging down
+/// let u1 = PlineVertex::new(0.0, 0.0, 1.0);
+/// let u2 = PlineVertex::new(4.0, 0.0, 0.0);
+/// let (p1, p2) = seg_closest_points(v1, v2, u1, u2);
+/// assert!(p1.fuzzy_eq(Vector2::new(0.0, 3.0)));
+/// assert!(p2.fuzzy_eq(Vector2::new(0.0, 0.0)));
+/// // clockwise half circle arc going from (0, 0) to (4, 0) bulging up to (2, 2)
+/// let u1 = PlineVertex::new(0.0, 0.0, -1.0);
+/// let (p1, p2) = seg_closest_points(v1, v2, u1, u2);
+/// assert!(p1.fuzzy_eq(Vector2::new(2.0, 3.0)));
+/// assert!(p2.fuzzy_eq(Vector2::new(2.0, 2.0)));
+/// ```
+pub fn seg_closest_points<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    u1: PlineVertex<T>,
+    u2: PlineVertex<T>,
+) -> (Vector2<T>, Vector2<T>)
+where
+    T: Real,
+{
+    match pline_seg_intr(v1, v2, u1, u2) {
+        PlineSegIntr::NoIntersect => {}
+        PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+            return (point, point);
+        }
+        PlineSegIntr::TwoIntersects { point1, .. }
+        | PlineSegIntr::OverlappingLines { point1, .. }
+        | PlineSegIntr::OverlappingArcs { point1, .. } => {
+            return (point1, point1);
+        }
+    }
+
+    let mut result = (v1.pos(), seg_closest_point(u1, u2, v1.pos()));
+    let mut min_dist = dist_squared(result.0, result.1);
+    let mut consider = |p1: Vector2<T>, p2: Vector2<T>| {
+        let dist = dist_squared(p1, p2);
+        if dist < min_dist {
+            result = (p1, p2);
+            min_dist = dist;
+        }
+    };
+
+    // closest points at the segment end points
+    consider(v2.pos(), seg_closest_point(u1, u2, v2.pos()));
+    consider(seg_closest_point(v1, v2, u1.pos()), u1.pos());
+    consider(seg_closest_point(v1, v2, u2.pos()), u2.pos());
+
+    // closest points within both segments (line between the points is perpendicular to both
+    // segments), only possible if at least one of the segments is an arc
+    let arc_points_nearest_line =
+        |a1: PlineVertex<T>, a2: PlineVertex<T>, l1: Vector2<T>, l2: Vector2<T>| {
+            let (radius, center) = seg_arc_radius_and_center(a1, a2);
+            let offset = (l2 - l1).unit_perp().scale(radius);
+            let within_sweep = |p: &Vector2<T>| {
+                point_within_arc_sweep(center, a1.pos(), a2.pos(), a1.bulge_is_neg(), *p)
+            };
+            [
+                Some(center + offset).filter(within_sweep),
+                Some(center - offset).filter(within_sweep),
+            ]
+        };
+
+    match (v1.bulge_is_zero(), u1.bulge_is_zero()) {
+        (true, true) => {}
+        (false, true) => {
+            for &p in arc_points_nearest_line(v1, v2, u1.pos(), u2.pos())
+                .iter()
+                .flatten()
+            {
+                consider(p, seg_closest_point(u1, u2, p));
+            }
+        }
+        (true, false) => {
+            for &p in arc_points_nearest_line(u1, u2, v1.pos(), v2.pos())
+                .iter()
+                .flatten()
+            {
+                consider(seg_closest_point(v1, v2, p), p);
+            }
+        }
+        (false, false) => {
+            let (radius1, center1) = seg_arc_radius_and_center(v1, v2);
+            let (radius2, center2) = seg_arc_radius_and_center(u1, u2);
+            // concentric arcs are handled by the end points
+            if !center1.fuzzy_eq(center2) {
+                let dir = (center2 - center1).normalize();
+                for &s1 in [T::one(), -T::one()].iter() {
+                    let p1 = center1 + dir.scale(s1 * radius1);
+                    if !point_within_arc_sweep(center1, v1.pos(), v2.pos(), v1.bulge_is_neg(), p1) {
+                        continue;
+                    }
+                    for &s2 in [T::one(), -T::one()].iter() {
+                        let p2 = center2 + dir.scale(s2 * radius2);
+                        if point_within_arc_sweep(
+                            center2,
+                            u1.pos(),
+                            u2.pos(),
+                            u1.bulge_is_neg(),
+                            p2,
+                        ) {
+                            consider(p1, p2);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
 /// Computes a fast approximate axis aligned bounding box of a polyline segment defined by `v1` to `v2`.
 ///
 /// The bounding box may be larger than the true bounding box for the segment (but is never smaller).
@@ -382,6 +509,106 @@ where
     arc_radius * delta_angle(start_angle, end_angle).abs()
 }
 
+/// Find the point at the path `length` along the polyline segment defined by `v1` to `v2`
+/// (measured from `v1`). Arc points are computed exactly from the bulge. `length` is not clamped
+/// to the segment, lengths past the end extend along the line or continue around the arc circle.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// // counter clockwise half circle arc going from (2, 2) to (4, 2), radius = 1
+/// let v1 = PlineVertex::new(2.0, 2.0, 1.0);
+/// let v2 = PlineVertex::new(4.0, 2.0, 0.0);
+/// let length = std::f64::consts::PI / 2.0;
+/// assert!(seg_point_at_length(v1, v2, length).fuzzy_eq(Vector2::new(3.0, 1.0)));
+/// // line segment going from (2, 2) to (4, 2)
+/// let v1 = PlineVertex::new(2.0, 2.0, 0.0);
+/// assert!(seg_point_at_length(v1, v2, 0.5).fuzzy_eq(Vector2::new(2.5, 2.0)));
+/// ```
+pub fn seg_point_at_length<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, length: T) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = v2.pos() - v1.pos();
+        let seg_length = dir.length();
+        if seg_length == T::zero() {
+            return v1.pos();
+        }
+
+        return v1.pos() + dir.scale(length / seg_length);
+    }
+
+    let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(arc_center, v1.pos());
+    let delta = length / arc_radius;
+    let point_angle = if v1.bulge_is_neg() {
+        start_angle - delta
+    } else {
+        start_angle + delta
+    };
+
+    point_on_circle(arc_radius, arc_center, point_angle)
+}
+
+/// Find the path length along the polyline segment defined by `v1` to `v2` from `v1` to `point`.
+///
+/// `point` is expected to lie on the segment, if it does not then the length to the closest point
+/// on the line (or closest arc end point if outside the arc sweep) is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::core::math::*;
+/// // clockwise half circle arc going from (2, 2) to (4, 2), radius = 1
+/// let v1 = PlineVertex::new(2.0, 2.0, -1.0);
+/// let v2 = PlineVertex::new(4.0, 2.0, 0.0);
+/// let length = seg_length_to_point(v1, v2, Vector2::new(3.0, 3.0));
+/// assert!(length.fuzzy_eq(std::f64::consts::PI / 2.0));
+/// assert!(seg_length_to_point(v1, v2, v2.pos()).fuzzy_eq(std::f64::consts::PI));
+/// ```
+pub fn seg_length_to_point<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>) -> T
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = v2.pos() - v1.pos();
+        let seg_length_squared = dir.length_squared();
+        if seg_length_squared == T::zero() {
+            return T::zero();
+        }
+
+        let t = (point - v1.pos()).dot(dir) / seg_length_squared;
+        let t = num_traits::real::Real::min(num_traits::real::Real::max(t, T::zero()), T::one());
+        return t * seg_length_squared.sqrt();
+    }
+
+    let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(arc_center, v1.pos());
+    let point_angle = angle(arc_center, point);
+    let sweep = angle_from_bulge(v1.bulge).abs();
+    let mut delta = if v1.bulge_is_neg() {
+        normalize_radians(start_angle - point_angle)
+    } else {
+        normalize_radians(point_angle - start_angle)
+    };
+
+    if delta > sweep {
+        // outside of the arc sweep, use the closer end
+        delta = if delta - sweep < T::tau() - delta {
+            sweep
+        } else {
+            T::zero()
+        };
+    }
+
+    arc_radius * delta
+}
+
 /// Find the midpoint for the polyline segment defined by `v1` to `v2`.
 ///
 /// # Examples
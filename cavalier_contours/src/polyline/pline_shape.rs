@@ -0,0 +1,832 @@
+use super::{
+    internal::{
+        pline_boolean::{batch_union, shape_boolean, try_shape_boolean},
+        pline_clip::clip_open,
+        pline_medial_axis::shape_medial_axis,
+        pline_nesting::{direct_containers, opposite_sign_parents},
+        pline_shape_offset::{shape_iterative_offset, shape_parallel_offset},
+        pline_shared_edges::dissolve_shared_edges,
+    },
+    BooleanOp, BooleanResult, BooleanResultPline, MedialAxis, MedialAxisOptions, OffsetLoopTree,
+    PlineClipOptions, PlineOpError, PlineSharedEdgesOptions, Polyline, ShapeBooleanOptions,
+    ShapeHierarchy, ShapeIterativeOffsetOptions, ShapeOffsetOptions,
+};
+use crate::core::{math::Vector2, traits::Real};
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder, AABB};
+
+/// Polyline with an associated spatial index of its segments.
+///
+/// The spatial index is retained to avoid rebuilding it when the polyline is used in multiple
+/// operations (e.g. when offsetting a [Shape] multiple times).
+#[derive(Debug, Clone)]
+pub struct IndexedPolyline<T>
+where
+    T: Real,
+{
+    /// The polyline.
+    pub polyline: Polyline<T>,
+    /// Spatial index of the polyline segments (created with
+    /// [Polyline::create_approx_aabb_index]).
+    pub spatial_index: StaticAABB2DIndex<T>,
+}
+
+impl<T> IndexedPolyline<T>
+where
+    T: Real,
+{
+    /// Create a new [IndexedPolyline] by constructing the spatial index for the `polyline` given.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `polyline` has less than 2 vertexes.
+    pub fn new(polyline: Polyline<T>) -> Self {
+        let spatial_index = polyline
+            .create_approx_aabb_index()
+            .expect("polyline must have at least 2 vertexes to create spatial index");
+        Self {
+            polyline,
+            spatial_index,
+        }
+    }
+}
+
+/// Shape represented by a set of closed polylines, counter clockwise polylines represent positive
+/// space and clockwise polylines represent negative space (islands/holes).
+#[derive(Debug, Clone)]
+pub struct Shape<T>
+where
+    T: Real,
+{
+    /// Counter clockwise polylines (positive space).
+    pub ccw_plines: Vec<IndexedPolyline<T>>,
+    /// Clockwise polylines (negative space).
+    pub cw_plines: Vec<IndexedPolyline<T>>,
+    /// Spatial index of all the polyline extents, counter clockwise polylines are indexed first
+    /// followed by the clockwise polylines (e.g. index `ccw_plines.len()` refers to
+    /// `cw_plines[0]`). `None` if the shape is empty.
+    pub plines_index: Option<StaticAABB2DIndex<T>>,
+}
+
+impl<T> Default for Shape<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> From<BooleanResult<T>> for Shape<T>
+where
+    T: Real,
+{
+    fn from(result: BooleanResult<T>) -> Self {
+        Self::from_boolean_result(result)
+    }
+}
+
+impl<T> BooleanResult<T>
+where
+    T: Real,
+{
+    /// Compute the containment nesting of the positive and negative polylines.
+    ///
+    /// Same as [Shape::hierarchy] for the shape created with [Shape::from_boolean_result], the
+    /// positive polylines are the counter clockwise polylines (`ccw_parents` holds the index of
+    /// the negative polyline directly enclosing each island) and the negative polylines are the
+    /// clockwise polylines (`cw_parents` holds the index of the positive polyline directly
+    /// enclosing each hole).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let circle = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    /// let result = rectangle.boolean(&circle, BooleanOp::Not);
+    /// assert_eq!(result.pos_plines.len(), 1);
+    /// assert_eq!(result.neg_plines.len(), 1);
+    /// let hierarchy = result.hierarchy();
+    /// assert_eq!(hierarchy.cw_parents, vec![Some(0)]);
+    /// assert_eq!(hierarchy.ccw_parents, vec![None]);
+    /// ```
+    pub fn hierarchy(&self) -> ShapeHierarchy {
+        let plines: Vec<&Polyline<T>> = self
+            .pos_plines
+            .iter()
+            .chain(self.neg_plines.iter())
+            .map(|r| &r.pline)
+            .collect();
+        let extents: Vec<AABB<T>> = plines.iter().map(|p| p.extents().unwrap()).collect();
+        let (ccw_parents, cw_parents) =
+            opposite_sign_parents(&plines, &extents, self.pos_plines.len());
+
+        ShapeHierarchy {
+            cw_parents,
+            ccw_parents,
+        }
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Create an empty shape with no polylines.
+    pub fn empty() -> Self {
+        Self {
+            ccw_plines: Vec::new(),
+            cw_plines: Vec::new(),
+            plines_index: None,
+        }
+    }
+
+    /// Create a shape from a set of closed polylines, the polylines are separated by their
+    /// orientation (counter clockwise or clockwise).
+    ///
+    /// Open polylines and polylines with less than 2 vertexes are ignored.
+    pub fn from_plines<I>(plines: I) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        let mut ccw_plines = Vec::new();
+        let mut cw_plines = Vec::new();
+        for pline in plines {
+            if !pline.is_closed() || pline.len() < 2 {
+                continue;
+            }
+
+            if pline.area() < T::zero() {
+                cw_plines.push(IndexedPolyline::new(pline));
+            } else {
+                ccw_plines.push(IndexedPolyline::new(pline));
+            }
+        }
+
+        Self::from_indexed_plines(ccw_plines, cw_plines)
+    }
+
+    /// Create a shape from unordered closed polylines ignoring their orientation.
+    ///
+    /// Polylines are nested by containment (using winding numbers, accelerated with a spatial
+    /// index of the polyline extents): polylines not contained by any other polyline and polylines
+    /// inside holes are made counter clockwise, polylines directly inside counter clockwise
+    /// polylines are made clockwise (holes). The polylines must not intersect each other.
+    ///
+    /// Open polylines and polylines with less than 2 vertexes are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // same orientation as the outer polyline
+    /// let hole = pline_closed![(2.0, 2.0, 0.0), (8.0, 2.0, 0.0), (8.0, 8.0, 0.0), (2.0, 8.0, 0.0)];
+    /// let shape = Shape::from_unoriented_plines(vec![hole, outer]);
+    /// assert_eq!(shape.ccw_plines.len(), 1);
+    /// assert_eq!(shape.cw_plines.len(), 1);
+    /// assert_eq!(shape.hierarchy().cw_parents, vec![Some(0)]);
+    /// assert!(shape.area().fuzzy_eq(100.0 - 36.0));
+    /// assert!(shape.contains_point(Vector2::new(1.0, 1.0)));
+    /// assert!(!shape.contains_point(Vector2::new(5.0, 5.0)));
+    /// ```
+    pub fn from_unoriented_plines<I>(plines: I) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        let plines: Vec<Polyline<T>> = plines
+            .into_iter()
+            .filter(|p| p.is_closed() && p.len() > 1)
+            .collect();
+
+        let extents: Vec<AABB<T>> = plines.iter().map(|p| p.extents().unwrap()).collect();
+        let containers = direct_containers(&plines.iter().collect::<Vec<_>>(), &extents);
+
+        // nesting depth, even depth is positive space and odd depth is a hole
+        let mut depths: Vec<Option<usize>> = vec![None; plines.len()];
+        for i in 0..plines.len() {
+            let mut chain = Vec::new();
+            let mut current = Some(i);
+            let mut base = 0;
+            while let Some(c) = current {
+                if let Some(d) = depths[c] {
+                    base = d + 1;
+                    break;
+                }
+                chain.push(c);
+                current = containers[c];
+            }
+
+            for (k, &c) in chain.iter().rev().enumerate() {
+                depths[c] = Some(base + k);
+            }
+        }
+
+        let mut ccw_plines = Vec::new();
+        let mut cw_plines = Vec::new();
+        for (pline, depth) in plines.into_iter().zip(depths) {
+            let is_hole = depth.unwrap() % 2 == 1;
+            let mut pline = pline;
+            if is_hole == (pline.area() > T::zero()) {
+                pline.invert_direction();
+            }
+
+            if is_hole {
+                cw_plines.push(IndexedPolyline::new(pline));
+            } else {
+                ccw_plines.push(IndexedPolyline::new(pline));
+            }
+        }
+
+        Self::from_indexed_plines(ccw_plines, cw_plines)
+    }
+
+    /// Create a shape from the result of a boolean operation, positive polylines are made counter
+    /// clockwise and negative polylines are made clockwise.
+    pub fn from_boolean_result(result: BooleanResult<T>) -> Self {
+        let oriented = |plines: Vec<BooleanResultPline<T>>, ccw: bool| {
+            plines
+                .into_iter()
+                .map(move |r| {
+                    let mut pline = r.pline;
+                    if (pline.area() > T::zero()) != ccw {
+                        pline.invert_direction();
+                    }
+                    IndexedPolyline::new(pline)
+                })
+                .collect()
+        };
+
+        Self::from_indexed_plines(
+            oriented(result.pos_plines, true),
+            oriented(result.neg_plines, false),
+        )
+    }
+
+    /// Create a shape from the union of all the closed polylines given using default options.
+    ///
+    /// See [Shape::batch_union_opt] for more information.
+    pub fn batch_union<I>(plines: I) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        Self::batch_union_opt(plines, &Default::default())
+    }
+
+    /// Create a shape from the union of all the closed polylines given with options provided.
+    ///
+    /// Only polylines with overlapping extents are unioned together (each group of overlapping
+    /// polylines is unioned using divide and conquer), this is much faster than repeatedly
+    /// performing boolean operations between pairs of polylines when there are many polylines.
+    /// Polylines that do not overlap any other polyline are moved into the shape as is. The
+    /// orientation of the polylines given is ignored (each polyline is treated as a filled region).
+    ///
+    /// Open polylines and polylines with less than 2 vertexes are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circles = (0..10).map(|i| {
+    ///     let x = 3.0 * (i as f64);
+    ///     // circles overlap in groups of 2
+    ///     let x = if i % 2 == 0 { x } else { x - 1.5 };
+    ///     let circle: Polyline<f64> = pline_closed![(x - 1.0, 0.0, 1.0), (x + 1.0, 0.0, 1.0)];
+    ///     circle
+    /// });
+    /// let shape = Shape::batch_union(circles);
+    /// assert_eq!(shape.ccw_plines.len(), 5);
+    /// assert!(shape.cw_plines.is_empty());
+    /// ```
+    pub fn batch_union_opt<I>(plines: I, options: &ShapeBooleanOptions<T>) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        batch_union(plines, options)
+    }
+
+    /// Create a shape by dissolving the shared edges between the closed polylines given using
+    /// default options.
+    ///
+    /// See [Shape::from_dissolved_plines_opt] for more information.
+    pub fn from_dissolved_plines<I>(plines: I) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        Self::from_dissolved_plines_opt(plines, &Default::default())
+    }
+
+    /// Create a shape by dissolving the shared edges between the closed polylines given with
+    /// options provided.
+    ///
+    /// Regions that are adjacent along shared edges (see [SharedEdge::find_opt]) are
+    /// merged into single loops, regions that do not share any edges are kept as is. The polylines
+    /// given are expected to not overlap each other other than along shared edges. The
+    /// orientation of the polylines given is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let tiles = (0..3).map(|i| {
+    ///     let x = 10.0 * (i as f64);
+    ///     let tile: Polyline<f64> = pline_closed![(x, 0.0, 0.0), (x + 10.0, 0.0, 0.0), (x + 10.0, 10.0, 0.0), (x, 10.0, 0.0)];
+    ///     tile
+    /// });
+    /// let shape = Shape::from_dissolved_plines(tiles);
+    /// assert_eq!(shape.ccw_plines.len(), 1);
+    /// assert!(shape.area().fuzzy_eq(300.0));
+    /// ```
+    pub fn from_dissolved_plines_opt<I>(plines: I, options: &PlineSharedEdgesOptions<T>) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        dissolve_shared_edges(plines.into_iter().collect(), options)
+    }
+
+    /// Create a shape from counter clockwise and clockwise indexed polylines, the caller must
+    /// ensure the polylines are closed and have the orientation matching the set they are in.
+    pub fn from_indexed_plines(
+        ccw_plines: Vec<IndexedPolyline<T>>,
+        cw_plines: Vec<IndexedPolyline<T>>,
+    ) -> Self {
+        let count = ccw_plines.len() + cw_plines.len();
+        let plines_index = if count == 0 {
+            None
+        } else {
+            let mut builder = StaticAABB2DIndexBuilder::new(count);
+            for ipline in ccw_plines.iter().chain(cw_plines.iter()) {
+                let i = &ipline.spatial_index;
+                builder.add(i.min_x(), i.min_y(), i.max_x(), i.max_y());
+            }
+            builder.build().ok()
+        };
+
+        Self {
+            ccw_plines,
+            cw_plines,
+            plines_index,
+        }
+    }
+
+    /// Returns true if the shape has no polylines.
+    pub fn is_empty(&self) -> bool {
+        self.ccw_plines.is_empty() && self.cw_plines.is_empty()
+    }
+
+    /// Get the indexed polyline at position `index` in the [Shape::plines_index] (counter
+    /// clockwise polylines first followed by clockwise polylines).
+    #[inline]
+    pub fn get_indexed_pline(&self, index: usize) -> &IndexedPolyline<T> {
+        let ccw_count = self.ccw_plines.len();
+        if index < ccw_count {
+            &self.ccw_plines[index]
+        } else {
+            &self.cw_plines[index - ccw_count]
+        }
+    }
+
+    /// Iterate through all the polylines in the shape (counter clockwise polylines first followed
+    /// by clockwise polylines).
+    pub fn iter_plines(&self) -> impl Iterator<Item = &Polyline<T>> + '_ {
+        self.ccw_plines
+            .iter()
+            .chain(self.cw_plines.iter())
+            .map(|ipline| &ipline.polyline)
+    }
+
+    /// Consume the shape returning all of its polylines (counter clockwise polylines first
+    /// followed by clockwise polylines).
+    pub fn into_plines(self) -> Vec<Polyline<T>> {
+        self.ccw_plines
+            .into_iter()
+            .chain(self.cw_plines)
+            .map(|ipline| ipline.polyline)
+            .collect()
+    }
+
+    /// Compute the containment nesting of the shape polylines (which clockwise polylines are
+    /// holes of which counter clockwise polylines, and which counter clockwise polylines are
+    /// islands inside which holes), see [ShapeHierarchy].
+    ///
+    /// The polylines must not intersect each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let circle = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    /// let shape = Shape::from(rectangle.boolean(&circle, BooleanOp::Not));
+    /// let hierarchy = shape.hierarchy();
+    /// assert_eq!(hierarchy.cw_parents, vec![Some(0)]);
+    /// assert_eq!(hierarchy.ccw_parents, vec![None]);
+    /// ```
+    pub fn hierarchy(&self) -> ShapeHierarchy {
+        let plines: Vec<&Polyline<T>> = self.iter_plines().collect();
+        let extents: Vec<AABB<T>> = self
+            .ccw_plines
+            .iter()
+            .chain(self.cw_plines.iter())
+            .map(|ipline| {
+                let i = &ipline.spatial_index;
+                AABB::new(i.min_x(), i.min_y(), i.max_x(), i.max_y())
+            })
+            .collect();
+        let (ccw_parents, cw_parents) =
+            opposite_sign_parents(&plines, &extents, self.ccw_plines.len());
+
+        ShapeHierarchy {
+            cw_parents,
+            ccw_parents,
+        }
+    }
+
+    /// Total area of the shape (counter clockwise polyline areas minus clockwise polyline areas).
+    pub fn area(&self) -> T {
+        self.iter_plines()
+            .fold(T::zero(), |acc, pline| acc + pline.area())
+    }
+
+    /// Bounding box of all the polylines in the shape, `None` if the shape is empty.
+    pub fn extents(&self) -> Option<AABB<T>> {
+        let index = self.plines_index.as_ref()?;
+        Some(AABB::new(
+            index.min_x(),
+            index.min_y(),
+            index.max_x(),
+            index.max_y(),
+        ))
+    }
+
+    /// Sum of the winding numbers of all the polylines in the shape around `point`, 0 if the point
+    /// is outside the shape.
+    pub fn winding_number(&self, point: Vector2<T>) -> i32 {
+        let index = match self.plines_index.as_ref() {
+            Some(i) => i,
+            None => return 0,
+        };
+
+        let mut query_stack = Vec::new();
+        index
+            .query(point.x, point.y, point.x, point.y)
+            .into_iter()
+            .map(|i| {
+                let ipline = self.get_indexed_pline(i);
+                ipline.polyline.winding_number_indexed(
+                    point,
+                    &ipline.spatial_index,
+                    &mut query_stack,
+                )
+            })
+            .sum()
+    }
+
+    /// Returns true if `point` is inside the shape (inside a counter clockwise polyline and not
+    /// inside any of its holes). Result is undefined for points that lie on a polyline.
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    /// Perform a boolean `operation` between this shape and another using default options.
+    ///
+    /// See [Shape::boolean_opt] for more information.
+    pub fn boolean(&self, other: &Shape<T>, operation: BooleanOp) -> Shape<T> {
+        self.boolean_opt(other, operation, &Default::default())
+    }
+
+    /// Perform a boolean `operation` between this shape and another with options given.
+    ///
+    /// Each shape is treated as a single region defined by all of its polylines (counter clockwise
+    /// polylines minus clockwise polylines), the polylines within each shape must not intersect
+    /// each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(4.0, 4.0, 0.0), (4.0, 6.0, 0.0), (6.0, 6.0, 0.0), (6.0, 4.0, 0.0)];
+    /// let frame = Shape::from_plines(vec![square, hole]);
+    /// let plug = Shape::from_plines(vec![pline_closed![
+    ///     (3.0, 3.0, 0.0),
+    ///     (7.0, 3.0, 0.0),
+    ///     (7.0, 7.0, 0.0),
+    ///     (3.0, 7.0, 0.0)
+    /// ]]);
+    /// // subtracting makes the hole bigger
+    /// let result = frame.boolean(&plug, BooleanOp::Not);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert_eq!(result.cw_plines.len(), 1);
+    /// assert!(result.area().fuzzy_eq(100.0 - 16.0));
+    /// // combining fills the hole
+    /// let result = frame.boolean(&plug, BooleanOp::Or);
+    /// assert!(result.cw_plines.is_empty());
+    /// assert!(result.area().fuzzy_eq(100.0));
+    /// ```
+    pub fn boolean_opt(
+        &self,
+        other: &Shape<T>,
+        operation: BooleanOp,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Shape<T> {
+        shape_boolean(self, other, operation, options)
+    }
+
+    /// Perform a boolean `operation` between this shape and another using default options,
+    /// returning an error for invalid input.
+    ///
+    /// See [Shape::try_boolean_opt] for more information.
+    pub fn try_boolean(
+        &self,
+        other: &Shape<T>,
+        operation: BooleanOp,
+    ) -> Result<Shape<T>, PlineOpError> {
+        self.try_boolean_opt(other, operation, &Default::default())
+    }
+
+    /// Perform a boolean `operation` between this shape and another with options given, returning
+    /// an error for invalid input.
+    ///
+    /// Same as [Shape::boolean_opt] except all polylines are validated (closed, at least 2
+    /// vertexes, finite values, and no repeat position vertexes) and an error is returned if
+    /// slices failed to stitch together into closed polylines. See [PlineOpError] for all errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = Shape::from_plines(vec![pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0)
+    /// ]]);
+    /// let shifted = Shape::from_plines(vec![pline_closed![
+    ///     (5.0, 0.0, 0.0),
+    ///     (15.0, 0.0, 0.0),
+    ///     (15.0, 10.0, 0.0),
+    ///     (5.0, 10.0, 0.0)
+    /// ]]);
+    /// let result = square.try_boolean(&shifted, BooleanOp::Xor).unwrap();
+    /// assert_eq!(result.ccw_plines.len(), 2);
+    /// assert!(result.area().fuzzy_eq(100.0));
+    /// ```
+    pub fn try_boolean_opt(
+        &self,
+        other: &Shape<T>,
+        operation: BooleanOp,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Result<Shape<T>, PlineOpError> {
+        try_shape_boolean(self, other, operation, options)
+    }
+
+    /// Clip the polyline `pline` against the region of this shape using default options.
+    ///
+    /// See [Shape::clip_open_opt] for more information.
+    pub fn clip_open(&self, pline: &Polyline<T>, keep_inside: bool) -> Vec<Polyline<T>> {
+        self.clip_open_opt(pline, keep_inside, &Default::default())
+    }
+
+    /// Clip the polyline `pline` against the region of this shape (counter clockwise polylines
+    /// minus clockwise polylines) with options given, returning the pieces inside the shape if
+    /// `keep_inside` is true or the pieces outside the shape otherwise.
+    ///
+    /// See [Polyline::clip_open_opt] for more information.
+    pub fn clip_open_opt(
+        &self,
+        pline: &Polyline<T>,
+        keep_inside: bool,
+        options: &PlineClipOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        clip_open(pline, self, keep_inside, options)
+    }
+
+    /// Compute the parallel offset of the shape using default options.
+    ///
+    /// See [Shape::parallel_offset_opt] for more information.
+    pub fn parallel_offset(&self, offset: T) -> Shape<T> {
+        self.parallel_offset_opt(offset, &Default::default())
+    }
+
+    /// Compute the parallel offset of the shape with options given.
+    ///
+    /// A positive `offset` shrinks the positive space of the shape (counter clockwise polylines
+    /// are offset inward and clockwise islands are offset outward), a negative `offset` grows the
+    /// positive space of the shape. The offset polylines are clipped against each other and
+    /// stitched together so the resulting shape has no overlapping polylines.
+    ///
+    /// The resulting shape holds the spatial index of every polyline so it may be offset again
+    /// without rebuilding the spatial indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // clockwise circle island
+    /// let island = pline_closed![(12.0, 5.0, -1.0), (8.0, 5.0, -1.0)];
+    /// let shape = Shape::from_plines(vec![outer, island]);
+    /// let offset_shape = shape.parallel_offset(1.0);
+    /// assert_eq!(offset_shape.ccw_plines.len(), 1);
+    /// assert_eq!(offset_shape.cw_plines.len(), 1);
+    /// assert!(offset_shape.ccw_plines[0].polyline.area().fuzzy_eq(18.0 * 8.0));
+    /// // offset island grows by the offset distance
+    /// assert!(offset_shape.cw_plines[0].polyline.area().fuzzy_eq(-std::f64::consts::PI * 9.0));
+    /// ```
+    pub fn parallel_offset_opt(&self, offset: T, options: &ShapeOffsetOptions<T>) -> Shape<T> {
+        shape_parallel_offset(self, offset, options)
+    }
+
+    /// Repeatedly offset the shape by `step` using default options.
+    ///
+    /// See [Shape::iterative_offset_opt] for more information.
+    pub fn iterative_offset(&self, step: T) -> OffsetLoopTree<T> {
+        self.iterative_offset_opt(step, &Default::default())
+    }
+
+    /// Repeatedly offset the shape by `step` with options given, returning every offset loop in
+    /// a tree that links each loop to the loop it was offset from (e.g. for linking concentric
+    /// pocketing toolpaths).
+    ///
+    /// Each pass offsets the shape created by the previous pass (see [Shape::parallel_offset_opt])
+    /// reusing its spatial indexes. Passes stop when the offset shape is empty or
+    /// [ShapeIterativeOffsetOptions::max_pass_count] is reached. If no maximum pass count is given
+    /// then passes also stop when the offset shape has no shrinking loops (the loops that are left
+    /// grow on every pass, e.g. a shape of only clockwise islands with a positive `step`), that
+    /// pass is not included. The loops of the input shape are the roots of the tree (pass 0).
+    ///
+    /// Loops that shrink with each pass (counter clockwise loops for a positive `step`) are linked
+    /// to the previous loop that contains them, so when a region splits each of the split loops
+    /// has the same parent. Loops that grow with each pass (clockwise islands for a positive
+    /// `step`) are linked to the closest previous loop of the same orientation, when islands merge
+    /// the parent is one of the merged islands.
+    ///
+    /// No passes are performed for a zero `step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rect = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// let shape = Shape::from_plines(vec![rect]);
+    /// let tree = shape.iterative_offset(1.0);
+    /// // input rectangle and a rectangle for offsets of 1 and 2 (offset of 3 is empty)
+    /// assert_eq!(tree.pass_count, 3);
+    /// assert_eq!(tree.nodes.len(), 3);
+    /// assert_eq!(tree.nodes[1].parent, Some(0));
+    /// assert_eq!(tree.nodes[2].parent, Some(1));
+    /// ```
+    pub fn iterative_offset_opt(
+        &self,
+        step: T,
+        options: &ShapeIterativeOffsetOptions<T>,
+    ) -> OffsetLoopTree<T> {
+        shape_iterative_offset(self, step, options)
+    }
+
+    /// Compute the morphological opening of the shape using default options.
+    ///
+    /// See [Shape::morph_open_opt] for more information.
+    pub fn morph_open(&self, radius: T) -> Shape<T> {
+        self.morph_open_opt(radius, &Default::default())
+    }
+
+    /// Compute the morphological opening of the shape with options given.
+    ///
+    /// The shape is offset inward by `radius` and then offset back outward by `radius`, this
+    /// rounds off convex corners to `radius` and removes parts of the shape narrower than two
+    /// times `radius` (parts joined by a narrow neck are split apart). The second offset pass
+    /// reuses the spatial indexes of the first pass result.
+    ///
+    /// If `radius` is not positive the shape is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rect = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// let shape = Shape::from_plines(vec![rect]);
+    /// let opened = shape.morph_open(1.0);
+    /// assert_eq!(opened.ccw_plines.len(), 1);
+    /// // every corner is rounded with a radius of 1
+    /// let expected_area = 40.0 - 4.0 + std::f64::consts::PI;
+    /// assert!(opened.ccw_plines[0].polyline.area().fuzzy_eq(expected_area));
+    /// ```
+    pub fn morph_open_opt(&self, radius: T, options: &ShapeOffsetOptions<T>) -> Shape<T> {
+        if radius <= T::zero() {
+            return self.clone();
+        }
+
+        self.parallel_offset_opt(radius, options)
+            .parallel_offset_opt(-radius, options)
+    }
+
+    /// Compute the morphological closing of the shape using default options.
+    ///
+    /// See [Shape::morph_close_opt] for more information.
+    pub fn morph_close(&self, radius: T) -> Shape<T> {
+        self.morph_close_opt(radius, &Default::default())
+    }
+
+    /// Compute the morphological closing of the shape with options given.
+    ///
+    /// The shape is offset outward by `radius` and then offset back inward by `radius`, this
+    /// rounds off concave corners to `radius` and fills gaps and holes narrower than two times
+    /// `radius` (nearby parts of the shape are merged together). The second offset pass reuses the
+    /// spatial indexes of the first pass result.
+    ///
+    /// If `radius` is not positive the shape is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let l_shape = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (6.0, 0.0, 0.0),
+    ///     (6.0, 2.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (2.0, 6.0, 0.0),
+    ///     (0.0, 6.0, 0.0),
+    /// ];
+    /// let shape = Shape::from_plines(vec![l_shape]);
+    /// let closed = shape.morph_close(1.0);
+    /// assert_eq!(closed.ccw_plines.len(), 1);
+    /// // inside corner is filled with a radius of 1
+    /// let expected_area = 20.0 + 1.0 - std::f64::consts::PI / 4.0;
+    /// assert!(closed.ccw_plines[0].polyline.area().fuzzy_eq(expected_area));
+    /// ```
+    pub fn morph_close_opt(&self, radius: T, options: &ShapeOffsetOptions<T>) -> Shape<T> {
+        if radius <= T::zero() {
+            return self.clone();
+        }
+
+        self.parallel_offset_opt(-radius, options)
+            .parallel_offset_opt(radius, options)
+    }
+
+    /// Compute the medial axis of the shape using default options.
+    ///
+    /// See [Shape::medial_axis_opt] for more information.
+    pub fn medial_axis(&self) -> MedialAxis<T> {
+        self.medial_axis_opt(&Default::default())
+    }
+
+    /// Compute the medial axis of the shape with options given.
+    ///
+    /// The result is an approximation built from points sampled along the boundary, its accuracy
+    /// is controlled by [MedialAxisOptions::sample_spacing]. Axis positions and radii are within
+    /// about half the sample spacing of the exact axis, along curved boundaries the error is
+    /// closer to `spacing^2 / (8 * r)` where `r` is the inscribed circle radius (e.g. an error of
+    /// about 0.01 for a spacing of 0.5 and a radius of 2).
+    ///
+    /// The medial axis is approximated from the Voronoi diagram of points sampled along all the
+    /// polylines of the shape (at most [MedialAxisOptions::sample_spacing] apart), only the parts
+    /// of the diagram inside the shape that separate non adjacent boundary samples are kept.
+    /// Vertexes along the branches are then moved onto the bisector of their two closest boundary
+    /// segments (found using the shape spatial indexes) and the inscribed circle radius is
+    /// computed from the closest boundary distance. Branch ends approach convex corners within
+    /// the sample spacing.
+    ///
+    /// Branches around holes form loops, a circular shape results in a single node with no
+    /// branches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(4.0, 4.0, 0.0), (4.0, 6.0, 0.0), (6.0, 6.0, 0.0), (6.0, 4.0, 0.0)];
+    /// let shape = Shape::from_plines(vec![outer, hole]);
+    /// let axis = shape.medial_axis();
+    /// // loop around the hole joined to a branch going to each outer corner
+    /// assert_eq!(axis.branches.len(), 8);
+    /// assert_eq!(axis.nodes.iter().filter(|n| n.branches.len() == 3).count(), 4);
+    /// ```
+    pub fn medial_axis_opt(&self, options: &MedialAxisOptions<T>) -> MedialAxis<T> {
+        shape_medial_axis(self, options)
+    }
+}
@@ -1,8 +1,12 @@
 //! Supporting public types used in [Polyline] methods.
 
 use super::{
-    internal::pline_intersects::OverlappingSlice, seg_arc_radius_and_center, seg_closest_point,
-    seg_length, seg_split_at_point, PlineVertex, Polyline,
+    internal::{
+        pline_arrangement::find_arrangement_faces, pline_intersects::OverlappingSlice,
+        pline_shared_edges::find_shared_edges,
+    },
+    seg_arc_radius_and_center, seg_closest_point, seg_length, seg_length_to_point,
+    seg_point_at_length, seg_split_at_point, seg_tangent_vector, PlineVertex, Polyline,
 };
 use crate::core::{
     math::{angle, angle_from_bulge, point_on_circle, Vector2},
@@ -39,6 +43,419 @@ where
     pub distance: T,
 }
 
+/// Result from calling [Polyline::min_distance].
+#[derive(Debug, Copy, Clone)]
+pub struct MinDistanceResult<T>
+where
+    T: Real,
+{
+    /// The start vertex index of the closest segment on the first polyline.
+    pub seg_start_index1: usize,
+    /// The closest point on the first polyline.
+    pub point1: Vector2<T>,
+    /// The start vertex index of the closest segment on the second polyline.
+    pub seg_start_index2: usize,
+    /// The closest point on the second polyline.
+    pub point2: Vector2<T>,
+    /// The distance between the points.
+    pub distance: T,
+}
+
+/// Struct to hold options parameters when finding the minimum distance between polylines.
+#[derive(Debug)]
+pub struct PlineMinDistanceOptions<'a, T>
+where
+    T: Real,
+{
+    /// Spatial index for `self` or first polyline argument (bounding boxes must contain the
+    /// segments, e.g. created with [Polyline::create_aabb_index]).
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+}
+
+impl<'a, T> PlineMinDistanceOptions<'a, T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+        }
+    }
+}
+
+impl<'a, T> Default for PlineMinDistanceOptions<'a, T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result from calling [Polyline::hausdorff_distance], [Polyline::directed_hausdorff_distance], or
+/// [Polyline::frechet_distance].
+#[derive(Debug, Copy, Clone)]
+pub struct ShapeDistanceResult<T>
+where
+    T: Real,
+{
+    /// The start vertex index of the segment the witness point lies on for the first polyline.
+    pub seg_start_index1: usize,
+    /// The witness point on the first polyline.
+    pub point1: Vector2<T>,
+    /// The start vertex index of the segment the witness point lies on for the second polyline.
+    pub seg_start_index2: usize,
+    /// The witness point on the second polyline.
+    pub point2: Vector2<T>,
+    /// The distance achieved between the witness points.
+    pub distance: T,
+}
+
+/// Struct to hold options parameters when computing the Hausdorff or Fréchet distance between
+/// polylines.
+#[derive(Debug, Clone)]
+pub struct PlineShapeDistanceOptions<T>
+where
+    T: Real,
+{
+    /// Maximum distance between points sampled along the polylines, smaller values give a more
+    /// accurate distance at the cost of performance. If `None` then 1/1024 of the largest extent
+    /// dimension of the polylines is used.
+    pub sample_spacing: Option<T>,
+}
+
+impl<T> PlineShapeDistanceOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            sample_spacing: None,
+        }
+    }
+}
+
+impl<T> Default for PlineShapeDistanceOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Table of cumulative path lengths at each vertex of a polyline, used to parameterize the
+/// polyline by path length. Queries by path length use binary search so this should be used when
+/// making repeated queries on the same polyline (see [Polyline::length_table]).
+///
+/// Path lengths given to the query methods are clamped to the range `[0, total_length]`. For
+/// closed polylines the path includes the closing segment back to the first vertex.
+#[derive(Debug, Clone)]
+pub struct PlineLengthTable<'a, T>
+where
+    T: Real,
+{
+    pline: &'a Polyline<T>,
+    /// Path length from the start of the polyline to the start of each segment followed by the
+    /// total path length.
+    cumulative_lengths: Vec<T>,
+}
+
+impl<'a, T> PlineLengthTable<'a, T>
+where
+    T: Real,
+{
+    pub fn new(pline: &'a Polyline<T>) -> Self {
+        let mut cumulative_lengths = Vec::with_capacity(pline.len() + 1);
+        let mut total = T::zero();
+        cumulative_lengths.push(total);
+        for (v1, v2) in pline.iter_segments() {
+            total = total + seg_length(v1, v2);
+            cumulative_lengths.push(total);
+        }
+
+        Self {
+            pline,
+            cumulative_lengths,
+        }
+    }
+
+    /// The polyline the table was created for.
+    #[inline]
+    pub fn pline(&self) -> &'a Polyline<T> {
+        self.pline
+    }
+
+    /// Total path length of the polyline.
+    #[inline]
+    pub fn total_length(&self) -> T {
+        *self.cumulative_lengths.last().unwrap()
+    }
+
+    /// Path length from the start of the polyline to the vertex at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn length_at_vertex(&self, index: usize) -> T {
+        assert!(index < self.pline.len(), "index out of bounds");
+        self.cumulative_lengths[index]
+    }
+
+    /// Find the segment at the path `length` along the polyline, returns the segment start index
+    /// and the path length remaining along that segment. Positions at a vertex resolve to the
+    /// segment starting at the vertex (except at the end of the path). Returns `None` if the
+    /// polyline has less than 2 vertexes.
+    pub fn seg_at_length(&self, length: T) -> Option<(usize, T)> {
+        let seg_count = self.cumulative_lengths.len() - 1;
+        if seg_count == 0 {
+            return None;
+        }
+
+        let length = num_traits::real::Real::min(
+            num_traits::real::Real::max(length, T::zero()),
+            self.total_length(),
+        );
+
+        let lengths = &self.cumulative_lengths;
+        let mut index = lengths.partition_point(|&l| l <= length) - 1;
+        if index >= seg_count {
+            // end of the path, use the last segment with length ending at the end
+            index = lengths
+                .partition_point(|&l| l < length)
+                .saturating_sub(1)
+                .min(seg_count - 1);
+        }
+
+        Some((index, length - lengths[index]))
+    }
+
+    /// Find the point at the path `length` along the polyline. Returns `None` if the polyline has
+    /// less than 2 vertexes.
+    pub fn point_at_length(&self, length: T) -> Option<Vector2<T>> {
+        let (index, seg_offset) = self.seg_at_length(length)?;
+        let v1 = self.pline[index];
+        let v2 = self.pline[self.pline.next_wrapping_index(index)];
+        Some(seg_point_at_length(v1, v2, seg_offset))
+    }
+
+    /// Find the unit tangent direction vector at the path `length` along the polyline (pointing in
+    /// the direction of the polyline). At a vertex the tangent of the segment starting at the
+    /// vertex is returned (except at the end of the path). Returns `None` if the polyline has less
+    /// than 2 vertexes or the path length is zero.
+    pub fn tangent_at_length(&self, length: T) -> Option<Vector2<T>> {
+        self.station_at_length(length).map(|s| s.tangent)
+    }
+
+    /// Find the station (position and unit tangent) at the path `length` along the polyline.
+    /// Returns `None` if the polyline has less than 2 vertexes or the path length is zero.
+    pub fn station_at_length(&self, length: T) -> Option<PlineStation<T>> {
+        let (index, seg_offset) = self.seg_at_length(length)?;
+        let v1 = self.pline[index];
+        let v2 = self.pline[self.pline.next_wrapping_index(index)];
+        if v1.pos() == v2.pos() {
+            return None;
+        }
+
+        let point = seg_point_at_length(v1, v2, seg_offset);
+        Some(PlineStation {
+            seg_start_index: index,
+            length: self.cumulative_lengths[index] + seg_offset,
+            point,
+            tangent: seg_tangent_vector(v1, v2, point).normalize(),
+        })
+    }
+
+    /// Find the path length from the start of the polyline to `point` on the segment starting at
+    /// `seg_start_index` (see [seg_length_to_point]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seg_start_index` is not the start of a segment.
+    pub fn length_at_point(&self, seg_start_index: usize, point: Vector2<T>) -> T {
+        assert!(
+            seg_start_index + 1 < self.cumulative_lengths.len(),
+            "seg_start_index out of bounds"
+        );
+        let v1 = self.pline[seg_start_index];
+        let v2 = self.pline[self.pline.next_wrapping_index(seg_start_index)];
+        self.cumulative_lengths[seg_start_index] + seg_length_to_point(v1, v2, point)
+    }
+}
+
+/// Position along a polyline path with its tangent direction, e.g. from
+/// [Polyline::resample_by_distance] or [Polyline::divide].
+#[derive(Debug, Copy, Clone)]
+pub struct PlineStation<T>
+where
+    T: Real,
+{
+    /// The start vertex index of the segment the station lies on.
+    pub seg_start_index: usize,
+    /// Path length from the start of the polyline to the station.
+    pub length: T,
+    /// Position of the station.
+    pub point: Vector2<T>,
+    /// Unit tangent direction vector at the station (pointing in the direction of the polyline).
+    pub tangent: Vector2<T>,
+}
+
+/// Struct to hold options parameters when splitting a polyline or taking a sub polyline by path
+/// length or points (e.g. [Polyline::split_at_length_opt] and [Polyline::sub_polyline_opt]).
+#[derive(Debug)]
+pub struct PlineSplitOptions<T>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal, pieces shorter
+    /// than this are discarded.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineSplitOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineSplitOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned by the fallible polyline operations (e.g. [Polyline::try_parallel_offset_opt]
+/// and [Polyline::try_boolean_opt]).
+///
+/// `input` identifies the polyline the error is for, 0 for the polyline the method is called on
+/// and 1 for the other polyline argument of a boolean operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlineOpError {
+    /// Input polyline has less than 2 vertexes.
+    TooFewVertexes { input: usize },
+    /// Input polyline has a vertex at the same position as the vertex before it (fuzzy compared
+    /// using the position equal epsilon), `vertex_index` is the index of the repeat vertex. Use
+    /// [Polyline::remove_repeat_pos] to remove repeat position vertexes.
+    RepeatPositionVertexes { input: usize, vertex_index: usize },
+    /// Input polyline has a vertex with a NaN or infinite coordinate or bulge.
+    NonFiniteVertex { input: usize, vertex_index: usize },
+    /// Input polyline is open but the operation requires closed polylines.
+    OpenInput { input: usize },
+    /// Slices failed to stitch together into closed polylines, `count` is the number of dangling
+    /// (discarded or open) polylines.
+    DanglingSlices { count: usize },
+    /// Number of offset distances given does not match the number of polyline segments.
+    OffsetCountMismatch { expected: usize, actual: usize },
+    /// Offset distance for the segment at `segment_index` is not finite, is zero, or does not have
+    /// the same sign as the other offset distances.
+    InvalidOffset { segment_index: usize },
+}
+
+impl std::fmt::Display for PlineOpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlineOpError::TooFewVertexes { input } => {
+                write!(f, "input polyline {} has less than 2 vertexes", input)
+            }
+            PlineOpError::RepeatPositionVertexes {
+                input,
+                vertex_index,
+            } => write!(
+                f,
+                "input polyline {} has repeat position vertex at index {}",
+                input, vertex_index
+            ),
+            PlineOpError::NonFiniteVertex {
+                input,
+                vertex_index,
+            } => write!(
+                f,
+                "input polyline {} has NaN or infinite value at vertex index {}",
+                input, vertex_index
+            ),
+            PlineOpError::OpenInput { input } => {
+                write!(f, "input polyline {} is open but must be closed", input)
+            }
+            PlineOpError::DanglingSlices { count } => write!(
+                f,
+                "{} polyline(s) failed to stitch together into closed polylines",
+                count
+            ),
+            PlineOpError::OffsetCountMismatch { expected, actual } => write!(
+                f,
+                "expected {} offset distances (one per segment) but got {}",
+                expected, actual
+            ),
+            PlineOpError::InvalidOffset { segment_index } => write!(
+                f,
+                "offset distance for segment {} is not finite and non-zero with the same sign as \
+                 the other offsets",
+                segment_index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlineOpError {}
+
+/// Style used to join adjacent raw offset segments at convex corners when performing polyline
+/// offset.
+///
+/// Joins to/from collapsed arc segments and joins at a full reversal in direction (parallel
+/// segments pointing in opposite directions) are always rounded.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum OffsetJoinStyle<T>
+where
+    T: Real,
+{
+    /// Join using an arc centered at the original vertex (radius is equal to the offset distance).
+    #[default]
+    Round,
+    /// Join by extending the segments until they meet at a sharp corner. If the distance from the
+    /// original vertex to the corner divided by the offset distance is greater than `limit` then
+    /// a [OffsetJoinStyle::Bevel] join is used instead.
+    Miter {
+        /// Maximum ratio of miter length to offset distance.
+        limit: T,
+    },
+    /// Join using a single line segment between the ends of the segments.
+    Bevel,
+}
+
+/// Source of a parallel offset polyline segment (segment that starts at an offset polyline vertex).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OffsetSegSource {
+    /// Segment is part of the offset of the source polyline segment that starts at the vertex
+    /// index given.
+    Segment(usize),
+    /// Segment was generated to join offset segments at the source polyline vertex index given
+    /// (arc for round joins, lines for miter and bevel joins).
+    Join(usize),
+}
+
+/// Parallel offset polyline with the source of each of its vertexes, see
+/// [Polyline::parallel_offset_with_source_opt].
+#[derive(Debug, Clone)]
+pub struct OffsetPolylineWithSource<T>
+where
+    T: Real,
+{
+    /// The offset polyline.
+    pub polyline: Polyline<T>,
+    /// Source of the segment that starts at each vertex of the offset polyline (same length as the
+    /// polyline), the last vertex of an open polyline has the same source as the vertex before it.
+    pub sources: Vec<OffsetSegSource>,
+}
+
 /// Struct to hold options parameters when performing polyline offset.
 #[derive(Debug, Clone)]
 pub struct PlineOffsetOptions<'a, T>
@@ -64,43 +481,496 @@ where
     /// Fuzzy comparison epsilon used when testing distance of slices to original polyline for
     /// validity.
     pub offset_dist_eps: T,
+    /// Style used to join offset segments at convex corners.
+    pub join_style: OffsetJoinStyle<T>,
+}
+
+impl<'a, T> PlineOffsetOptions<'a, T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            handle_self_intersects: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+            offset_dist_eps: T::from(1e-4).unwrap(),
+            join_style: OffsetJoinStyle::Round,
+        }
+    }
+}
+
+impl<'a, T> Default for PlineOffsetOptions<'a, T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when performing shape offset.
+#[derive(Debug, Clone)]
+pub struct ShapeOffsetOptions<T>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used when testing distance of slices to original polylines for
+    /// validity.
+    pub offset_dist_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+    /// Style used to join offset segments at convex corners.
+    pub join_style: OffsetJoinStyle<T>,
+}
+
+impl<T> ShapeOffsetOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            offset_dist_eps: T::from(1e-4).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+            join_style: OffsetJoinStyle::Round,
+        }
+    }
+}
+
+impl<T> Default for ShapeOffsetOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when computing iterative offsets of a shape.
+#[derive(Debug, Clone)]
+pub struct ShapeIterativeOffsetOptions<T>
+where
+    T: Real,
+{
+    /// Options used for each shape offset pass.
+    pub offset_options: ShapeOffsetOptions<T>,
+    /// Maximum number of offset passes to perform, if `None` then offset passes continue until
+    /// the offset shape is empty or has no shrinking loops.
+    pub max_pass_count: Option<usize>,
+}
+
+impl<T> ShapeIterativeOffsetOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            offset_options: ShapeOffsetOptions::new(),
+            max_pass_count: None,
+        }
+    }
+}
+
+impl<T> Default for ShapeIterativeOffsetOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Loop in an [OffsetLoopTree].
+#[derive(Debug, Clone)]
+pub struct OffsetLoopNode<T>
+where
+    T: Real,
+{
+    /// The closed polyline of the loop.
+    pub polyline: Polyline<T>,
+    /// Offset pass the loop was created in (0 for the loops of the input shape).
+    pub pass: usize,
+    /// Index of the loop this loop was offset from, `None` for the loops of the input shape.
+    pub parent: Option<usize>,
+    /// Indexes of the loops offset from this loop.
+    pub children: Vec<usize>,
+}
+
+/// Tree of loops created by repeatedly offsetting a shape, see
+/// [Shape::iterative_offset_opt](crate::polyline::Shape::iterative_offset_opt).
+#[derive(Debug, Clone)]
+pub struct OffsetLoopTree<T>
+where
+    T: Real,
+{
+    /// All the loops of the tree ordered by pass (loops of the input shape first), within a pass
+    /// counter clockwise loops are ordered before clockwise loops.
+    pub nodes: Vec<OffsetLoopNode<T>>,
+    /// Number of passes in the tree (including the pass for the input shape).
+    pub pass_count: usize,
+}
+
+impl<T> OffsetLoopTree<T>
+where
+    T: Real,
+{
+    /// Returns true if the tree has no loops.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Iterate through the indexes of all the root loops (loops without a parent).
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.parent.is_none())
+            .map(|(i, _)| i)
+    }
+
+    /// Iterate through the indexes of all the loops created in offset `pass`.
+    pub fn pass_nodes(&self, pass: usize) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, n)| n.pass == pass)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Style used to cap the ends of an open polyline when stroking.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StrokeCapStyle {
+    /// End the stroke with a line through the polyline end point (no extension past the end).
+    Butt,
+    /// Extend the stroke past the polyline end point by half the stroke width and end it with a
+    /// line.
+    Square,
+    /// End the stroke with a half circle centered at the polyline end point.
+    #[default]
+    Round,
+}
+
+/// Struct to hold options parameters when stroking a polyline.
+#[derive(Debug, Clone)]
+pub struct PlineStrokeOptions<T>
+where
+    T: Real,
+{
+    /// Style used to join offset segments at convex corners.
+    pub join_style: OffsetJoinStyle<T>,
+    /// Style used to cap the ends of open polylines.
+    pub cap_style: StrokeCapStyle,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+}
+
+impl<T> PlineStrokeOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            join_style: OffsetJoinStyle::Round,
+            cap_style: StrokeCapStyle::Round,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineStrokeOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reason a polyline corner was not filleted or chamfered, `vertex_index` is the index of the
+/// corner vertex in the input polyline.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlineCornerError {
+    /// Vertex index is out of bounds.
+    InvalidIndex { vertex_index: usize },
+    /// Vertex is not a corner, either it is the start or end of an open polyline or the
+    /// direction of the polyline does not change at the vertex.
+    NotACorner { vertex_index: usize },
+    /// Segments adjacent to the corner are too short for the requested fillet radius or chamfer
+    /// distance (also reported when the corner would overlap an adjacent corner already cut).
+    SegmentTooShort { vertex_index: usize },
+    /// No tangent fillet arc of the requested radius exists at the corner (e.g. an adjacent arc
+    /// segment curves into the corner with a radius smaller than the fillet radius).
+    NoFillet { vertex_index: usize },
+}
+
+impl std::fmt::Display for PlineCornerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlineCornerError::InvalidIndex { vertex_index } => {
+                write!(f, "vertex index {} is out of bounds", vertex_index)
+            }
+            PlineCornerError::NotACorner { vertex_index } => {
+                write!(f, "vertex at index {} is not a corner", vertex_index)
+            }
+            PlineCornerError::SegmentTooShort { vertex_index } => write!(
+                f,
+                "segments adjacent to vertex at index {} are too short",
+                vertex_index
+            ),
+            PlineCornerError::NoFillet { vertex_index } => write!(
+                f,
+                "no fillet arc exists for the corner at vertex index {}",
+                vertex_index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlineCornerError {}
+
+/// Struct to hold options parameters when filleting or chamfering polyline corners.
+#[derive(Debug, Clone)]
+pub struct PlineCornerOptions<T>
+where
+    T: Real,
+{
+    /// If `Some` then only corners where the polyline direction changes by more than the angle
+    /// given (in radians) are modified when operating on all the corners of a polyline.
+    pub angle_threshold: Option<T>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineCornerOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            angle_threshold: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineCornerOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of filleting or chamfering all the corners of a polyline.
+#[derive(Debug, Clone)]
+pub struct PlineCornerResult<T>
+where
+    T: Real,
+{
+    /// Polyline with the corners modified.
+    pub polyline: Polyline<T>,
+    /// Corners that were left unchanged because the operation was not possible at the corner.
+    pub skipped: Vec<PlineCornerError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Boolean operation to apply to polylines.
+pub enum BooleanOp {
+    /// Return the union of the polylines.
+    Or,
+    /// Return the intersection of the polylines.
+    And,
+    /// Return the exclusion of a polyline from another.
+    Not,
+    /// Exclusive OR between polylines.
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Controls how boolean operation result regions that touch each other at a single point are
+/// returned.
+pub enum BooleanTouchingMode {
+    /// Keep the polylines as they are stitched together, regions touching at a point may be
+    /// returned as either a single polyline touching itself or as separate polylines.
+    AsStitched,
+    /// Merge regions touching at a point into a single polyline touching itself at the point, a
+    /// hole touching its outer boundary is merged into the outer boundary. Regions sharing an edge
+    /// are not merged.
+    Merge,
+    /// Split polylines touching themselves into separate polylines, loops of a split polyline
+    /// with the opposite orientation (e.g. a hole touching the outer boundary) are moved to the
+    /// opposite result list. The [BooleanResultPline::subslices] of a split polyline are dropped
+    /// (the split loops have no subslices).
+    Separate,
+}
+
+#[derive(Debug, Clone)]
+/// Boundary section shared by two closed polylines (the polylines have coincident segments along
+/// it), see [SharedEdge::find].
+pub struct SharedEdge<T>
+where
+    T: Real,
+{
+    /// Open polyline of the shared section following the direction of the first polyline.
+    pub pline: Polyline<T>,
+    /// Index of the first polyline sharing the edge.
+    pub pline_index1: usize,
+    /// Index of the second polyline sharing the edge.
+    pub pline_index2: usize,
+    /// Whether the second polyline has the opposite direction of the first polyline along the edge
+    /// (e.g. two adjacent regions with the same orientation).
+    pub opposing_directions: bool,
+}
+
+impl<T> SharedEdge<T>
+where
+    T: Real,
+{
+    /// Find the boundary sections shared by the closed polylines given using default options.
+    ///
+    /// See [SharedEdge::find_opt] for more information.
+    pub fn find(plines: &[Polyline<T>]) -> Vec<SharedEdge<T>> {
+        Self::find_opt(plines, &Default::default())
+    }
+
+    /// Find the boundary sections shared by the closed polylines given (sections where two of the
+    /// polylines have coincident segments) with options provided.
+    ///
+    /// Each shared section is returned as an open polyline following the direction of the polyline
+    /// with the lower index, along with the indexes of the two polylines sharing it. This is useful
+    /// for common line cutting of adjacent parts. Open polylines are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let left: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // shares half of the left square's right side
+    /// let right = pline_closed![(10.0, 5.0, 0.0), (20.0, 5.0, 0.0), (20.0, 15.0, 0.0), (10.0, 15.0, 0.0)];
+    /// let edges = SharedEdge::find(&[left, right]);
+    /// assert_eq!(edges.len(), 1);
+    /// assert_eq!((edges[0].pline_index1, edges[0].pline_index2), (0, 1));
+    /// assert!(edges[0].pline.path_length().fuzzy_eq(5.0));
+    /// assert!(edges[0].opposing_directions);
+    /// ```
+    pub fn find_opt(
+        plines: &[Polyline<T>],
+        options: &PlineSharedEdgesOptions<T>,
+    ) -> Vec<SharedEdge<T>> {
+        find_shared_edges(plines, options)
+    }
 }
 
-impl<'a, T> PlineOffsetOptions<'a, T>
+#[derive(Debug, Clone)]
+/// Bounded face of the planar arrangement formed by a collection of polylines, see
+/// [ArrangementFace::find].
+pub struct ArrangementFace<T>
 where
     T: Real,
 {
-    pub fn new() -> Self {
-        Self {
-            aabb_index: None,
-            handle_self_intersects: false,
-            pos_equal_eps: T::from(1e-5).unwrap(),
-            slice_join_eps: T::from(1e-4).unwrap(),
-            offset_dist_eps: T::from(1e-4).unwrap(),
-        }
-    }
+    /// Counter clockwise closed polyline of the outer boundary of the face.
+    pub boundary: Polyline<T>,
+    /// Clockwise closed polylines of the holes in the face (outer boundaries of the groups of
+    /// polylines nested inside the face).
+    pub holes: Vec<Polyline<T>>,
+    /// Index of the face this face is nested in (the face with a hole containing this face),
+    /// `None` if the face is not nested in another face.
+    pub parent: Option<usize>,
 }
 
-impl<'a, T> Default for PlineOffsetOptions<'a, T>
+impl<T> ArrangementFace<T>
 where
     T: Real,
 {
-    fn default() -> Self {
-        Self::new()
+    /// Find the bounded faces of the planar arrangement formed by the polylines given using
+    /// default options.
+    ///
+    /// See [ArrangementFace::find_opt] for more information.
+    pub fn find(plines: &[Polyline<T>]) -> Vec<ArrangementFace<T>> {
+        Self::find_opt(plines, &Default::default())
+    }
+
+    /// Find the bounded faces of the planar arrangement formed by the polylines given (open and
+    /// closed) with options provided.
+    ///
+    /// The polylines are split at all of their intersects with each other and themselves, every
+    /// region enclosed by the resulting segments is returned as a face with a counter clockwise
+    /// boundary, its clockwise holes, and the index of the face it is nested in. Segments that do
+    /// not enclose any region (e.g. dangling ends of open polylines) are ignored. This is useful
+    /// for picking a region to fill from a sketch of lines and arcs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let square: Polyline<f64> = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// // line across the square with dangling ends
+    /// let line = pline_open![(-5.0, 5.0, 0.0), (15.0, 5.0, 0.0)];
+    /// let faces = ArrangementFace::find(&[square, line]);
+    /// assert_eq!(faces.len(), 2);
+    /// assert!(faces.iter().all(|f| f.boundary.area().fuzzy_eq(50.0)));
+    /// assert!(faces.iter().all(|f| f.holes.is_empty() && f.parent.is_none()));
+    /// ```
+    pub fn find_opt(
+        plines: &[Polyline<T>],
+        options: &PlineArrangementOptions<T>,
+    ) -> Vec<ArrangementFace<T>> {
+        find_arrangement_faces(plines, options)
     }
 }
 
+#[derive(Debug, Clone, Default)]
+/// Result of dividing a polyline by another polyline, see [Polyline::boolean_divide].
+pub struct BooleanDivideResult<T>
+where
+    T: Real,
+{
+    /// Pieces of the first polyline inside the second polyline (same as [BooleanOp::And]).
+    pub inside: BooleanResult<T>,
+    /// Pieces of the first polyline outside the second polyline (same as [BooleanOp::Not]).
+    pub outside: BooleanResult<T>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-/// Boolean operation to apply to polylines.
-pub enum BooleanOp {
-    /// Return the union of the polylines.
-    Or,
-    /// Return the intersection of the polylines.
-    And,
-    /// Return the exclusion of a polyline from another.
-    Not,
-    /// Exclusive OR between polylines.
-    Xor,
+/// Rule used to determine which regions of a self intersecting closed polyline are filled based on
+/// the winding number.
+pub enum FillRule {
+    /// Region is filled if the winding number is not zero.
+    NonZero,
+    /// Region is filled if the winding number is odd.
+    EvenOdd,
+    /// Region is filled if the winding number is greater than zero.
+    Positive,
+    /// Region is filled if the winding number is less than zero.
+    Negative,
+}
+
+impl FillRule {
+    /// Returns true if a region with the `winding_number` given is filled according to this rule.
+    #[inline]
+    pub fn is_filled(&self, winding_number: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding_number != 0,
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::Positive => winding_number > 0,
+            FillRule::Negative => winding_number < 0,
+        }
+    }
 }
 
 #[cfg_attr(
@@ -184,6 +1054,13 @@ where
     /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
     /// polyline slices together.
     pub slice_join_eps: T,
+    /// Controls how result regions touching each other at a single point are returned.
+    pub touching_mode: BooleanTouchingMode,
+    /// Drop result polylines with zero area (e.g. the sliver left along the edge shared by two
+    /// polylines). A polyline is considered zero area if its area is less than `pos_equal_eps`
+    /// times its path length. Slivers are dropped before applying the `touching_mode` so they are
+    /// never merged into other polylines.
+    pub drop_slivers: bool,
 }
 
 impl<'a, T> PlineBooleanOptions<'a, T>
@@ -195,6 +1072,8 @@ where
             pline1_aabb_index: None,
             pos_equal_eps: T::from(1e-5).unwrap(),
             slice_join_eps: T::from(1e-4).unwrap(),
+            touching_mode: BooleanTouchingMode::AsStitched,
+            drop_slivers: false,
         }
     }
 }
@@ -208,6 +1087,191 @@ where
     }
 }
 
+/// Struct to hold options parameters when resolving the self intersects of a polyline according to
+/// a [FillRule].
+#[derive(Debug)]
+pub struct PlineResolveOptions<'a, T>
+where
+    T: Real,
+{
+    /// Spatial index for the polyline.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+}
+
+impl<'a, T> PlineResolveOptions<'a, T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineResolveOptions<'a, T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when finding or dissolving the shared edges between closed
+/// polylines.
+#[derive(Debug)]
+pub struct PlineSharedEdgesOptions<T>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together (only used when dissolving).
+    pub slice_join_eps: T,
+}
+
+impl<T> PlineSharedEdgesOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineSharedEdgesOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when finding the faces of the planar arrangement formed by a
+/// collection of polylines.
+#[derive(Debug)]
+pub struct PlineArrangementOptions<T>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineArrangementOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineArrangementOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when clipping an open polyline against a closed region.
+#[derive(Debug)]
+pub struct PlineClipOptions<'a, T>
+where
+    T: Real,
+{
+    /// Spatial index for `self` (the polyline being clipped).
+    pub pline_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineClipOptions<'a, T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pline_aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineClipOptions<'a, T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Containment nesting of the polylines of a [Shape](crate::polyline::Shape), see
+/// [Shape::hierarchy](crate::polyline::Shape::hierarchy).
+pub struct ShapeHierarchy {
+    /// Index of the counter clockwise polyline directly enclosing each clockwise polyline (`None`
+    /// if the clockwise polyline is not enclosed by any counter clockwise polyline).
+    pub cw_parents: Vec<Option<usize>>,
+    /// Index of the clockwise polyline directly enclosing each counter clockwise polyline (island
+    /// inside a hole), `None` if the counter clockwise polyline is not enclosed by any clockwise
+    /// polyline.
+    pub ccw_parents: Vec<Option<usize>>,
+}
+
+/// Struct to hold options parameters when performing a boolean operation between two
+/// [Shape](crate::polyline::Shape)s.
+#[derive(Debug, Clone)]
+pub struct ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+}
+
+impl<T> ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Enum to control which self intersects to include.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SelfIntersectsInclude {
@@ -1170,3 +2234,126 @@ where
         self.inverted
     }
 }
+
+/// Struct to hold options parameters when computing a medial axis.
+#[derive(Debug, Clone)]
+pub struct MedialAxisOptions<T>
+where
+    T: Real,
+{
+    /// Maximum distance between points sampled along the boundary, smaller values give a more
+    /// accurate axis at the cost of performance. If `None` then 1/256 of the largest extent
+    /// dimension of the shape is used.
+    pub sample_spacing: Option<T>,
+    /// Branches ending at a node with only one branch (spurs) that are shorter than this length
+    /// are removed from the axis.
+    pub min_spur_length: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> MedialAxisOptions<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            sample_spacing: None,
+            min_spur_length: T::zero(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for MedialAxisOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Node of a [MedialAxis] graph (branch end point or junction of branches).
+#[derive(Debug, Clone)]
+pub struct MedialAxisNode<T>
+where
+    T: Real,
+{
+    /// Position of the node.
+    pub pos: Vector2<T>,
+    /// Radius of the inscribed circle centered at the node.
+    pub radius: T,
+    /// Indexes of the branches that start or end at the node.
+    pub branches: Vec<usize>,
+}
+
+/// Branch of a [MedialAxis] graph connecting two nodes.
+#[derive(Debug, Clone)]
+pub struct MedialAxisBranch<T>
+where
+    T: Real,
+{
+    /// Open polyline of the branch (all segments are lines), the first vertex is at the start
+    /// node and the last vertex is at the end node.
+    pub polyline: Polyline<T>,
+    /// Radius of the inscribed circle centered at each vertex of the polyline.
+    pub radii: Vec<T>,
+    /// Index of the node the branch starts at.
+    pub start_node: usize,
+    /// Index of the node the branch ends at (same as `start_node` for a branch that forms a loop,
+    /// e.g. around a hole).
+    pub end_node: usize,
+}
+
+/// Medial axis of a shape as a graph of branches joined at nodes.
+#[derive(Debug, Clone)]
+pub struct MedialAxis<T>
+where
+    T: Real,
+{
+    /// Nodes of the graph.
+    pub nodes: Vec<MedialAxisNode<T>>,
+    /// Branches of the graph.
+    pub branches: Vec<MedialAxisBranch<T>>,
+}
+
+impl<T> MedialAxis<T>
+where
+    T: Real,
+{
+    /// Create an empty medial axis with no nodes or branches.
+    pub fn empty() -> Self {
+        Self {
+            nodes: Vec::new(),
+            branches: Vec::new(),
+        }
+    }
+
+    /// Returns true if the medial axis has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the largest inscribed circle radius along the medial axis (the maximum wall
+    /// thickness of the shape is two times this value), `None` if the medial axis is empty.
+    pub fn max_radius(&self) -> Option<T> {
+        self.nodes
+            .iter()
+            .map(|n| n.radius)
+            .chain(self.branches.iter().flat_map(|b| b.radii.iter().copied()))
+            .fold(None, |acc, r| match acc {
+                Some(m) if m >= r => Some(m),
+                _ => Some(r),
+            })
+    }
+}
+
+impl<T> Default for MedialAxis<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
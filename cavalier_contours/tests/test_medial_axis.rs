@@ -0,0 +1,233 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{MedialAxis, MedialAxisOptions, Polyline, Shape},
+};
+use test_utils::rect;
+
+/// Assert the graph links between nodes and branches are consistent.
+fn assert_graph_valid(axis: &MedialAxis<f64>) {
+    for (i, b) in axis.branches.iter().enumerate() {
+        assert!(!b.polyline.is_closed());
+        assert_eq!(b.polyline.len(), b.radii.len());
+        let first = b.polyline[0].pos();
+        let last = b.polyline[b.polyline.len() - 1].pos();
+        assert!(first.fuzzy_eq(axis.nodes[b.start_node].pos));
+        assert!(last.fuzzy_eq(axis.nodes[b.end_node].pos));
+        assert!(axis.nodes[b.start_node].branches.contains(&i));
+        assert!(axis.nodes[b.end_node].branches.contains(&i));
+    }
+}
+
+#[test]
+fn rectangle_axis() {
+    let axis = rect(0.0, 0.0, 10.0, 2.0).medial_axis();
+    assert_graph_valid(&axis);
+    // center line with a branch going to each corner
+    assert_eq!(axis.nodes.len(), 6);
+    assert_eq!(axis.branches.len(), 5);
+    let junctions: Vec<_> = axis
+        .nodes
+        .iter()
+        .filter(|n| n.branches.len() == 3)
+        .collect();
+    assert_eq!(junctions.len(), 2);
+    for n in junctions {
+        assert!(n.radius.fuzzy_eq_eps(1.0, 1e-3));
+        assert!(n.pos.y.fuzzy_eq_eps(1.0, 1e-3));
+    }
+
+    let center_line = axis
+        .branches
+        .iter()
+        .max_by(|a, b| {
+            a.polyline
+                .path_length()
+                .partial_cmp(&b.polyline.path_length())
+                .unwrap()
+        })
+        .unwrap();
+    assert!(center_line.polyline.path_length().fuzzy_eq_eps(8.0, 1e-3));
+    for (v, &r) in center_line.polyline.iter().zip(center_line.radii.iter()) {
+        assert!(v.y.fuzzy_eq(1.0));
+        assert!(r.fuzzy_eq(1.0));
+    }
+
+    // same result for clockwise direction
+    let mut inverted = rect(0.0, 0.0, 10.0, 2.0);
+    inverted.invert_direction();
+    let axis = inverted.medial_axis();
+    assert_eq!(axis.nodes.len(), 6);
+    assert_eq!(axis.branches.len(), 5);
+}
+
+#[test]
+fn prune_corner_spurs() {
+    let options = MedialAxisOptions {
+        min_spur_length: 2.0,
+        ..Default::default()
+    };
+    let axis = rect(0.0, 0.0, 10.0, 2.0).medial_axis_opt(&options);
+    assert_graph_valid(&axis);
+    assert_eq!(axis.nodes.len(), 2);
+    assert_eq!(axis.branches.len(), 1);
+    assert!(axis.max_radius().unwrap().fuzzy_eq(1.0));
+}
+
+#[test]
+fn circle_axis() {
+    let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let axis = circle.medial_axis();
+    assert!(axis.branches.is_empty());
+    assert_eq!(axis.nodes.len(), 1);
+    assert!(axis.nodes[0].pos.fuzzy_eq_eps(Vector2::new(5.0, 0.0), 1e-5));
+    assert!(axis.nodes[0].radius.fuzzy_eq_eps(5.0, 1e-5));
+}
+
+#[test]
+fn stadium_axis() {
+    // axis of a rectangle with half circle ends goes between the arc centers
+    let stadium = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 4.0, 0.0),
+        (0.0, 4.0, 1.0)
+    ];
+    let axis = stadium.medial_axis();
+    assert_graph_valid(&axis);
+    assert_eq!(axis.branches.len(), 1);
+    let branch = &axis.branches[0];
+    assert!(branch.polyline.path_length().fuzzy_eq_eps(10.0, 1e-5));
+    for (v, &r) in branch.polyline.iter().zip(branch.radii.iter()) {
+        assert!(v.y.fuzzy_eq(2.0));
+        assert!(r.fuzzy_eq_eps(2.0, 1e-5));
+    }
+}
+
+#[test]
+fn shape_with_hole_axis() {
+    let outer = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let hole = pline_closed![
+        (4.0, 4.0, 0.0),
+        (4.0, 6.0, 0.0),
+        (6.0, 6.0, 0.0),
+        (6.0, 4.0, 0.0)
+    ];
+    let shape = Shape::from_plines(vec![outer, hole]);
+    let options = MedialAxisOptions {
+        min_spur_length: 1.0,
+        ..Default::default()
+    };
+    let axis = shape.medial_axis_opt(&options);
+    assert_graph_valid(&axis);
+
+    // 4 junctions joining the loop around the hole with the outer corner branches
+    let junctions: Vec<_> = axis
+        .nodes
+        .iter()
+        .filter(|n| n.branches.len() == 3)
+        .collect();
+    assert_eq!(junctions.len(), 4);
+    let expected_radius = 8.0 - 4.0 * 2.0f64.sqrt();
+    for n in junctions {
+        assert!(n.radius.fuzzy_eq_eps(expected_radius, 1e-3));
+    }
+
+    // axis around the hole is half way to the outer sides
+    let loop_branches: Vec<_> = axis
+        .branches
+        .iter()
+        .filter(|b| {
+            axis.nodes[b.start_node].branches.len() == 3
+                && axis.nodes[b.end_node].branches.len() == 3
+        })
+        .collect();
+    assert_eq!(loop_branches.len(), 4);
+    for b in loop_branches {
+        let min_radius = b.radii.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(min_radius.fuzzy_eq_eps(2.0, 1e-5));
+    }
+}
+
+#[test]
+fn reflex_corner_axis() {
+    let l_shape = pline_closed![
+        (0.0, 0.0, 0.0),
+        (6.0, 0.0, 0.0),
+        (6.0, 2.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 6.0, 0.0),
+        (0.0, 6.0, 0.0),
+    ];
+    let axis = l_shape.medial_axis();
+    assert_graph_valid(&axis);
+    // junction at the center of the circle touching both outer sides and the reflex corner
+    let expected_radius = 4.0 - 2.0 * 2.0f64.sqrt();
+    let corner_junction = axis
+        .nodes
+        .iter()
+        .find(|n| n.branches.len() == 3 && n.pos.x.fuzzy_eq_eps(n.pos.y, 1e-3) && n.pos.x < 2.0)
+        .unwrap();
+    assert!(corner_junction.radius.fuzzy_eq_eps(expected_radius, 1e-3));
+
+    // every axis vertex is equidistant to the reflex corner or an inner side and an outer side
+    for b in axis.branches.iter() {
+        for (v, &r) in b.polyline.iter().zip(b.radii.iter()) {
+            let outer_dist = v.x.min(v.y).min(6.0 - v.x).min(6.0 - v.y);
+            assert!(outer_dist.fuzzy_eq_eps(r, 1e-2));
+        }
+    }
+}
+
+#[test]
+fn open_polyline_axis_is_empty() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    assert!(pline.medial_axis().is_empty());
+    assert!(Shape::<f64>::empty().medial_axis().is_empty());
+}
+
+#[test]
+fn non_finite_vertex_axis_is_empty() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, f64::NAN, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    assert!(pline.medial_axis().is_empty());
+}
+
+#[test]
+fn annulus_axis_radius_error() {
+    // axis of an annulus is the circle midway between the boundaries, radius is half the wall
+    let outer = pline_closed![(-10.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let mut hole: Polyline<f64> = pline_closed![(-6.0, 0.0, 1.0), (6.0, 0.0, 1.0)];
+    hole.invert_direction();
+    let shape = Shape::from_plines(vec![outer, hole]);
+    let half_wall = 2.0;
+    for &(spacing, tol) in &[
+        (None, 1e-3),
+        (Some(0.5), 0.5 * 0.5 / (8.0 * half_wall)),
+        (Some(1.0), 1.0 / (8.0 * half_wall)),
+    ] {
+        let options = MedialAxisOptions {
+            sample_spacing: spacing,
+            ..Default::default()
+        };
+        let axis = shape.medial_axis_opt(&options);
+        assert_graph_valid(&axis);
+        assert_eq!(axis.branches.len(), 1);
+        let branch = &axis.branches[0];
+        for (v, &r) in branch.polyline.iter().zip(branch.radii.iter()) {
+            assert!(r.fuzzy_eq_eps(half_wall, tol));
+            assert!(v.pos().length().fuzzy_eq_eps(8.0, tol));
+        }
+    }
+}
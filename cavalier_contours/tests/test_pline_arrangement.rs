@@ -0,0 +1,172 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::traits::FuzzyEq,
+    pline_closed, pline_open,
+    polyline::{ArrangementFace, PlineOrientation, Polyline},
+};
+use std::f64::consts::PI;
+use test_utils::{circle, square};
+
+/// Get the face areas sorted in ascending order.
+fn sorted_areas(faces: &[ArrangementFace<f64>]) -> Vec<f64> {
+    let mut areas: Vec<f64> = faces.iter().map(|f| f.boundary.area()).collect();
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    areas
+}
+
+fn assert_areas(faces: &[ArrangementFace<f64>], expected: &[f64]) {
+    let areas = sorted_areas(faces);
+    assert_eq!(areas.len(), expected.len(), "areas: {:?}", areas);
+    for (&a, &e) in areas.iter().zip(expected.iter()) {
+        assert!(a.fuzzy_eq_eps(e, 1e-5), "area {} != {}", a, e);
+    }
+}
+
+#[test]
+fn lines_crossing_square() {
+    let diagonal1 = pline_open![(-5.0, -5.0, 0.0), (15.0, 15.0, 0.0)];
+    let diagonal2 = pline_open![(-5.0, 15.0, 0.0), (15.0, -5.0, 0.0)];
+    let faces = ArrangementFace::find(&[square(0.0, 0.0, 10.0), diagonal1, diagonal2]);
+    assert_areas(&faces, &[25.0, 25.0, 25.0, 25.0]);
+    for face in faces.iter() {
+        assert!(face.boundary.is_closed());
+        assert_eq!(face.boundary.len(), 3);
+        assert!(face.holes.is_empty());
+        assert!(face.parent.is_none());
+    }
+}
+
+#[test]
+fn open_lines_forming_triangle() {
+    // lines overhanging at each corner of the triangle
+    let lines = vec![
+        pline_open![(-5.0, 0.0, 0.0), (15.0, 0.0, 0.0)],
+        pline_open![(12.0, -2.0, 0.0), (-2.0, 12.0, 0.0)],
+        pline_open![(0.0, -5.0, 0.0), (0.0, 15.0, 0.0)],
+    ];
+    let faces = ArrangementFace::find(&lines);
+    assert_areas(&faces, &[50.0]);
+
+    // no enclosed regions
+    assert!(ArrangementFace::find(&lines[..2]).is_empty());
+    assert!(ArrangementFace::<f64>::find(&[]).is_empty());
+}
+
+#[test]
+fn nested_faces() {
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(2.0, 2.0, 6.0),
+        square(4.0, 4.0, 2.0),
+        square(20.0, 0.0, 10.0),
+    ];
+    let faces = ArrangementFace::find(&plines);
+    assert_eq!(faces.len(), 4);
+
+    let find_face = |area: f64| {
+        faces
+            .iter()
+            .position(|f| f.boundary.area().fuzzy_eq(area))
+            .unwrap()
+    };
+    let outer = find_face(100.0);
+    let middle = find_face(36.0);
+    let inner = find_face(4.0);
+
+    assert!(faces[outer].parent.is_none());
+    assert_eq!(faces[middle].parent, Some(outer));
+    assert_eq!(faces[inner].parent, Some(middle));
+    assert_eq!(faces[outer].holes.len(), 1);
+    assert!(faces[outer].holes[0].area().fuzzy_eq(-36.0));
+    assert_eq!(
+        faces[middle].holes[0].orientation(),
+        PlineOrientation::Clockwise
+    );
+    assert!(faces[inner].holes.is_empty());
+
+    // hole containing separate loops
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(1.0, 1.0, 2.0),
+        square(5.0, 5.0, 2.0),
+    ];
+    let faces = ArrangementFace::find(&plines);
+    let outer = faces
+        .iter()
+        .position(|f| f.boundary.area().fuzzy_eq(100.0))
+        .unwrap();
+    assert_eq!(faces[outer].holes.len(), 2);
+    assert_eq!(faces.iter().filter(|f| f.parent == Some(outer)).count(), 2);
+}
+
+#[test]
+fn arcs() {
+    // circle cut in half by a line
+    let line = pline_open![(-10.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let faces = ArrangementFace::find(&[circle(0.0, 0.0, 5.0), line]);
+    assert_areas(&faces, &[12.5 * PI, 12.5 * PI]);
+
+    // circle tangent inside another circle, all segments leave the tangent point in the same
+    // direction so they are ordered by curvature
+    let faces = ArrangementFace::find(&[circle(0.0, 0.0, 5.0), circle(0.0, 3.0, 2.0)]);
+    assert_areas(&faces, &[4.0 * PI, 21.0 * PI]);
+    assert!(faces.iter().all(|f| f.holes.is_empty()));
+
+    // circles touching at a vertex of both, two arcs leave the tangent point in the -x direction
+    // (angles either side of the wrap at π) and are ordered by curvature
+    let lower = pline_closed![(0.0, -5.0, 1.0), (0.0, 5.0, 1.0)];
+    let upper = pline_closed![(0.0, 5.0, 1.0), (0.0, 9.0, 1.0)];
+    let faces = ArrangementFace::find(&[lower, upper]);
+    assert_areas(&faces, &[4.0 * PI, 25.0 * PI]);
+    assert!(faces
+        .iter()
+        .all(|f| f.holes.is_empty() && f.parent.is_none()));
+
+    // line tangent to a circle does not create any faces
+    let line = pline_open![(-10.0, 5.0, 0.0), (10.0, 5.0, 0.0)];
+    let faces = ArrangementFace::find(&[circle(0.0, 0.0, 5.0), line]);
+    assert_areas(&faces, &[25.0 * PI]);
+}
+
+#[test]
+fn overlapping_and_self_intersecting() {
+    // adjacent squares sharing part of an edge
+    let faces = ArrangementFace::find(&[square(0.0, 0.0, 10.0), square(10.0, 5.0, 10.0)]);
+    assert_areas(&faces, &[100.0, 100.0]);
+
+    // same square given twice
+    let faces = ArrangementFace::find(&[square(0.0, 0.0, 10.0), square(0.0, 0.0, 10.0)]);
+    assert_areas(&faces, &[100.0]);
+
+    // overlapping squares
+    let faces = ArrangementFace::find(&[square(0.0, 0.0, 10.0), square(5.0, 5.0, 10.0)]);
+    assert_areas(&faces, &[25.0, 75.0, 75.0]);
+
+    let bow_tie = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let faces = ArrangementFace::find(&[bow_tie]);
+    assert_areas(&faces, &[25.0, 25.0]);
+}
+
+#[test]
+fn bridges_and_dangling_edges() {
+    // squares joined by a line and a spike going into one square
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(20.0, 0.0, 10.0),
+        pline_open![(10.0, 5.0, 0.0), (20.0, 5.0, 0.0), (25.0, 5.0, 0.0)],
+    ];
+    let faces = ArrangementFace::find(&plines);
+    assert_areas(&faces, &[100.0, 100.0]);
+    for face in faces.iter() {
+        // boundary is split where the bridge joined it
+        assert_eq!(face.boundary.len(), 5);
+        assert!(face.holes.is_empty());
+        assert!(face.parent.is_none());
+    }
+}
@@ -4,8 +4,8 @@ use cavalier_contours::polyline::{
     BooleanOp, BooleanPlineSlice, BooleanResult, BooleanResultPline, Polyline, PolylineSlice,
 };
 use test_utils::{
-    create_property_set, property_sets_match, property_sets_match_abs_a, ModifiedPlineSet,
-    ModifiedPlineSetVisitor, ModifiedPlineState, PlineProperties,
+    circle, create_property_set, property_sets_match, property_sets_match_abs_a, rect, square,
+    ModifiedPlineSet, ModifiedPlineSetVisitor, ModifiedPlineState, PlineProperties,
 };
 
 fn create_boolean_property_set(polylines: &[BooleanResultPline<f64>]) -> Vec<PlineProperties> {
@@ -505,3 +505,443 @@ mod test_specific {
         }
     }
 }
+
+mod test_try_boolean {
+    use super::*;
+    use cavalier_contours::polyline::PlineOpError;
+
+    #[test]
+    fn valid_input_matches_boolean() {
+        let rectangle = rect(-1.0, -2.0, 3.0, 2.0);
+        let circle = circle(1.0, 0.0, 1.0);
+        for op in [
+            BooleanOp::Or,
+            BooleanOp::And,
+            BooleanOp::Not,
+            BooleanOp::Xor,
+        ] {
+            let expected = rectangle.boolean(&circle, op);
+            let result = rectangle.try_boolean(&circle, op).unwrap();
+            assert!(property_sets_match(
+                &create_boolean_property_set(&result.pos_plines),
+                &create_boolean_property_set(&expected.pos_plines)
+            ));
+            assert!(property_sets_match(
+                &create_boolean_property_set(&result.neg_plines),
+                &create_boolean_property_set(&expected.neg_plines)
+            ));
+        }
+    }
+
+    #[test]
+    fn too_few_vertexes() {
+        let mut single = Polyline::new_closed();
+        single.add(0.0, 0.0, 0.0);
+        assert_eq!(
+            single
+                .try_boolean(&circle(1.0, 0.0, 1.0), BooleanOp::Or)
+                .unwrap_err(),
+            PlineOpError::TooFewVertexes { input: 0 }
+        );
+        assert_eq!(
+            circle(1.0, 0.0, 1.0)
+                .try_boolean(&single, BooleanOp::Or)
+                .unwrap_err(),
+            PlineOpError::TooFewVertexes { input: 1 }
+        );
+    }
+
+    #[test]
+    fn open_input() {
+        let mut open = circle(1.0, 0.0, 1.0);
+        open.set_is_closed(false);
+        assert_eq!(
+            open.try_boolean(&rect(-1.0, -2.0, 3.0, 2.0), BooleanOp::And)
+                .unwrap_err(),
+            PlineOpError::OpenInput { input: 0 }
+        );
+    }
+
+    #[test]
+    fn repeat_position_vertexes() {
+        let mut repeat = rect(-1.0, -2.0, 3.0, 2.0);
+        // last vertex repeats position of the first vertex
+        repeat.add(-1.0, -2.0, 0.0);
+        assert_eq!(
+            circle(1.0, 0.0, 1.0)
+                .try_boolean(&repeat, BooleanOp::Not)
+                .unwrap_err(),
+            PlineOpError::RepeatPositionVertexes {
+                input: 1,
+                vertex_index: 0
+            }
+        );
+    }
+
+    #[test]
+    fn non_finite_vertex() {
+        let mut nan = rect(-1.0, -2.0, 3.0, 2.0);
+        nan.set_vertex(2, f64::NAN, 2.0, 0.0);
+        assert_eq!(
+            nan.try_boolean(&circle(1.0, 0.0, 1.0), BooleanOp::Xor)
+                .unwrap_err(),
+            PlineOpError::NonFiniteVertex {
+                input: 0,
+                vertex_index: 2
+            }
+        );
+
+        let mut inf = rect(-1.0, -2.0, 3.0, 2.0);
+        inf.set_vertex(1, 3.0, -2.0, f64::INFINITY);
+        assert_eq!(
+            circle(1.0, 0.0, 1.0)
+                .try_boolean(&inf, BooleanOp::Xor)
+                .unwrap_err(),
+            PlineOpError::NonFiniteVertex {
+                input: 1,
+                vertex_index: 1
+            }
+        );
+    }
+}
+
+mod test_divide_imprint {
+    use super::*;
+    use cavalier_contours::{
+        core::{math::Vector2, traits::FuzzyEq},
+        pline_closed, pline_open,
+    };
+
+    fn assert_same_result(result: &BooleanResult<f64>, expected: &BooleanResult<f64>) {
+        assert!(property_sets_match(
+            &create_boolean_property_set(&result.pos_plines),
+            &create_boolean_property_set(&expected.pos_plines)
+        ));
+        assert!(property_sets_match(
+            &create_boolean_property_set(&result.neg_plines),
+            &create_boolean_property_set(&expected.neg_plines)
+        ));
+    }
+
+    #[test]
+    fn divide_matches_and_not() {
+        let rectangle = rect(-1.0, -2.0, 3.0, 2.0);
+        let others = [
+            // overlapping
+            pline_closed![(2.0, 0.0, 1.0), (4.0, 0.0, 1.0)],
+            // inside
+            pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+            // disjoint
+            pline_closed![(10.0, 0.0, 1.0), (12.0, 0.0, 1.0)],
+            // sharing an edge
+            pline_closed![
+                (3.0, -2.0, 0.0),
+                (5.0, -2.0, 0.0),
+                (5.0, 2.0, 0.0),
+                (3.0, 2.0, 0.0)
+            ],
+        ];
+
+        for other in others.iter() {
+            let result = rectangle.boolean_divide(other);
+            assert_same_result(&result.inside, &rectangle.boolean(other, BooleanOp::And));
+            assert_same_result(&result.outside, &rectangle.boolean(other, BooleanOp::Not));
+        }
+    }
+
+    #[test]
+    fn imprint_keeps_shape() {
+        let rectangle = rect(-1.0, -2.0, 3.0, 2.0);
+        let circle = pline_closed![(2.0, 0.0, 1.0), (4.0, 0.0, 1.0)];
+
+        let result = rectangle.imprint(&circle);
+        assert_eq!(result.len(), 6);
+        assert!(result[2].pos().fuzzy_eq(Vector2::new(3.0, -1.0)));
+        assert!(result[3].pos().fuzzy_eq(Vector2::new(3.0, 1.0)));
+        assert!(result.area().fuzzy_eq(rectangle.area()));
+        assert!(result.path_length().fuzzy_eq(rectangle.path_length()));
+
+        let result = circle.imprint(&rectangle);
+        assert_eq!(result.len(), 4);
+        assert!(result.area().fuzzy_eq(circle.area()));
+        assert!(result.path_length().fuzzy_eq(circle.path_length()));
+
+        // no intersects
+        let far = pline_closed![(10.0, 0.0, 1.0), (12.0, 0.0, 1.0)];
+        assert_eq!(rectangle.imprint(&far).len(), 4);
+    }
+
+    #[test]
+    fn imprint_overlapping_and_vertexes() {
+        let rectangle = rect(-1.0, -2.0, 3.0, 2.0);
+
+        // line overlapping part of the bottom edge and ending at a rectangle vertex
+        let line = pline_open![(0.0, -2.0, 0.0), (3.0, -2.0, 0.0)];
+        let result = rectangle.imprint(&line);
+        assert_eq!(result.len(), 5);
+        assert!(result[1].pos().fuzzy_eq(Vector2::new(0.0, -2.0)));
+        assert!(result.area().fuzzy_eq(rectangle.area()));
+
+        // open polyline imprinted by closed polyline
+        let open = pline_open![(-5.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+        let result = open.imprint(&rectangle);
+        assert!(!result.is_closed());
+        assert_eq!(result.len(), 4);
+        assert!(result[1].pos().fuzzy_eq(Vector2::new(-1.0, 0.0)));
+        assert!(result[2].pos().fuzzy_eq(Vector2::new(3.0, 0.0)));
+        assert!(result.path_length().fuzzy_eq(10.0));
+    }
+}
+
+mod test_hierarchy {
+    use super::*;
+    use cavalier_contours::polyline::ShapeHierarchy;
+
+    #[test]
+    fn boolean_results() {
+        // hole cut in the square
+        let result = square(0.0, 0.0, 10.0).boolean(&square(2.0, 2.0, 6.0), BooleanOp::Not);
+        assert_eq!(
+            result.hierarchy(),
+            ShapeHierarchy {
+                cw_parents: vec![Some(0)],
+                ccw_parents: vec![None],
+            }
+        );
+
+        // disjoint squares
+        let result = square(0.0, 0.0, 10.0).boolean(&square(20.0, 20.0, 10.0), BooleanOp::Or);
+        assert_eq!(result.pos_plines.len(), 2);
+        let hierarchy = result.hierarchy();
+        assert!(hierarchy.cw_parents.is_empty());
+        assert_eq!(hierarchy.ccw_parents, vec![None, None]);
+
+        assert_eq!(
+            BooleanResult::<f64>::default().hierarchy(),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn islands_within_holes() {
+        // outer square with a hole containing an island with a hole containing another island
+        // with a hole, given in shuffled order
+        let result = BooleanResult::from_whole_plines(
+            vec![
+                square(8.0, 8.0, 4.0),
+                square(0.0, 0.0, 20.0),
+                square(9.0, 9.0, 2.0),
+            ],
+            vec![
+                square(9.5, 9.5, 1.0),
+                square(2.0, 2.0, 16.0),
+                square(8.5, 8.5, 3.0),
+            ],
+        );
+        let hierarchy = result.hierarchy();
+        assert_eq!(hierarchy.ccw_parents, vec![Some(1), None, Some(2)]);
+        assert_eq!(hierarchy.cw_parents, vec![Some(2), Some(1), Some(0)]);
+    }
+}
+
+mod test_touching {
+    use super::*;
+    use cavalier_contours::{
+        core::traits::FuzzyEq,
+        pline_closed,
+        polyline::{BooleanTouchingMode, PlineBooleanOptions},
+    };
+
+    fn options(
+        touching_mode: BooleanTouchingMode,
+        drop_slivers: bool,
+    ) -> PlineBooleanOptions<'static, f64> {
+        PlineBooleanOptions {
+            touching_mode,
+            drop_slivers,
+            ..Default::default()
+        }
+    }
+
+    /// Absolute areas of the polylines sorted in ascending order.
+    fn abs_areas(plines: &[BooleanResultPline<f64>]) -> Vec<f64> {
+        let mut areas: Vec<f64> = plines.iter().map(|r| r.pline.area().abs()).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        areas
+    }
+
+    fn assert_abs_areas(plines: &[BooleanResultPline<f64>], expected: &[f64]) {
+        let areas = abs_areas(plines);
+        assert_eq!(areas.len(), expected.len(), "areas: {:?}", areas);
+        for (a, e) in areas.iter().zip(expected.iter()) {
+            assert!(a.fuzzy_eq(*e), "area {} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn touching_at_corner() {
+        let pline1 = square(0.0, 0.0, 10.0);
+        let pline2 = square(10.0, 10.0, 10.0);
+        for &op in [BooleanOp::Or, BooleanOp::Xor].iter() {
+            let merged =
+                pline1.boolean_opt(&pline2, op, &options(BooleanTouchingMode::Merge, false));
+            assert_abs_areas(&merged.pos_plines, &[200.0]);
+            assert_eq!(merged.pos_plines[0].pline.len(), 8);
+            assert!(merged.neg_plines.is_empty());
+
+            let separate =
+                pline1.boolean_opt(&pline2, op, &options(BooleanTouchingMode::Separate, false));
+            assert_abs_areas(&separate.pos_plines, &[100.0, 100.0]);
+            assert!(separate.pos_plines.iter().all(|r| r.pline.len() == 4));
+            assert!(separate.neg_plines.is_empty());
+        }
+
+        // triangle vertex touching the middle of the square edge
+        let triangle = pline_closed![(10.0, 5.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0)];
+        let result = pline1.boolean_opt(
+            &triangle,
+            BooleanOp::Or,
+            &options(BooleanTouchingMode::Separate, false),
+        );
+        assert_abs_areas(&result.pos_plines, &[50.0, 100.0]);
+        let result = pline1.boolean_opt(
+            &triangle,
+            BooleanOp::Or,
+            &options(BooleanTouchingMode::Merge, false),
+        );
+        assert_abs_areas(&result.pos_plines, &[150.0]);
+    }
+
+    #[test]
+    fn hole_touching_boundary() {
+        let pline1 = square(0.0, 0.0, 10.0);
+        // triangle inside the square with a vertex on the square's left edge
+        let pline2 = pline_closed![(0.0, 5.0, 0.0), (5.0, 2.0, 0.0), (5.0, 8.0, 0.0)];
+
+        let separate = pline1.boolean_opt(
+            &pline2,
+            BooleanOp::Not,
+            &options(BooleanTouchingMode::Separate, false),
+        );
+        assert_abs_areas(&separate.pos_plines, &[100.0]);
+        assert_abs_areas(&separate.neg_plines, &[15.0]);
+        let pos_area = separate.pos_plines[0].pline.area();
+        let neg_area = separate.neg_plines[0].pline.area();
+        assert!(pos_area.signum() != neg_area.signum());
+
+        let merged = pline1.boolean_opt(
+            &pline2,
+            BooleanOp::Not,
+            &options(BooleanTouchingMode::Merge, false),
+        );
+        assert_abs_areas(&merged.pos_plines, &[85.0]);
+        assert!(merged.neg_plines.is_empty());
+    }
+
+    #[test]
+    fn hole_stitched_separately_touching_outer() {
+        // U shape closed off by a triangle, leaving a hole that touches the outer boundary
+        let u_shape: Polyline<f64> = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (8.0, 10.0, 0.0),
+            (8.0, 2.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (2.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        // triangle vertex at the U shape vertex and in the middle of the U shape edge
+        let triangles = [
+            pline_closed![(1.0, 9.0, 0.0), (8.0, 10.0, 0.0), (1.0, 12.0, 0.0)],
+            pline_closed![(1.0, 9.0, 0.0), (8.0, 6.0, 0.0), (1.0, 12.0, 0.0)],
+        ];
+        for triangle in triangles.iter() {
+            let stitched = u_shape.boolean(triangle, BooleanOp::Or);
+            assert_eq!(stitched.pos_plines.len(), 1);
+            assert_eq!(stitched.neg_plines.len(), 1);
+            let net_area = stitched.pos_plines[0].pline.area().abs()
+                - stitched.neg_plines[0].pline.area().abs();
+
+            let merged = u_shape.boolean_opt(
+                triangle,
+                BooleanOp::Or,
+                &options(BooleanTouchingMode::Merge, false),
+            );
+            assert_abs_areas(&merged.pos_plines, &[net_area]);
+            assert!(merged.neg_plines.is_empty());
+            assert_eq!(
+                merged.pos_plines[0].subslices.len(),
+                stitched.pos_plines[0].subslices.len() + stitched.neg_plines[0].subslices.len()
+            );
+        }
+    }
+
+    #[test]
+    fn shared_edge_slivers() {
+        let pline1 = square(0.0, 0.0, 10.0);
+        for pline2 in [square(10.0, 0.0, 10.0), square(10.0, 5.0, 10.0)].iter() {
+            // zero area sliver left along the shared edge
+            let result = pline1.boolean(pline2, BooleanOp::Or);
+            assert_abs_areas(&result.pos_plines, &[0.0, 200.0]);
+            let result = pline1.boolean(pline2, BooleanOp::And);
+            assert_abs_areas(&result.pos_plines, &[0.0]);
+
+            let drop_slivers = options(BooleanTouchingMode::AsStitched, true);
+            let result = pline1.boolean_opt(pline2, BooleanOp::Or, &drop_slivers);
+            assert_abs_areas(&result.pos_plines, &[200.0]);
+            let result = pline1.boolean_opt(pline2, BooleanOp::And, &drop_slivers);
+            assert!(result.pos_plines.is_empty());
+            assert!(result.neg_plines.is_empty());
+
+            // regions sharing an edge are not merged
+            let result = pline1.boolean_opt(
+                pline2,
+                BooleanOp::Xor,
+                &options(BooleanTouchingMode::Merge, true),
+            );
+            assert_abs_areas(&result.pos_plines, &[100.0, 100.0]);
+        }
+
+        // sliver is dropped rather than merged into the result loop
+        let result = pline1.boolean_opt(
+            &square(10.0, 0.0, 10.0),
+            BooleanOp::Or,
+            &options(BooleanTouchingMode::Merge, true),
+        );
+        assert_eq!(result.pos_plines.len(), 1);
+        assert_eq!(result.pos_plines[0].pline.len(), 6);
+        assert!(result.pos_plines[0].pline.area().abs().fuzzy_eq(200.0));
+        assert!(result.neg_plines.is_empty());
+    }
+
+    #[test]
+    fn no_touching() {
+        // results without touching regions are unchanged by all the options
+        let pline1 = square(0.0, 0.0, 10.0);
+        let pline2 = pline_closed![(2.0, 5.0, 1.0), (4.0, 5.0, 1.0)];
+        for &op in [
+            BooleanOp::Or,
+            BooleanOp::And,
+            BooleanOp::Not,
+            BooleanOp::Xor,
+        ]
+        .iter()
+        {
+            let expected = pline1.boolean(&pline2, op);
+            for &mode in [BooleanTouchingMode::Merge, BooleanTouchingMode::Separate].iter() {
+                let result = pline1.boolean_opt(&pline2, op, &options(mode, true));
+                assert_eq!(result.pos_plines.len(), expected.pos_plines.len());
+                assert_eq!(result.neg_plines.len(), expected.neg_plines.len());
+                assert!(property_sets_match(
+                    &create_boolean_property_set(&result.pos_plines),
+                    &create_boolean_property_set(&expected.pos_plines)
+                ));
+                assert!(property_sets_match(
+                    &create_boolean_property_set(&result.neg_plines),
+                    &create_boolean_property_set(&expected.neg_plines)
+                ));
+            }
+        }
+    }
+}
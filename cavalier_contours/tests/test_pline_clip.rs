@@ -0,0 +1,203 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{Polyline, Shape},
+};
+use test_utils::square;
+
+/// Expected clipped piece (start point, end point, path length).
+type ExpectedPiece = ((f64, f64), (f64, f64), f64);
+
+/// Assert the pieces match the expected pieces in order.
+fn assert_pieces(pieces: &[Polyline<f64>], expected: &[ExpectedPiece]) {
+    assert_eq!(pieces.len(), expected.len(), "pieces: {:?}", pieces);
+    for (piece, &(start, end, length)) in pieces.iter().zip(expected.iter()) {
+        assert!(!piece.is_closed());
+        let start = Vector2::new(start.0, start.1);
+        let end = Vector2::new(end.0, end.1);
+        assert!(
+            piece[0].pos().fuzzy_eq_eps(start, 1e-5),
+            "start {:?} != {:?}",
+            piece[0].pos(),
+            start
+        );
+        assert!(
+            piece.last().unwrap().pos().fuzzy_eq_eps(end, 1e-5),
+            "end {:?} != {:?}",
+            piece.last().unwrap().pos(),
+            end
+        );
+        assert!(
+            piece.path_length().fuzzy_eq_eps(length, 1e-5),
+            "length {} != {}",
+            piece.path_length(),
+            length
+        );
+    }
+}
+
+#[test]
+fn line_crossing_square() {
+    let line = pline_open![(-5.0, 5.0, 0.0), (15.0, 5.0, 0.0)];
+    assert_pieces(
+        &line.clip_open(&square(0.0, 0.0, 10.0), true),
+        &[((0.0, 5.0), (10.0, 5.0), 10.0)],
+    );
+    assert_pieces(
+        &line.clip_open(&square(0.0, 0.0, 10.0), false),
+        &[
+            ((-5.0, 5.0), (0.0, 5.0), 5.0),
+            ((10.0, 5.0), (15.0, 5.0), 5.0),
+        ],
+    );
+
+    // same result for clockwise region
+    let mut inverted = square(0.0, 0.0, 10.0);
+    inverted.invert_direction();
+    assert_pieces(
+        &line.clip_open(&inverted, true),
+        &[((0.0, 5.0), (10.0, 5.0), 10.0)],
+    );
+
+    // path with vertexes inside and outside the region
+    let zigzag = pline_open![(-5.0, 5.0, 0.0), (5.0, 5.0, 0.0), (5.0, 15.0, 0.0)];
+    assert_pieces(
+        &zigzag.clip_open(&square(0.0, 0.0, 10.0), true),
+        &[((0.0, 5.0), (5.0, 10.0), 10.0)],
+    );
+    assert_pieces(
+        &zigzag.clip_open(&square(0.0, 0.0, 10.0), false),
+        &[
+            ((-5.0, 5.0), (0.0, 5.0), 5.0),
+            ((5.0, 10.0), (5.0, 15.0), 5.0),
+        ],
+    );
+}
+
+#[test]
+fn arcs() {
+    let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    // line passing through the circle vertexes
+    let line = pline_open![(-10.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert_pieces(
+        &line.clip_open(&circle, true),
+        &[((-5.0, 0.0), (5.0, 0.0), 10.0)],
+    );
+    let line = pline_open![(-10.0, 3.0, 0.0), (10.0, 3.0, 0.0)];
+    assert_pieces(
+        &line.clip_open(&circle, true),
+        &[((-4.0, 3.0), (4.0, 3.0), 8.0)],
+    );
+
+    // half circle arc path (centered at (5, 0) with radius 8) crossing the square
+    let arc = pline_open![(-3.0, 0.0, -1.0), (13.0, 0.0, 0.0)];
+    let inside = arc.clip_open(&square(0.0, 0.0, 10.0), true);
+    assert_eq!(inside.len(), 1);
+    let y = 39.0f64.sqrt();
+    assert!(inside[0][0].pos().fuzzy_eq(Vector2::new(0.0, y)));
+    assert!(inside[0]
+        .last()
+        .unwrap()
+        .pos()
+        .fuzzy_eq(Vector2::new(10.0, y)));
+    let outside = arc.clip_open(&square(0.0, 0.0, 10.0), false);
+    assert_eq!(outside.len(), 2);
+    let total: f64 = inside
+        .iter()
+        .chain(outside.iter())
+        .map(|p| p.path_length())
+        .sum();
+    assert!(total.fuzzy_eq(arc.path_length()));
+}
+
+#[test]
+fn overlapping_boundary() {
+    // path running along the bottom edge of the square, boundary is considered inside
+    let line = pline_open![(-5.0, 0.0, 0.0), (15.0, 0.0, 0.0)];
+    assert_pieces(
+        &line.clip_open(&square(0.0, 0.0, 10.0), true),
+        &[((0.0, 0.0), (10.0, 0.0), 10.0)],
+    );
+    assert_pieces(
+        &line.clip_open(&square(0.0, 0.0, 10.0), false),
+        &[
+            ((-5.0, 0.0), (0.0, 0.0), 5.0),
+            ((10.0, 0.0), (15.0, 0.0), 5.0),
+        ],
+    );
+
+    // path entering the region after following an edge stays in one piece
+    let path = pline_open![(5.0, 0.0, 0.0), (10.0, 0.0, 0.0), (5.0, 5.0, 0.0)];
+    let inside = path.clip_open(&square(0.0, 0.0, 10.0), true);
+    assert_eq!(inside.len(), 1);
+    assert!(inside[0].path_length().fuzzy_eq(path.path_length()));
+    assert!(path.clip_open(&square(0.0, 0.0, 10.0), false).is_empty());
+}
+
+#[test]
+fn touching_vertex() {
+    // path touching the corner of the square from outside
+    let path = pline_open![(-5.0, -5.0, 0.0), (0.0, 0.0, 0.0), (-5.0, 5.0, 0.0)];
+    assert!(path.clip_open(&square(0.0, 0.0, 10.0), true).is_empty());
+    let outside = path.clip_open(&square(0.0, 0.0, 10.0), false);
+    assert_eq!(outside.len(), 1);
+    assert!(outside[0].path_length().fuzzy_eq(path.path_length()));
+
+    // path ending on the boundary
+    let path = pline_open![(5.0, 5.0, 0.0), (5.0, 10.0, 0.0)];
+    assert_pieces(
+        &path.clip_open(&square(0.0, 0.0, 10.0), true),
+        &[((5.0, 5.0), (5.0, 10.0), 5.0)],
+    );
+    assert!(path.clip_open(&square(0.0, 0.0, 10.0), false).is_empty());
+}
+
+#[test]
+fn shape_with_hole() {
+    let hole = pline_closed![
+        (4.0, 4.0, 0.0),
+        (6.0, 4.0, 0.0),
+        (6.0, 6.0, 0.0),
+        (4.0, 6.0, 0.0)
+    ];
+    let shape = Shape::from_unoriented_plines(vec![square(0.0, 0.0, 10.0), hole]);
+    let line = pline_open![(-5.0, 5.0, 0.0), (15.0, 5.0, 0.0)];
+    assert_pieces(
+        &shape.clip_open(&line, true),
+        &[
+            ((0.0, 5.0), (4.0, 5.0), 4.0),
+            ((6.0, 5.0), (10.0, 5.0), 4.0),
+        ],
+    );
+    assert_pieces(
+        &shape.clip_open(&line, false),
+        &[
+            ((-5.0, 5.0), (0.0, 5.0), 5.0),
+            ((4.0, 5.0), (6.0, 5.0), 2.0),
+            ((10.0, 5.0), (15.0, 5.0), 5.0),
+        ],
+    );
+}
+
+#[test]
+fn no_intersects() {
+    let line = pline_open![(2.0, 2.0, 0.0), (8.0, 8.0, 0.0)];
+    let inside = line.clip_open(&square(0.0, 0.0, 10.0), true);
+    assert_eq!(inside.len(), 1);
+    assert_eq!(inside[0].len(), 2);
+    assert!(line.clip_open(&square(0.0, 0.0, 10.0), false).is_empty());
+
+    // closed path is clipped as open path starting at the first vertex
+    let closed = pline_closed![(2.0, 2.0, 0.0), (8.0, 2.0, 0.0), (8.0, 8.0, 0.0)];
+    let inside = closed.clip_open(&square(0.0, 0.0, 10.0), true);
+    assert_eq!(inside.len(), 1);
+    assert!(!inside[0].is_closed());
+    assert!(inside[0].path_length().fuzzy_eq(closed.path_length()));
+
+    // open region has no inside
+    let open_region = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    assert!(line.clip_open(&open_region, true).is_empty());
+    assert_eq!(line.clip_open(&open_region, false).len(), 1);
+}
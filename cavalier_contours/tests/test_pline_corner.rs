@@ -0,0 +1,210 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{
+        seg_arc_radius_and_center, seg_length, seg_tangent_vector, PlineCornerError,
+        PlineCornerOptions, PlineVertex, Polyline,
+    },
+};
+use std::f64::consts::PI;
+use test_utils::{create_property_set, property_sets_match, square, PlineProperties};
+
+/// Assert the polyline direction does not change at vertex `k`.
+fn assert_tangent_continuous(pline: &Polyline<f64>, k: usize) {
+    let prev = pline.prev_wrapping_index(k);
+    let next = pline.next_wrapping_index(k);
+    let v = pline[k];
+    let t1 = seg_tangent_vector(pline[prev], v, v.pos()).normalize();
+    let t2 = seg_tangent_vector(v, pline[next], v.pos()).normalize();
+    assert!(
+        t1.fuzzy_eq_eps(t2, 1e-5),
+        "direction changes at vertex {}: {:?} -> {:?}",
+        k,
+        t1,
+        t2
+    );
+}
+
+/// Assert the segment starting at vertex `k` is an arc with `radius`.
+fn assert_arc_radius(pline: &Polyline<f64>, k: usize, radius: f64) {
+    let v1 = pline[k];
+    let v2 = pline[pline.next_wrapping_index(k)];
+    assert!(!v1.bulge_is_zero(), "segment at {} is not an arc", k);
+    let (r, _) = seg_arc_radius_and_center(v1, v2);
+    assert!(r.fuzzy_eq_eps(radius, 1e-5), "radius {} != {}", r, radius);
+}
+
+#[test]
+fn fillet_all_square() {
+    let result = square(0.0, 0.0, 10.0).fillet_all(2.0);
+    assert!(result.skipped.is_empty());
+    assert!(property_sets_match(
+        &create_property_set(vec![&result.polyline], false),
+        &[PlineProperties::new(
+            8,
+            84.0 + 4.0 * PI,
+            24.0 + 4.0 * PI,
+            0.0,
+            0.0,
+            10.0,
+            10.0
+        )]
+    ));
+
+    for k in 0..result.polyline.len() {
+        assert_tangent_continuous(&result.polyline, k);
+    }
+
+    // same result for clockwise direction
+    let mut inverted = square(0.0, 0.0, 10.0);
+    inverted.invert_direction();
+    let result = inverted.fillet_all(2.0);
+    assert!(result.skipped.is_empty());
+    assert!(result.polyline.area().fuzzy_eq(-84.0 - 4.0 * PI));
+}
+
+#[test]
+fn fillet_right_turn() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, -10.0, 0.0)];
+    let result = pline.fillet_vertex(1, 2.0).unwrap();
+    let bulge = -(PI / 8.0).tan();
+    let expected = [
+        (0.0, 0.0, 0.0),
+        (8.0, 0.0, bulge),
+        (10.0, -2.0, 0.0),
+        (10.0, -10.0, 0.0),
+    ];
+    assert_eq!(result.len(), expected.len());
+    for (v, &(x, y, b)) in result.iter().zip(expected.iter()) {
+        assert!(v.fuzzy_eq(PlineVertex::new(x, y, b)));
+    }
+}
+
+#[test]
+fn fillet_line_arc() {
+    // line joins half circle arc (centered at (20, 0) with radius 10) turning right
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (30.0, 0.0, 0.0)];
+    let result = pline.fillet_vertex(1, 2.0).unwrap();
+    assert_eq!(result.len(), 4);
+    assert_arc_radius(&result, 1, 2.0);
+    assert_tangent_continuous(&result, 1);
+    assert_tangent_continuous(&result, 2);
+
+    // fillet center is offset 2 from both the line and the arc
+    let center_x = 20.0 - 140.0f64.sqrt();
+    assert!(result[1].fuzzy_eq(PlineVertex::new(center_x, 0.0, result[1].bulge)));
+    assert!(result[1].bulge < 0.0);
+    let arc_center = Vector2::new(20.0, 0.0);
+    assert!((result[2].pos() - arc_center).length().fuzzy_eq(10.0));
+    assert_arc_radius(&result, 2, 10.0);
+    assert!(result[3].pos().fuzzy_eq(Vector2::new(30.0, 0.0)));
+}
+
+#[test]
+fn fillet_arc_arc() {
+    // lens shape formed by two arcs, corners at both vertexes
+    let lens = pline_closed![(0.0, 0.0, 0.5), (10.0, 0.0, 0.5)];
+    let result = lens.fillet_all(1.0);
+    assert!(result.skipped.is_empty());
+    assert_eq!(result.polyline.len(), 4);
+    let (lens_radius, _) = seg_arc_radius_and_center(lens[0], lens[1]);
+    for k in 0..4 {
+        assert_tangent_continuous(&result.polyline, k);
+        let expected_radius = if k % 2 == 0 { 1.0 } else { lens_radius };
+        assert_arc_radius(&result.polyline, k, expected_radius);
+    }
+    assert!(result.polyline.area() < lens.area());
+}
+
+#[test]
+fn chamfer_arc_corner() {
+    let lens = pline_closed![(0.0, 0.0, 0.5), (10.0, 0.0, 0.5)];
+    let result = lens.chamfer_vertex(0, 1.0).unwrap();
+    assert_eq!(result.len(), 3);
+    // chamfer line, then the trimmed arcs
+    assert!(result[0].bulge_is_zero());
+    assert!(seg_length(result[1], result[2]).fuzzy_eq(seg_length(lens[0], lens[1]) - 1.0));
+    assert!(seg_length(result[2], result[0]).fuzzy_eq(seg_length(lens[1], lens[0]) - 1.0));
+}
+
+#[test]
+fn chamfer_all_square() {
+    let result = square(0.0, 0.0, 10.0).chamfer_all(2.0);
+    assert!(result.skipped.is_empty());
+    assert!(property_sets_match(
+        &create_property_set(&[result.polyline], false),
+        &[PlineProperties::new(
+            8,
+            100.0 - 8.0,
+            24.0 + 8.0 * 2.0f64.sqrt(),
+            0.0,
+            0.0,
+            10.0,
+            10.0
+        )]
+    ));
+
+    // chamfers meeting exactly in the middle of the sides
+    let result = square(0.0, 0.0, 10.0).chamfer_all(5.0);
+    assert!(result.skipped.is_empty());
+    assert_eq!(result.polyline.len(), 4);
+    assert!(result.polyline.area().fuzzy_eq(50.0));
+}
+
+#[test]
+fn angle_threshold() {
+    // 10 degree turn at vertex 1 and sharp corners elsewhere
+    let turn = 10.0f64.to_radians();
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0 + 10.0 * turn.cos(), 10.0 * turn.sin(), 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let options = PlineCornerOptions {
+        angle_threshold: Some(0.5),
+        ..Default::default()
+    };
+    let result = pline.fillet_all_opt(1.0, &options);
+    assert!(result.skipped.is_empty());
+    assert_eq!(result.polyline.len(), 7);
+    assert!(result.polyline[2].pos().fuzzy_eq(Vector2::new(10.0, 0.0)));
+
+    let result = pline.fillet_all(1.0);
+    assert!(result.skipped.is_empty());
+    assert_eq!(result.polyline.len(), 8);
+}
+
+#[test]
+fn corner_errors() {
+    let pline = square(0.0, 0.0, 10.0);
+    assert_eq!(
+        pline.fillet_vertex(4, 1.0).unwrap_err(),
+        PlineCornerError::InvalidIndex { vertex_index: 4 }
+    );
+    assert_eq!(
+        pline.chamfer_vertex(2, 11.0).unwrap_err(),
+        PlineCornerError::SegmentTooShort { vertex_index: 2 }
+    );
+
+    // arc continues tangent from line
+    let smooth = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)];
+    assert_eq!(
+        smooth.fillet_vertex(1, 1.0).unwrap_err(),
+        PlineCornerError::NotACorner { vertex_index: 1 }
+    );
+    assert!(smooth.fillet_all(1.0).skipped.is_empty());
+
+    // counter clockwise arc of radius 1 curving into a left turn corner
+    let tight = pline_open![(0.0, 0.0, 1.0), (2.0, 0.0, 0.0), (0.0, 10.0, 0.0)];
+    assert_eq!(
+        tight.fillet_vertex(1, 2.0).unwrap_err(),
+        PlineCornerError::NoFillet { vertex_index: 1 }
+    );
+
+    // non-positive radius leaves polyline unchanged
+    let result = pline.fillet_vertex(1, 0.0).unwrap();
+    assert_eq!(result.len(), pline.len());
+}
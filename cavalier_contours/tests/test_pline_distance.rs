@@ -0,0 +1,325 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{
+        seg_closest_points, PlineMinDistanceOptions, PlineShapeDistanceOptions, PlineVertex,
+        Polyline,
+    },
+};
+use test_utils::{circle, square};
+
+/// Assert the minimum distance between the polylines is `expected` and the result is consistent
+/// (points lie on their segments and the distance is the same in both directions).
+fn assert_min_distance(pline1: &Polyline<f64>, pline2: &Polyline<f64>, expected: f64) {
+    let result = pline1.min_distance(pline2).unwrap();
+    assert!(
+        result.distance.fuzzy_eq(expected),
+        "distance {} != {}",
+        result.distance,
+        expected
+    );
+    assert!((result.point2 - result.point1).length().fuzzy_eq(expected));
+
+    let on_pline1 = pline1.closest_point(result.point1).unwrap();
+    assert!(on_pline1.distance.fuzzy_eq(0.0));
+    let on_pline2 = pline2.closest_point(result.point2).unwrap();
+    assert!(on_pline2.distance.fuzzy_eq(0.0));
+
+    let i = result.seg_start_index1;
+    let closest = pline1.closest_point(result.point1).unwrap();
+    assert!(
+        closest.seg_start_index == i
+            || closest
+                .seg_point
+                .fuzzy_eq(pline1[pline1.next_wrapping_index(i)].pos())
+            || closest.seg_point.fuzzy_eq(pline1[i].pos())
+    );
+
+    let reversed = pline2.min_distance(pline1).unwrap();
+    assert!(reversed.distance.fuzzy_eq(expected));
+}
+
+#[test]
+fn lines() {
+    let line1 = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let line2 = pline_open![(2.0, 3.0, 0.0), (8.0, 3.0, 0.0)];
+    assert_min_distance(&line1, &line2, 3.0);
+
+    let line2 = pline_open![(12.0, 4.0, 0.0), (20.0, 10.0, 0.0)];
+    assert_min_distance(&line1, &line2, 20.0f64.sqrt());
+    let result = line1.min_distance(&line2).unwrap();
+    assert!(result.point1.fuzzy_eq(Vector2::new(10.0, 0.0)));
+    assert!(result.point2.fuzzy_eq(Vector2::new(12.0, 4.0)));
+
+    // crossing
+    let line2 = pline_open![(5.0, -5.0, 0.0), (5.0, 5.0, 0.0)];
+    assert_min_distance(&line1, &line2, 0.0);
+    let result = line1.min_distance(&line2).unwrap();
+    assert!(result.point1.fuzzy_eq(Vector2::new(5.0, 0.0)));
+}
+
+#[test]
+fn lines_and_arcs() {
+    // arc bulging up towards the line above it
+    let line = pline_open![(0.0, 3.0, 0.0), (4.0, 3.0, 0.0)];
+    let arc = pline_open![(0.0, 0.0, -1.0), (4.0, 0.0, 0.0)];
+    assert_min_distance(&line, &arc, 1.0);
+    let result = line.min_distance(&arc).unwrap();
+    assert!(result.point1.fuzzy_eq(Vector2::new(2.0, 3.0)));
+    assert!(result.point2.fuzzy_eq(Vector2::new(2.0, 2.0)));
+
+    // arc bulging away from the line
+    let arc = pline_open![(0.0, 0.0, 1.0), (4.0, 0.0, 0.0)];
+    assert_min_distance(&line, &arc, 3.0);
+
+    // square around a circle
+    let square = pline_closed![
+        (-5.0, -5.0, 0.0),
+        (5.0, -5.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (-5.0, 5.0, 0.0)
+    ];
+    assert_min_distance(&square, &circle(1.0, 0.0, 2.0), 2.0);
+    assert_min_distance(&square, &circle(0.0, 0.0, 5.0), 0.0);
+}
+
+#[test]
+fn arcs() {
+    assert_min_distance(&circle(0.0, 0.0, 1.0), &circle(5.0, 0.0, 2.0), 2.0);
+    let result = circle(0.0, 0.0, 1.0)
+        .min_distance(&circle(5.0, 0.0, 2.0))
+        .unwrap();
+    assert!(result.point1.fuzzy_eq(Vector2::new(1.0, 0.0)));
+    assert!(result.point2.fuzzy_eq(Vector2::new(3.0, 0.0)));
+
+    // circle inside circle
+    assert_min_distance(&circle(0.0, 0.0, 5.0), &circle(0.0, 0.0, 2.0), 3.0);
+    assert_min_distance(
+        &circle(0.0, 0.0, 5.0),
+        &circle(1.0, 1.0, 2.0),
+        3.0 - 2.0f64.sqrt(),
+    );
+
+    // closest points within both arcs (not at end points)
+    let v1 = PlineVertex::new(-1.0, 1.0, -0.5);
+    let v2 = PlineVertex::new(1.0, 1.0, 0.0);
+    let u1 = PlineVertex::new(1.0, 4.0, -0.5);
+    let u2 = PlineVertex::new(-1.0, 4.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2);
+    assert!(p1.x.fuzzy_eq(0.0));
+    assert!(p2.x.fuzzy_eq(0.0));
+    assert!(p1.y > 1.0);
+    assert!(p2.y < 4.0);
+}
+
+#[test]
+fn degenerate_inputs() {
+    let empty = Polyline::<f64>::new();
+    let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(empty.min_distance(&line).is_none());
+    assert!(line.min_distance(&empty).is_none());
+
+    let point = pline_open![(5.0, 5.0, 0.0)];
+    assert_min_distance(&point, &line, 5.0);
+    let result = line.min_distance(&point).unwrap();
+    assert!(result.point1.fuzzy_eq(Vector2::new(5.0, 0.0)));
+    assert!(result.point2.fuzzy_eq(Vector2::new(5.0, 5.0)));
+    assert_eq!(result.seg_start_index2, 0);
+
+    // existing spatial index
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let index = square.create_aabb_index().unwrap();
+    let options = PlineMinDistanceOptions {
+        pline1_aabb_index: Some(&index),
+    };
+    let other = pline_open![
+        (30.0, 0.0, 0.0),
+        (30.0, 20.0, 0.0),
+        (16.0, 8.0, 0.0),
+        (14.0, 4.0, 0.0)
+    ];
+    let result = square.min_distance_opt(&other, &options).unwrap();
+    assert!(result.distance.fuzzy_eq(4.0));
+    assert_eq!(result.seg_start_index1, 1);
+    assert!(result.point1.fuzzy_eq(Vector2::new(10.0, 4.0)));
+    assert_eq!(result.seg_start_index2, 2);
+}
+
+/// Assert the witness points of a shape distance result lie on their polylines and segments.
+fn assert_witness_on_plines(
+    pline1: &Polyline<f64>,
+    pline2: &Polyline<f64>,
+    seg_start_index1: usize,
+    point1: Vector2<f64>,
+    seg_start_index2: usize,
+    point2: Vector2<f64>,
+) {
+    for (pline, i, p) in [
+        (pline1, seg_start_index1, point1),
+        (pline2, seg_start_index2, point2),
+    ] {
+        let mut seg = Polyline::new();
+        seg.add_vertex(pline[i]);
+        seg.add_vertex(pline[pline.next_wrapping_index(i)]);
+        assert!(seg
+            .closest_point(p)
+            .unwrap()
+            .distance
+            .fuzzy_eq_eps(0.0, 1e-9));
+    }
+}
+
+#[test]
+fn hausdorff_same_shape() {
+    // different start index and extra collinear vertexes
+    let square1 = square(0.0, 0.0, 10.0);
+    let square2 = pline_closed![
+        (10.0, 5.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0)
+    ];
+    let result = square1.hausdorff_distance(&square2).unwrap();
+    assert!(result.distance.fuzzy_eq(0.0));
+
+    // circle split into 4 arcs going in the opposite direction
+    let b = (std::f64::consts::PI / 8.0).tan();
+    let circle2 = pline_closed![
+        (0.0, 5.0, -b),
+        (5.0, 0.0, -b),
+        (0.0, -5.0, -b),
+        (-5.0, 0.0, -b)
+    ];
+    let result = circle(0.0, 0.0, 5.0).hausdorff_distance(&circle2).unwrap();
+    assert!(result.distance.fuzzy_eq(0.0));
+}
+
+#[test]
+fn hausdorff_offset_shapes() {
+    let circle1 = circle(0.0, 0.0, 5.0);
+    let circle2 = circle(0.0, 0.0, 6.0);
+    let result = circle1.hausdorff_distance(&circle2).unwrap();
+    assert!(result.distance.fuzzy_eq(1.0));
+    assert!((result.point2 - result.point1).length().fuzzy_eq(1.0));
+    assert_witness_on_plines(
+        &circle1,
+        &circle2,
+        result.seg_start_index1,
+        result.point1,
+        result.seg_start_index2,
+        result.point2,
+    );
+
+    let result = circle1.hausdorff_distance(&circle(1.0, 0.0, 5.0)).unwrap();
+    assert!(result.distance.fuzzy_eq_eps(1.0, 1e-6));
+
+    // rounded outward offset is 1 away everywhere in both directions
+    let square1 = square(0.0, 0.0, 10.0);
+    let offset = square1.parallel_offset(-1.0).remove(0);
+    let result = square1.hausdorff_distance(&offset).unwrap();
+    assert!(result.distance.fuzzy_eq(1.0));
+    let result = offset.hausdorff_distance(&square1).unwrap();
+    assert!(result.distance.fuzzy_eq(1.0));
+    assert_witness_on_plines(
+        &offset,
+        &square1,
+        result.seg_start_index1,
+        result.point1,
+        result.seg_start_index2,
+        result.point2,
+    );
+}
+
+#[test]
+fn hausdorff_directed() {
+    let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let short_line = pline_open![(2.0, 1.0, 0.0), (8.0, 1.0, 0.0)];
+    let result = short_line.directed_hausdorff_distance(&line).unwrap();
+    assert!(result.distance.fuzzy_eq(1.0));
+
+    let result = line.directed_hausdorff_distance(&short_line).unwrap();
+    assert!(result.distance.fuzzy_eq(5.0f64.sqrt()));
+    assert!(
+        result.point1.fuzzy_eq(Vector2::new(0.0, 0.0))
+            || result.point1.fuzzy_eq(Vector2::new(10.0, 0.0))
+    );
+
+    // symmetric result keeps the witness on each polyline
+    let result = short_line.hausdorff_distance(&line).unwrap();
+    assert!(result.distance.fuzzy_eq(5.0f64.sqrt()));
+    assert!(result.point1.y.fuzzy_eq(1.0));
+    assert!(result.point2.y.fuzzy_eq(0.0));
+
+    // farthest point is in the middle of an arc between coarse samples
+    let arc = pline_open![(0.0, 0.0, -1.0), (4.0, 0.0, 0.0)];
+    let line = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+    let options = PlineShapeDistanceOptions {
+        sample_spacing: Some(0.7),
+    };
+    let result = arc
+        .directed_hausdorff_distance_opt(&line, &options)
+        .unwrap();
+    assert!(result.distance.fuzzy_eq_eps(2.0, 1e-6));
+    assert!(result.point1.fuzzy_eq_eps(Vector2::new(2.0, 2.0), 1e-3));
+    assert!(result.point2.fuzzy_eq_eps(Vector2::new(2.0, 0.0), 1e-3));
+
+    let empty = Polyline::<f64>::new();
+    assert!(empty.hausdorff_distance(&line).is_none());
+    assert!(line.directed_hausdorff_distance(&empty).is_none());
+    assert!(line.frechet_distance(&empty).is_none());
+}
+
+#[test]
+fn frechet() {
+    let options = PlineShapeDistanceOptions {
+        sample_spacing: Some(0.01),
+    };
+
+    let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let split_line = pline_open![(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let result = line.frechet_distance_opt(&split_line, &options).unwrap();
+    assert!(result.distance < 0.01);
+
+    let mut reversed = line.clone();
+    reversed.invert_direction();
+    let result = line.frechet_distance_opt(&reversed, &options).unwrap();
+    assert!(result.distance.fuzzy_eq(10.0));
+
+    // path going back on itself has a small Hausdorff distance but large Fréchet distance
+    let zig_zag = pline_open![
+        (0.0, 1.0, 0.0),
+        (8.0, 1.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (10.0, 1.0, 0.0)
+    ];
+    let hausdorff = line.hausdorff_distance_opt(&zig_zag, &options).unwrap();
+    let frechet = line.frechet_distance_opt(&zig_zag, &options).unwrap();
+    assert!(hausdorff.distance.fuzzy_eq(1.0));
+    assert!(frechet.distance > 3.0);
+    assert!((frechet.point2 - frechet.point1)
+        .length()
+        .fuzzy_eq(frechet.distance));
+    assert_witness_on_plines(
+        &line,
+        &zig_zag,
+        frechet.seg_start_index1,
+        frechet.point1,
+        frechet.seg_start_index2,
+        frechet.point2,
+    );
+
+    // concentric circles
+    let result = circle(0.0, 0.0, 5.0)
+        .frechet_distance_opt(&circle(0.0, 0.0, 6.0), &options)
+        .unwrap();
+    assert!(result.distance.fuzzy_eq_eps(1.0, 0.01));
+}
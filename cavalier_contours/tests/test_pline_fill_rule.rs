@@ -0,0 +1,207 @@
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{
+        internal::pline_intersects::all_self_intersects_as_basic, FillRule, PlineOpError,
+        PlineOrientation, Polyline,
+    },
+};
+use std::f64::consts::PI;
+
+/// Assert all the resolved polylines are counter clockwise or clockwise (holes) and free of self
+/// intersects, returning the total area.
+fn assert_simple_loops(result: &[Polyline<f64>]) -> f64 {
+    for pline in result.iter() {
+        assert!(pline.is_closed());
+        let index = pline.create_approx_aabb_index().unwrap();
+        let intrs = all_self_intersects_as_basic(pline, &index, 1e-5);
+        assert!(intrs.is_empty(), "self intersects in {:?}", pline);
+    }
+    result.iter().map(|p| p.area()).sum()
+}
+
+#[test]
+fn bow_tie() {
+    let bow_tie = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+
+    for &rule in [FillRule::NonZero, FillRule::EvenOdd].iter() {
+        let result = bow_tie.resolve_self_intersects(rule);
+        assert_eq!(result.len(), 2);
+        assert!(assert_simple_loops(&result).fuzzy_eq(50.0));
+        for pline in result.iter() {
+            assert_eq!(pline.len(), 3);
+            assert_eq!(pline.orientation(), PlineOrientation::CounterClockwise);
+        }
+    }
+
+    // left triangle is counter clockwise and right triangle is clockwise
+    let result = bow_tie.resolve_self_intersects(FillRule::Positive);
+    assert_eq!(result.len(), 1);
+    assert!(assert_simple_loops(&result).fuzzy_eq(25.0));
+    assert!(result[0].extents().unwrap().max_x.fuzzy_eq(5.0));
+
+    let result = bow_tie.resolve_self_intersects(FillRule::Negative);
+    assert_eq!(result.len(), 1);
+    assert!(assert_simple_loops(&result).fuzzy_eq(25.0));
+    assert!(result[0].extents().unwrap().min_x.fuzzy_eq(5.0));
+}
+
+#[test]
+fn pentagram() {
+    let mut star = Polyline::new_closed();
+    for i in 0..5 {
+        let angle = PI / 2.0 + (i as f64) * 4.0 * PI / 5.0;
+        star.add(10.0 * angle.cos(), 10.0 * angle.sin(), 0.0);
+    }
+    // center pentagon has winding number 2 and the star points have winding number 1
+    let star_winding_area = star.area();
+
+    let non_zero = star.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(non_zero.len(), 1);
+    assert_eq!(non_zero[0].len(), 10);
+    let non_zero_area = assert_simple_loops(&non_zero);
+
+    // points of the star all touch at the center pentagon vertexes but are kept as separate loops
+    let even_odd = star.resolve_self_intersects(FillRule::EvenOdd);
+    assert_eq!(even_odd.len(), 5);
+    assert!(even_odd.iter().all(|p| p.len() == 3));
+    let even_odd_area = assert_simple_loops(&even_odd);
+
+    // star winding area = points + 2 * pentagon, non zero area = points + pentagon, even odd area =
+    // points
+    assert!((2.0 * non_zero_area - even_odd_area).fuzzy_eq(star_winding_area));
+
+    let positive = star.resolve_self_intersects(FillRule::Positive);
+    assert_eq!(positive.len(), 1);
+    assert!(assert_simple_loops(&positive).fuzzy_eq(non_zero_area));
+
+    assert!(star.resolve_self_intersects(FillRule::Negative).is_empty());
+}
+
+#[test]
+fn figure_eight_with_arcs() {
+    // crossing lines joined by half circle arcs bulging outward on the left and right
+    let figure_eight = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 10.0, -1.0),
+        (10.0, 0.0, 0.0),
+        (0.0, 10.0, 1.0)
+    ];
+    let lobe_area = 25.0 + 12.5 * PI;
+
+    let result = figure_eight.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(result.len(), 2);
+    assert!(assert_simple_loops(&result).fuzzy_eq(2.0 * lobe_area));
+    for pline in result.iter() {
+        assert!(pline.area().fuzzy_eq(lobe_area));
+        assert!(pline
+            .iter()
+            .any(|v| v.pos().fuzzy_eq_eps(Vector2::new(5.0, 5.0), 1e-5)));
+    }
+}
+
+#[test]
+fn overlapping_segments() {
+    // spike going out from the square and straight back along itself
+    let spike = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (5.0, 15.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let result = spike.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(result.len(), 1);
+    assert!(assert_simple_loops(&result).fuzzy_eq(100.0));
+    assert!(result[0].extents().unwrap().max_y.fuzzy_eq(10.0));
+
+    // clockwise inner square joined to the outer square by a bridge going in and back out
+    let bridged = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 5.0, 0.0),
+        (7.0, 5.0, 0.0),
+        (7.0, 3.0, 0.0),
+        (3.0, 3.0, 0.0),
+        (3.0, 7.0, 0.0),
+        (7.0, 7.0, 0.0),
+        (7.0, 5.0, 0.0),
+        (10.0, 5.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let mut result = bridged.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(result.len(), 2);
+    assert!(assert_simple_loops(&result).fuzzy_eq(84.0));
+    result.sort_by(|a, b| b.area().partial_cmp(&a.area()).unwrap());
+    assert!(result[0].area().fuzzy_eq(100.0));
+    assert!(result[1].area().fuzzy_eq(-16.0));
+
+    // square traversed twice in the same direction
+    let twice = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let result = twice.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].len(), 4);
+    assert!(assert_simple_loops(&result).fuzzy_eq(100.0));
+    assert!(twice.resolve_self_intersects(FillRule::EvenOdd).is_empty());
+}
+
+#[test]
+fn simple_loops() {
+    let mut square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    square.invert_direction();
+
+    let result = square.resolve_self_intersects(FillRule::NonZero);
+    assert_eq!(result.len(), 1);
+    assert!(result[0].area().fuzzy_eq(100.0));
+    assert!(square
+        .resolve_self_intersects(FillRule::Positive)
+        .is_empty());
+    assert_eq!(square.resolve_self_intersects(FillRule::Negative).len(), 1);
+
+    let open = pline_open![(0.0, 0.0, 0.0), (10.0, 10.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(open.resolve_self_intersects(FillRule::NonZero).is_empty());
+}
+
+#[test]
+fn try_resolve() {
+    let bow_tie = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let result = bow_tie
+        .try_resolve_self_intersects(FillRule::NonZero)
+        .unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(assert_simple_loops(&result).fuzzy_eq(50.0));
+
+    let open = pline_open![(0.0, 0.0, 0.0), (10.0, 10.0, 0.0), (10.0, 0.0, 0.0)];
+    assert_eq!(
+        open.try_resolve_self_intersects(FillRule::NonZero)
+            .unwrap_err(),
+        PlineOpError::OpenInput { input: 0 }
+    );
+}
@@ -0,0 +1,296 @@
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::Polyline,
+};
+use std::f64::consts::PI;
+
+#[test]
+fn point_and_tangent_at_length() {
+    // line, ccw half circle (radius 2), then cw quarter circle (radius 2)
+    let quarter_bulge = (PI / 8.0).tan();
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 4.0, -quarter_bulge),
+        (8.0, 6.0, 0.0)
+    ];
+    let table = pline.length_table();
+    assert!(table.total_length().fuzzy_eq(pline.path_length()));
+    assert!(table.total_length().fuzzy_eq(10.0 + 3.0 * PI));
+    assert!(table.length_at_vertex(2).fuzzy_eq(10.0 + 2.0 * PI));
+
+    let cases = [
+        (0.0, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)),
+        (3.7, Vector2::new(3.7, 0.0), Vector2::new(1.0, 0.0)),
+        // vertex resolves to the segment starting at it
+        (10.0, Vector2::new(10.0, 0.0), Vector2::new(1.0, 0.0)),
+        (10.0 + PI, Vector2::new(12.0, 2.0), Vector2::new(0.0, 1.0)),
+        (
+            10.0 + 2.0 * PI,
+            Vector2::new(10.0, 4.0),
+            Vector2::new(-1.0, 0.0),
+        ),
+        (
+            10.0 + 2.5 * PI,
+            Vector2::new(10.0 - 2.0f64.sqrt(), 4.0 + 2.0 - 2.0f64.sqrt()),
+            Vector2::new(-1.0, 1.0).normalize(),
+        ),
+        (
+            10.0 + 3.0 * PI,
+            Vector2::new(8.0, 6.0),
+            Vector2::new(0.0, 1.0),
+        ),
+    ];
+
+    for &(length, point, tangent) in cases.iter() {
+        let p = table.point_at_length(length).unwrap();
+        assert!(
+            p.fuzzy_eq(point),
+            "length {}: {:?} != {:?}",
+            length,
+            p,
+            point
+        );
+        let t = table.tangent_at_length(length).unwrap();
+        assert!(
+            t.fuzzy_eq(tangent),
+            "length {}: {:?} != {:?}",
+            length,
+            t,
+            tangent
+        );
+        assert!(pline.point_at_length(length).unwrap().fuzzy_eq(point));
+        assert!(pline.tangent_at_length(length).unwrap().fuzzy_eq(tangent));
+    }
+
+    let (seg_index, seg_offset) = table.seg_at_length(10.0 + 2.5 * PI).unwrap();
+    assert_eq!(seg_index, 2);
+    assert!(seg_offset.fuzzy_eq(0.5 * PI));
+
+    // clamped to the path
+    assert!(table
+        .point_at_length(-1.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(table
+        .point_at_length(100.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(8.0, 6.0)));
+    assert_eq!(table.seg_at_length(100.0).unwrap().0, 2);
+}
+
+#[test]
+fn closed_polyline() {
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let table = square.length_table();
+    assert!(table.total_length().fuzzy_eq(40.0));
+    assert!(table
+        .point_at_length(35.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, 5.0)));
+    assert!(table
+        .tangent_at_length(35.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, -1.0)));
+    // end of the path is back at the start along the closing segment
+    assert_eq!(table.seg_at_length(40.0).unwrap().0, 3);
+    assert!(table
+        .point_at_length(40.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(table
+        .tangent_at_length(40.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, -1.0)));
+    assert!(table
+        .length_at_point(3, Vector2::new(0.0, 2.0))
+        .fuzzy_eq(38.0));
+}
+
+#[test]
+fn length_at_point_round_trip() {
+    let plines: Vec<Polyline<f64>> = vec![
+        pline_open![
+            (0.0, 0.0, 0.5),
+            (10.0, 0.0, -0.8),
+            (10.0, 10.0, 0.0),
+            (0.0, 8.0, 0.0)
+        ],
+        pline_closed![(0.0, 0.0, -1.0), (6.0, 0.0, -1.0)],
+        pline_closed![(0.0, 0.0, 0.3), (5.0, -1.0, 0.0), (4.0, 6.0, -0.4)],
+    ];
+
+    for pline in plines.iter() {
+        let table = pline.length_table();
+        let total = table.total_length();
+        for i in 0..=20 {
+            let length = total * (i as f64) / 20.0;
+            let point = table.point_at_length(length).unwrap();
+            let closest = pline.closest_point(point).unwrap();
+            assert!(closest.distance.fuzzy_eq(0.0));
+            let (seg_index, _) = table.seg_at_length(length).unwrap();
+            let result = table.length_at_point(seg_index, point);
+            assert!(result.fuzzy_eq(length), "{} != {}", result, length);
+            assert!(pline.length_at_point(seg_index, point).fuzzy_eq(length));
+        }
+    }
+}
+
+#[test]
+fn degenerate_inputs() {
+    let empty = Polyline::<f64>::new();
+    assert!(empty.point_at_length(0.0).is_none());
+    assert!(empty.length_table().seg_at_length(0.0).is_none());
+    assert!(empty.length_table().total_length().fuzzy_eq(0.0));
+
+    let point = pline_open![(1.0, 1.0, 0.0)];
+    assert!(point.point_at_length(0.0).is_none());
+    assert!(point.tangent_at_length(0.0).is_none());
+
+    // repeat position vertexes are skipped
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (5.0, 5.0, 0.0)
+    ];
+    let table = pline.length_table();
+    assert_eq!(table.seg_at_length(5.0).unwrap().0, 2);
+    assert!(table
+        .tangent_at_length(5.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, 1.0)));
+    assert_eq!(table.seg_at_length(10.0).unwrap().0, 2);
+    assert!(table
+        .tangent_at_length(10.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(0.0, 1.0)));
+
+    let zero_length = pline_open![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    assert!(zero_length
+        .point_at_length(1.0)
+        .unwrap()
+        .fuzzy_eq(Vector2::new(1.0, 1.0)));
+    assert!(zero_length.tangent_at_length(1.0).is_none());
+}
+
+#[test]
+fn resample_by_distance() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    let total = 10.0 + 2.0 * PI;
+    let stations: Vec<_> = pline.resample_by_distance(1.5).collect();
+    assert_eq!(stations.len(), (total / 1.5).floor() as usize + 1);
+    for (i, s) in stations.iter().enumerate() {
+        assert!(s.length.fuzzy_eq(1.5 * i as f64));
+        assert!(pline.closest_point(s.point).unwrap().distance.fuzzy_eq(0.0));
+        assert!(s.tangent.length().fuzzy_eq(1.0));
+        assert!(pline
+            .length_at_point(s.seg_start_index, s.point)
+            .fuzzy_eq(s.length));
+    }
+
+    // end included when the path length is a multiple of the step
+    let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let stations: Vec<_> = line.resample_by_distance(2.5).collect();
+    assert_eq!(stations.len(), 5);
+    assert!(stations[4].point.fuzzy_eq(Vector2::new(10.0, 0.0)));
+
+    // closed polylines spacing is adjusted to be even across the seam
+    let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let stations: Vec<_> = circle.resample_by_distance(3.0).collect();
+    let n = (10.0 * PI / 3.0).round();
+    assert_eq!(stations.len(), n as usize);
+    assert!(stations[0].point.fuzzy_eq(Vector2::new(0.0, 0.0)));
+    let chord = (stations[1].point - stations[0].point).length();
+    let seam_chord = (stations[0].point - stations.last().unwrap().point).length();
+    assert!(chord.fuzzy_eq(seam_chord));
+    assert!((chord / 10.0).asin().fuzzy_eq(PI / n));
+
+    assert_eq!(line.resample_by_distance(0.0).count(), 0);
+    assert_eq!(line.resample_by_distance(-1.0).count(), 0);
+    assert_eq!(line.resample_by_distance(f64::INFINITY).count(), 0);
+    assert_eq!(line.resample_by_distance(f64::NAN).count(), 0);
+    assert_eq!(Polyline::<f64>::new().resample_by_distance(1.0).count(), 0);
+}
+
+#[test]
+fn divide() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    let total = 10.0 + 2.0 * PI;
+    let stations = pline.divide(7);
+    assert_eq!(stations.len(), 8);
+    for (i, s) in stations.iter().enumerate() {
+        assert!(s.length.fuzzy_eq(total * i as f64 / 7.0));
+    }
+    assert!(stations[7].point.fuzzy_eq(Vector2::new(10.0, 4.0)));
+    assert!(stations[7].tangent.fuzzy_eq(Vector2::new(-1.0, 0.0)));
+
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let stations = square.divide(8);
+    assert_eq!(stations.len(), 8);
+    assert!(stations[7].point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+    assert!(stations[7].tangent.fuzzy_eq(Vector2::new(0.0, -1.0)));
+
+    assert!(pline.divide(0).is_empty());
+    assert!(pline_open![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)]
+        .divide(2)
+        .is_empty());
+}
+
+#[test]
+fn insert_stations() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    let stations = pline.divide(4);
+    let result = pline.insert_stations(&stations);
+    assert!(!result.is_closed());
+    // end stations are on top of existing vertexes
+    assert_eq!(result.len(), 3 + 3);
+    assert!(result.path_length().fuzzy_eq(pline.path_length()));
+    for s in stations.iter() {
+        assert!(result.iter().any(|v| v.pos().fuzzy_eq(s.point)));
+    }
+    let deviation = result.hausdorff_distance(&pline).unwrap();
+    assert!(deviation.distance.fuzzy_eq(0.0));
+
+    // closed polyline with stations at the seam and at vertexes given in any order
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let mut stations = square.divide(8);
+    stations.reverse();
+    let result = square.insert_stations(&stations);
+    assert!(result.is_closed());
+    assert_eq!(result.len(), 8);
+    assert!(result[0].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(result[7].pos().fuzzy_eq(Vector2::new(0.0, 5.0)));
+    assert!(result.area().fuzzy_eq(100.0));
+
+    // station at the end of the closing segment
+    let table = square.length_table();
+    let mut seam = table.station_at_length(40.0).unwrap();
+    assert_eq!(seam.seg_start_index, 3);
+    seam.point = Vector2::new(0.0, 0.0);
+    let result = square.insert_stations(&[seam]);
+    assert_eq!(result.len(), 4);
+
+    let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let result = circle.insert_stations(&circle.divide(6));
+    assert_eq!(result.len(), 6);
+    assert!(result.area().fuzzy_eq(circle.area()));
+    assert!(result.path_length().fuzzy_eq(circle.path_length()));
+}
@@ -1,9 +1,9 @@
 mod test_utils;
 
-use cavalier_contours::polyline::{PlineOffsetOptions, Polyline};
+use cavalier_contours::polyline::{OffsetJoinStyle, PlineOffsetOptions, Polyline};
 use test_utils::{
-    create_property_set, property_sets_match, ModifiedPlineSet, ModifiedPlineSetVisitor,
-    ModifiedPlineState, PlineProperties,
+    create_property_set, property_sets_match, rect, square, ModifiedPlineSet,
+    ModifiedPlineSetVisitor, ModifiedPlineState, PlineProperties,
 };
 
 fn offset_into_properties_set(
@@ -11,10 +11,12 @@ fn offset_into_properties_set(
     offset: f64,
     inverted: bool,
     handle_self_intersects: bool,
+    join_style: OffsetJoinStyle<f64>,
 ) -> Vec<PlineProperties> {
     let offset = if inverted { -offset } else { offset };
     let options = PlineOffsetOptions {
         handle_self_intersects,
+        join_style,
         ..Default::default()
     };
     let offset_results = polyline.parallel_offset_opt(offset, &options);
@@ -32,6 +34,7 @@ struct PlineOffsetTestVisitor<'a> {
     offset: f64,
     expected_properties_set: &'a [PlineProperties],
     handle_self_intersects: bool,
+    join_style: OffsetJoinStyle<f64>,
 }
 
 impl<'a> ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'a> {
@@ -41,6 +44,7 @@ impl<'a> ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'a> {
             self.offset,
             pline_state.inverted_direction,
             self.handle_self_intersects,
+            self.join_style,
         );
         assert!(
             property_sets_match(&offset_results, self.expected_properties_set),
@@ -54,6 +58,7 @@ impl<'a> ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'a> {
                 self.offset,
                 pline_state.inverted_direction,
                 true,
+                self.join_style,
             );
             assert!(
             property_sets_match(&offset_results, self.expected_properties_set),
@@ -69,11 +74,13 @@ fn run_pline_offset_tests(
     offset: f64,
     expected_properties_set: &[PlineProperties],
     handle_self_intersects: bool,
+    join_style: OffsetJoinStyle<f64>,
 ) {
     let mut visitor = PlineOffsetTestVisitor {
         offset,
         expected_properties_set,
         handle_self_intersects,
+        join_style,
     };
 
     let test_set = ModifiedPlineSet::new(input, true, true);
@@ -86,7 +93,7 @@ macro_rules! declare_offset_tests {
             #[test]
             fn $name() {
                 $(
-                    run_pline_offset_tests(&$value.0, $value.1, &$expected, false);
+                    run_pline_offset_tests(&$value.0, $value.1, &$expected, false, OffsetJoinStyle::Round);
                 )+
             }
         )+
@@ -99,7 +106,21 @@ macro_rules! declare_self_intersecting_offset_tests {
             #[test]
             fn $name() {
                 $(
-                    run_pline_offset_tests(&$value.0, $value.1, &$expected, true);
+                    run_pline_offset_tests(&$value.0, $value.1, &$expected, true, OffsetJoinStyle::Round);
+                )+
+            }
+        )+
+    };
+}
+
+macro_rules! declare_join_style_offset_tests {
+    ($($name:ident { $($value:expr => $expected:expr),+ $(,)? })*) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    let (input, offset, join_style): (Polyline, f64, OffsetJoinStyle<f64>) = $value;
+                    run_pline_offset_tests(&input, offset, &$expected, false, join_style);
                 )+
             }
         )+
@@ -606,3 +627,450 @@ mod test_past_failures {
 // (4578.8050878621025, 6656.675671873701, 0.0),
 // (4578.805231182584, 6656.675569740822, 0.0)], 3.0) =>
 //                     [PlineProperties::new(6, 0.0, 5639.266054391041, -736.2155311168141, 6659.118694762635, 4580.546248163486, 8200.360349202138)]
+
+/// Test cases for parallel offset using miter and bevel join styles.
+mod test_join_style {
+    use super::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    declare_join_style_offset_tests!(
+        closed_rectangle_outward_miter {
+            (pline_closed![ (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, OffsetJoinStyle::Miter { limit: 2.0 }) =>
+            [PlineProperties::new(4, 336.0, 76.0, -2.0, -2.0, 22.0, 12.0)]
+        }
+        closed_rectangle_outward_bevel {
+            (pline_closed![ (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, OffsetJoinStyle::Bevel) =>
+            [PlineProperties::new(8, 328.0, 71.313708498985, -2.0, -2.0, 22.0, 12.0)]
+        }
+        closed_rectangle_outward_miter_limit_exceeded {
+            (pline_closed![ (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, OffsetJoinStyle::Miter { limit: 1.2 }) =>
+            [PlineProperties::new(8, 328.0, 71.313708498985, -2.0, -2.0, 22.0, 12.0)]
+        }
+        closed_rectangle_inward_miter {
+            (pline_closed![ (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], 2.0, OffsetJoinStyle::Miter { limit: 2.0 }) =>
+            [PlineProperties::new(4, 96.0, 44.0, 2.0, 2.0, 18.0, 8.0)]
+        }
+        open_rectangle_outward_miter {
+            (pline_open![ (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0) ], -2.0, OffsetJoinStyle::Miter { limit: 2.0 }) =>
+            [PlineProperties::new(5, 0.0, 72.0, -2.0, -2.0, 22.0, 12.0)]
+        }
+        closed_slot_outward_miter {
+            // offset of slot sides overlap and must be trimmed
+            (pline_closed![ (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (6.0, 10.0, 0.0), (6.0, 2.0, 0.0), (4.0, 2.0, 0.0), (4.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, OffsetJoinStyle::Miter { limit: 2.0 }) =>
+            [PlineProperties::new(4, 196.0, 56.0, -2.0, -2.0, 12.0, 12.0)]
+        }
+        closed_slot_outward_bevel {
+            // bevel joins at the top of the slot cross each other leaving a notch
+            (pline_closed![ (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (6.0, 10.0, 0.0), (6.0, 2.0, 0.0), (4.0, 2.0, 0.0), (4.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, OffsetJoinStyle::Bevel) =>
+            [PlineProperties::new(11, 187.0, 52.142135623731, -2.0, -2.0, 12.0, 12.0)]
+        }
+        closed_arcs_outward_miter {
+            // half circle capped rectangle joined with lines at tangent, no corners to miter
+            (pline_closed![ (0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0), (0.0, 10.0, 1.0) ], -2.0, OffsetJoinStyle::Miter { limit: 2.0 }) =>
+            [PlineProperties::new(4, 293.9380400259, 63.982297150257, -7.0, -2.0, 17.0, 12.0)]
+        }
+    );
+}
+
+mod test_offset_source {
+    use super::*;
+    use cavalier_contours::{
+        core::{math::dist_squared, traits::FuzzyEq},
+        pline_closed, pline_open,
+        polyline::{seg_closest_point, seg_midpoint, OffsetSegSource},
+    };
+
+    /// Check every offset segment lies at the offset distance from its source segment or is a
+    /// join centered at its source vertex, returns (segment source count, join source count).
+    fn check_sources(
+        input: &Polyline<f64>,
+        offset: f64,
+        join_style: OffsetJoinStyle<f64>,
+    ) -> (usize, usize) {
+        let options = PlineOffsetOptions {
+            join_style,
+            ..Default::default()
+        };
+        let results = input.parallel_offset_with_source_opt(offset, &options);
+        let plain_results = input.parallel_offset_opt(offset, &options);
+        assert_eq!(results.len(), plain_results.len());
+
+        let mut counts = (0, 0);
+        for (result, plain) in results.iter().zip(plain_results.iter()) {
+            assert!(result.polyline.fuzzy_eq(plain));
+            assert_eq!(result.sources.len(), result.polyline.len());
+            for (i, (v1, v2)) in result.polyline.iter_segments().enumerate() {
+                let midpoint = seg_midpoint(v1, v2);
+                match result.sources[i] {
+                    OffsetSegSource::Segment(s) => {
+                        counts.0 += 1;
+                        let u1 = input[s];
+                        let u2 = input[input.next_wrapping_index(s)];
+                        let closest = seg_closest_point(u1, u2, midpoint);
+                        assert!(dist_squared(closest, midpoint)
+                            .sqrt()
+                            .fuzzy_eq_eps(offset.abs(), 1e-5));
+                    }
+                    OffsetSegSource::Join(k) => {
+                        counts.1 += 1;
+                        let center = input[k].pos();
+                        if matches!(join_style, OffsetJoinStyle::Round) {
+                            // round joins are arcs centered at the source vertex
+                            assert!(!v1.bulge_is_zero());
+                            for p in [v1.pos(), v2.pos(), midpoint] {
+                                assert!(dist_squared(center, p)
+                                    .sqrt()
+                                    .fuzzy_eq_eps(offset.abs(), 1e-5));
+                            }
+                        } else {
+                            assert!(v1.bulge_is_zero());
+                        }
+                    }
+                }
+            }
+
+            if !result.polyline.is_closed() {
+                let n = result.sources.len();
+                assert_eq!(result.sources[n - 1], result.sources[n - 2]);
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn rectangle_outward_round() {
+        assert_eq!(
+            check_sources(&rect(0.0, 0.0, 20.0, 10.0), -2.0, OffsetJoinStyle::Round),
+            (4, 4)
+        );
+    }
+
+    #[test]
+    fn rectangle_outward_bevel() {
+        assert_eq!(
+            check_sources(&rect(0.0, 0.0, 20.0, 10.0), -2.0, OffsetJoinStyle::Bevel),
+            (4, 4)
+        );
+    }
+
+    #[test]
+    fn rectangle_outward_miter() {
+        assert_eq!(
+            check_sources(
+                &rect(0.0, 0.0, 20.0, 10.0),
+                -2.0,
+                OffsetJoinStyle::Miter { limit: 2.0 }
+            ),
+            (4, 0)
+        );
+    }
+
+    #[test]
+    fn rectangle_inward() {
+        assert_eq!(
+            check_sources(&rect(0.0, 0.0, 20.0, 10.0), 2.0, OffsetJoinStyle::Round),
+            (4, 0)
+        );
+    }
+
+    #[test]
+    fn arcs_and_lines() {
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.5),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, -0.5),
+            (2.0, 5.0, 0.0)
+        ];
+        let (segs, joins) = check_sources(&input, -1.0, OffsetJoinStyle::Round);
+        assert_eq!(segs, 5);
+        assert!(joins > 0);
+        check_sources(&input, 1.0, OffsetJoinStyle::Round);
+    }
+
+    #[test]
+    fn open_polyline() {
+        let input = pline_open![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        assert_eq!(check_sources(&input, -1.0, OffsetJoinStyle::Round), (3, 2));
+        assert_eq!(check_sources(&input, 1.0, OffsetJoinStyle::Round), (3, 0));
+    }
+
+    #[test]
+    fn split_offset() {
+        // offset of slot separates into two loops
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (6.0, 10.0, 0.0),
+            (6.0, 2.0, 0.0),
+            (4.0, 2.0, 0.0),
+            (4.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        check_sources(&input, 1.5, OffsetJoinStyle::Round);
+        check_sources(&input, -1.5, OffsetJoinStyle::Bevel);
+    }
+}
+
+mod test_try_offset {
+    use super::*;
+    use cavalier_contours::{pline_closed, pline_open, polyline::PlineOpError};
+
+    #[test]
+    fn valid_input_matches_offset() {
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (20.0, 0.0, 0.0),
+            (20.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        for offset in [-2.0, 2.0, 4.0] {
+            let expected = input.parallel_offset(offset);
+            let result = input.try_parallel_offset(offset).unwrap();
+            assert!(property_sets_match(
+                &create_property_set(&result, false),
+                &create_property_set(&expected, false)
+            ));
+        }
+
+        // offset collapsed to nothing is not an error
+        assert!(input.try_parallel_offset(6.0).unwrap().is_empty());
+
+        let open = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.5), (20.0, 0.0, 0.0)];
+        assert_eq!(open.try_parallel_offset(1.0).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let empty = Polyline::<f64>::new();
+        assert_eq!(
+            empty.try_parallel_offset(1.0).unwrap_err(),
+            PlineOpError::TooFewVertexes { input: 0 }
+        );
+
+        let repeat = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        assert_eq!(
+            repeat.try_parallel_offset(1.0).unwrap_err(),
+            PlineOpError::RepeatPositionVertexes {
+                input: 0,
+                vertex_index: 2
+            }
+        );
+
+        let nan = pline_open![(0.0, 0.0, 0.0), (10.0, f64::NAN, 0.0)];
+        assert_eq!(
+            nan.try_parallel_offset(1.0).unwrap_err(),
+            PlineOpError::NonFiniteVertex {
+                input: 0,
+                vertex_index: 1
+            }
+        );
+    }
+}
+
+mod test_variable_offset {
+    use super::*;
+    use cavalier_contours::{
+        core::traits::FuzzyEq,
+        pline_closed, pline_open,
+        polyline::{PlineOpError, PlineVertex},
+    };
+    use std::f64::consts::PI;
+
+    #[test]
+    fn square_inward() {
+        let result = square(0.0, 0.0, 10.0)
+            .parallel_offset_variable(&[1.0, 2.0, 3.0, 4.0])
+            .unwrap();
+        assert!(property_sets_match(
+            &create_property_set(&result, false),
+            &[PlineProperties::new(4, 24.0, 20.0, 4.0, 1.0, 8.0, 7.0)]
+        ));
+    }
+
+    #[test]
+    fn uniform_offsets_match_parallel_offset() {
+        let notched = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (6.0, 10.0, 0.0),
+            (6.0, 2.0, 0.0),
+            (4.0, 2.0, 0.0),
+            (4.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        for &join_style in [
+            OffsetJoinStyle::Round,
+            OffsetJoinStyle::Miter { limit: 2.0 },
+            OffsetJoinStyle::Bevel,
+        ]
+        .iter()
+        {
+            let options = PlineOffsetOptions {
+                join_style,
+                ..Default::default()
+            };
+            for &offset in [1.5, -1.5].iter() {
+                let expected = notched.parallel_offset_opt(offset, &options);
+                assert!(!expected.is_empty());
+                let result = notched
+                    .parallel_offset_variable_opt(&[offset; 8], &options)
+                    .unwrap();
+                assert!(
+                    property_sets_match(
+                        &create_property_set(&result, false),
+                        &create_property_set(&expected, false)
+                    ),
+                    "{:?} offset {}",
+                    join_style,
+                    offset
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn square_outward() {
+        // every corner joins a side offset by 1 with a side offset by 2, joining arcs have radius 2
+        let result = square(0.0, 0.0, 10.0)
+            .parallel_offset_variable(&[-1.0, -2.0, -1.0, -2.0])
+            .unwrap();
+        let sqrt_3 = 3.0f64.sqrt();
+        let area = 160.0 + 20.0 * PI / 3.0 - 2.0 * sqrt_3;
+        let path_length = 40.0 - 4.0 * sqrt_3 + 20.0 * PI / 3.0;
+        assert!(property_sets_match(
+            &create_property_set(&result, false),
+            &[PlineProperties::new(
+                8,
+                area,
+                path_length,
+                -2.0,
+                -2.0,
+                12.0,
+                12.0
+            )]
+        ));
+    }
+
+    #[test]
+    fn uniform_offsets_match_offset() {
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (20.0, 0.0, 0.5),
+            (20.0, 10.0, 0.0),
+            (10.0, 12.0, -0.3),
+            (0.0, 10.0, 0.0)
+        ];
+        let segment_count = input.iter_segment_indexes().count();
+        for offset in [-3.0, -1.0, 1.0, 3.0] {
+            let expected = input.parallel_offset(offset);
+            let result = input
+                .parallel_offset_variable(&vec![offset; segment_count])
+                .unwrap();
+            assert!(property_sets_match(
+                &create_property_set(&result, false),
+                &create_property_set(&expected, false)
+            ));
+        }
+    }
+
+    #[test]
+    fn join_style_used_where_offset_does_not_change() {
+        let options = PlineOffsetOptions {
+            join_style: OffsetJoinStyle::Miter { limit: 2.0 },
+            ..Default::default()
+        };
+        let result = square(0.0, 0.0, 10.0)
+            .parallel_offset_variable_opt(&[-1.0; 4], &options)
+            .unwrap();
+        assert!(property_sets_match(
+            &create_property_set(&result, false),
+            &[PlineProperties::new(4, 144.0, 48.0, -1.0, -1.0, 11.0, 11.0)]
+        ));
+
+        // corners where the offset changes are still joined with arcs
+        let result = square(0.0, 0.0, 10.0)
+            .parallel_offset_variable_opt(&[-1.0, -1.0, -2.0, -2.0], &options)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let arc_count = result[0]
+            .iter_segments()
+            .filter(|(v1, _)| !v1.bulge_is_zero())
+            .count();
+        assert_eq!(arc_count, 2);
+    }
+
+    #[test]
+    fn invalid_offsets() {
+        let input = square(0.0, 0.0, 10.0);
+        assert_eq!(
+            input.parallel_offset_variable(&[1.0, 2.0]).unwrap_err(),
+            PlineOpError::OffsetCountMismatch {
+                expected: 4,
+                actual: 2
+            }
+        );
+        assert_eq!(
+            input
+                .parallel_offset_variable(&[1.0, 2.0, -1.0, 2.0])
+                .unwrap_err(),
+            PlineOpError::InvalidOffset { segment_index: 2 }
+        );
+        assert_eq!(
+            input
+                .parallel_offset_variable(&[1.0, 0.0, 1.0, 1.0])
+                .unwrap_err(),
+            PlineOpError::InvalidOffset { segment_index: 1 }
+        );
+        assert_eq!(
+            input
+                .parallel_offset_variable(&[1.0, 1.0, 1.0, f64::NAN])
+                .unwrap_err(),
+            PlineOpError::InvalidOffset { segment_index: 3 }
+        );
+        assert!(Polyline::<f64>::new()
+            .parallel_offset_variable(&[])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn open_polyline() {
+        let input = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+
+        // inside of corner, lines are trimmed where they intersect
+        let result = input.parallel_offset_variable(&[1.0, 2.0]).unwrap();
+        assert_eq!(result.len(), 1);
+        let expected = [(0.0, 1.0, 0.0), (8.0, 1.0, 0.0), (8.0, 10.0, 0.0)];
+        assert_eq!(result[0].len(), expected.len());
+        for (v, &(x, y, bulge)) in result[0].iter().zip(expected.iter()) {
+            assert!(v.fuzzy_eq(PlineVertex::new(x, y, bulge)));
+        }
+
+        // outside of corner, first segment is trimmed where it meets the larger joining arc
+        let result = input.parallel_offset_variable(&[-1.0, -2.0]).unwrap();
+        assert_eq!(result.len(), 1);
+        let arc_bulge = (5.0 * PI / 24.0).tan();
+        let expected = [
+            (0.0, -1.0, 0.0),
+            (10.0 - 3.0f64.sqrt(), -1.0, arc_bulge),
+            (12.0, 0.0, 0.0),
+            (12.0, 10.0, 0.0),
+        ];
+        assert_eq!(result[0].len(), expected.len());
+        for (v, &(x, y, bulge)) in result[0].iter().zip(expected.iter()) {
+            assert!(v.fuzzy_eq(PlineVertex::new(x, y, bulge)));
+        }
+        assert!(result[0]
+            .path_length()
+            .fuzzy_eq(20.0 - 3.0f64.sqrt() + 5.0 * PI / 3.0));
+    }
+}
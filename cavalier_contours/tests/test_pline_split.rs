@@ -0,0 +1,175 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{seg_midpoint, PlineSplitOptions, Polyline},
+};
+use std::f64::consts::PI;
+use test_utils::square;
+
+/// Assert `piece` is an open polyline that lies along `source` (checked at the vertexes and
+/// segment midpoints) with the path length given.
+fn assert_piece_on_source(piece: &Polyline<f64>, source: &Polyline<f64>, length: f64) {
+    assert!(!piece.is_closed());
+    assert!(
+        piece.path_length().fuzzy_eq(length),
+        "length {} != {}",
+        piece.path_length(),
+        length
+    );
+    for (v1, v2) in piece.iter_segments() {
+        assert!(!v1.pos().fuzzy_eq(v2.pos()), "repeat position vertex");
+        for p in [v1.pos(), seg_midpoint(v1, v2)] {
+            let closest = source.closest_point(p).unwrap();
+            assert!(closest.distance.fuzzy_eq(0.0), "{:?} not on source", p);
+        }
+    }
+}
+
+#[test]
+fn split_open_at_length() {
+    // line then ccw half circle arc with radius 2
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    let total = 10.0 + 2.0 * PI;
+
+    let pieces = pline.split_at_length(10.0 + 0.5 * PI);
+    assert_eq!(pieces.len(), 2);
+    assert_piece_on_source(&pieces[0], &pline, 10.0 + 0.5 * PI);
+    assert_piece_on_source(&pieces[1], &pline, 1.5 * PI);
+    let split_point = Vector2::new(10.0 + 2.0f64.sqrt(), 2.0 - 2.0f64.sqrt());
+    assert!(pieces[0].last().unwrap().pos().fuzzy_eq(split_point));
+    assert!(pieces[1][0].pos().fuzzy_eq(split_point));
+    assert!(pieces[0].last().unwrap().bulge.fuzzy_eq(0.0));
+
+    // at a vertex
+    let pieces = pline.split_at_length(10.0);
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0].len(), 2);
+    assert_eq!(pieces[1].len(), 2);
+    assert!(pieces[1][0].bulge.fuzzy_eq(1.0));
+
+    // at the ends
+    for length in [0.0, total, -5.0, 100.0] {
+        let pieces = pline.split_at_length(length);
+        assert_eq!(pieces.len(), 1);
+        assert_piece_on_source(&pieces[0], &pline, total);
+    }
+
+    assert!(Polyline::<f64>::new().split_at_length(1.0).is_empty());
+}
+
+#[test]
+fn split_at_points() {
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+
+    // unordered with a repeat point and a point at the start
+    let points = [
+        (1, Vector2::new(10.0, 5.0)),
+        (0, Vector2::new(2.0, 0.0)),
+        (1, Vector2::new(10.0, 5.0)),
+        (0, Vector2::new(0.0, 0.0)),
+    ];
+    let pieces = pline.split_at_points(&points);
+    assert_eq!(pieces.len(), 3);
+    assert_piece_on_source(&pieces[0], &pline, 2.0);
+    assert_piece_on_source(&pieces[1], &pline, 13.0);
+    assert_piece_on_source(&pieces[2], &pline, 15.0);
+    assert_eq!(pieces[1].len(), 3);
+
+    let pieces = pline.split_at_points(&[]);
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].len(), pline.len());
+
+    // closed polyline pieces go around the loop
+    let square = square(0.0, 0.0, 10.0);
+    let points = [
+        (3, Vector2::new(0.0, 5.0)),
+        (1, Vector2::new(10.0, 5.0)),
+        (0, Vector2::new(5.0, 0.0)),
+    ];
+    let pieces = square.split_at_points(&points);
+    assert_eq!(pieces.len(), 3);
+    assert_piece_on_source(&pieces[0], &square, 10.0);
+    assert_piece_on_source(&pieces[1], &square, 20.0);
+    assert_piece_on_source(&pieces[2], &square, 10.0);
+    assert!(pieces[2][0].pos().fuzzy_eq(Vector2::new(0.0, 5.0)));
+    assert!(pieces[2][1].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(pieces[2][2].pos().fuzzy_eq(Vector2::new(5.0, 0.0)));
+
+    // split at a single point opens the loop
+    let pieces = square.split_at_points(&[(1, Vector2::new(10.0, 5.0))]);
+    assert_eq!(pieces.len(), 1);
+    assert_piece_on_source(&pieces[0], &square, 40.0);
+    assert_eq!(pieces[0].len(), 6);
+}
+
+#[test]
+fn sub_polyline() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 4.0, 0.0)];
+    let part = pline.sub_polyline(4.0, 10.0 + PI).unwrap();
+    assert_piece_on_source(&part, &pline, 6.0 + PI);
+    assert!(part[0].pos().fuzzy_eq(Vector2::new(4.0, 0.0)));
+    assert!(part[1].pos().fuzzy_eq(Vector2::new(10.0, 0.0)));
+    assert!(part[2].pos().fuzzy_eq(Vector2::new(12.0, 2.0)));
+
+    // within a single arc segment
+    let part = pline
+        .sub_polyline(10.0 + 0.5 * PI, 10.0 + 1.5 * PI)
+        .unwrap();
+    assert_eq!(part.len(), 2);
+    assert_piece_on_source(&part, &pline, PI);
+
+    // clamped
+    let part = pline.sub_polyline(-10.0, 2.0).unwrap();
+    assert_piece_on_source(&part, &pline, 2.0);
+
+    assert!(pline.sub_polyline(5.0, 5.0).is_none());
+    assert!(pline.sub_polyline(6.0, 5.0).is_none());
+
+    // closed polylines wrap around the loop
+    let square = square(0.0, 0.0, 10.0);
+    let part = square.sub_polyline(35.0, 5.0).unwrap();
+    assert_piece_on_source(&part, &square, 10.0);
+    let part = square.sub_polyline(-5.0, 45.0).unwrap();
+    assert_piece_on_source(&part, &square, 10.0);
+    let part = square.sub_polyline(5.0, 35.0).unwrap();
+    assert_piece_on_source(&part, &square, 30.0);
+    let part = square.sub_polyline(0.0, 40.0).unwrap();
+    assert_piece_on_source(&part, &square, 40.0);
+    assert_eq!(part.len(), 5);
+    assert!(square.sub_polyline(5.0, 5.0).is_none());
+
+    let options = PlineSplitOptions { pos_equal_eps: 0.1 };
+    assert!(square.sub_polyline_opt(5.0, 5.05, &options).is_none());
+}
+
+#[test]
+fn open_at_point() {
+    let circle: Polyline<f64> = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    let opened = circle.open_at_point(1, Vector2::new(1.0, 1.0)).unwrap();
+    assert_piece_on_source(&opened, &circle, 2.0 * PI);
+    assert_eq!(opened.len(), 4);
+    assert!(opened[0].pos().fuzzy_eq(Vector2::new(1.0, 1.0)));
+    assert!(opened[1].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(opened[2].pos().fuzzy_eq(Vector2::new(2.0, 0.0)));
+    assert!(opened[3].pos().fuzzy_eq(Vector2::new(1.0, 1.0)));
+
+    // at a vertex
+    let square = square(0.0, 0.0, 10.0);
+    let opened = square.open_at_point(2, Vector2::new(10.0, 10.0)).unwrap();
+    assert_piece_on_source(&opened, &square, 40.0);
+    assert_eq!(opened.len(), 5);
+    assert!(opened[0].pos().fuzzy_eq(Vector2::new(10.0, 10.0)));
+    let opened = square.open_at_point(1, Vector2::new(10.0, 10.0)).unwrap();
+    assert_eq!(opened.len(), 5);
+    assert!(opened[0].pos().fuzzy_eq(Vector2::new(10.0, 10.0)));
+
+    let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(line.open_at_point(0, Vector2::new(5.0, 0.0)).is_none());
+}
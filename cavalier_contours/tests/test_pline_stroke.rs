@@ -0,0 +1,207 @@
+mod test_utils;
+
+use cavalier_contours::{
+    pline_closed, pline_open,
+    polyline::{OffsetJoinStyle, PlineStrokeOptions, Polyline, StrokeCapStyle},
+};
+use std::f64::consts::PI;
+use test_utils::{create_property_set, property_sets_match, PlineProperties};
+
+fn stroke_properties(
+    pline: &Polyline<f64>,
+    width: f64,
+    cap_style: StrokeCapStyle,
+    join_style: OffsetJoinStyle<f64>,
+) -> Vec<PlineProperties> {
+    let options = PlineStrokeOptions {
+        cap_style,
+        join_style,
+        ..Default::default()
+    };
+    let result = pline.stroke_opt(width, &options);
+    for r in result.iter() {
+        assert!(r.is_closed(), "stroke results should always be closed");
+    }
+    create_property_set(&result, false)
+}
+
+#[test]
+fn line_round_caps() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let result = stroke_properties(&pline, 2.0, StrokeCapStyle::Round, OffsetJoinStyle::Round);
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(
+            4,
+            20.0 + PI,
+            20.0 + 2.0 * PI,
+            -1.0,
+            -1.0,
+            11.0,
+            1.0
+        )]
+    ));
+}
+
+#[test]
+fn line_butt_caps() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let result = stroke_properties(&pline, 2.0, StrokeCapStyle::Butt, OffsetJoinStyle::Round);
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(4, 20.0, 24.0, 0.0, -1.0, 10.0, 1.0)]
+    ));
+}
+
+#[test]
+fn line_square_caps() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let result = stroke_properties(&pline, 2.0, StrokeCapStyle::Square, OffsetJoinStyle::Round);
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(4, 24.0, 28.0, -1.0, -1.0, 11.0, 1.0)]
+    ));
+}
+
+#[test]
+fn arc_square_caps() {
+    // half circle of radius 5, square caps extend tangentially past the arc end points
+    let pline = pline_open![(5.0, 0.0, 1.0), (-5.0, 0.0, 0.0)];
+    let result = stroke_properties(&pline, 2.0, StrokeCapStyle::Square, OffsetJoinStyle::Round);
+    let arc_area = 0.5 * PI * (36.0 - 16.0);
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(
+            8,
+            arc_area + 4.0,
+            10.0 * PI + 8.0,
+            -6.0,
+            -1.0,
+            6.0,
+            6.0
+        )]
+    ));
+}
+
+#[test]
+fn corner_round_join() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let result = stroke_properties(&pline, 2.0, StrokeCapStyle::Butt, OffsetJoinStyle::Round);
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(
+            7,
+            39.0 + PI / 4.0,
+            42.0 + PI / 2.0,
+            0.0,
+            -1.0,
+            11.0,
+            10.0
+        )]
+    ));
+}
+
+#[test]
+fn corner_miter_join() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let result = stroke_properties(
+        &pline,
+        2.0,
+        StrokeCapStyle::Butt,
+        OffsetJoinStyle::Miter { limit: 2.0 },
+    );
+    assert!(property_sets_match(
+        &result,
+        &[PlineProperties::new(6, 40.0, 44.0, 0.0, -1.0, 11.0, 10.0)]
+    ));
+}
+
+#[test]
+fn self_crossing_path_forms_hole() {
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (5.0, -5.0, 0.0),
+    ];
+
+    for cap_style in [StrokeCapStyle::Round, StrokeCapStyle::Butt] {
+        let result = pline.stroke_opt(
+            2.0,
+            &PlineStrokeOptions {
+                cap_style,
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.len(), 2);
+        let (outer, hole): (Vec<_>, Vec<_>) = result.iter().partition(|p| p.area() > 0.0);
+        assert_eq!(outer.len(), 1);
+        assert!(property_sets_match(
+            &create_property_set(hole, false),
+            &[PlineProperties::new(4, -24.0, 22.0, 6.0, 1.0, 9.0, 9.0)]
+        ));
+    }
+}
+
+#[test]
+fn closed_pline_stroke() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let expected = [
+        PlineProperties::new(8, 140.0 + PI, 40.0 + 2.0 * PI, -1.0, -1.0, 11.0, 11.0),
+        PlineProperties::new(4, -64.0, 32.0, 1.0, 1.0, 9.0, 9.0),
+    ];
+
+    let result = pline.stroke(2.0);
+    assert!(property_sets_match(
+        &create_property_set(&result, false),
+        &expected
+    ));
+
+    // direction of input does not change result
+    let mut inverted = pline.clone();
+    inverted.invert_direction();
+    let result = inverted.stroke(2.0);
+    assert!(property_sets_match(
+        &create_property_set(&result, false),
+        &expected
+    ));
+}
+
+#[test]
+fn zero_width_stroke_is_empty() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(pline.stroke(0.0).is_empty());
+}
+
+#[test]
+fn u_turn_narrower_than_width() {
+    // inner side raw offsets overlap, stroke is a single loop with no hole
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 1.0, 0.0),
+        (0.0, 1.0, 0.0)
+    ];
+    let result = stroke_properties(&pline, 4.0, StrokeCapStyle::Butt, OffsetJoinStyle::Round);
+    assert_eq!(result.len(), 1);
+    assert!((result[0].area - (52.0 + 2.0 * PI)).abs() < PlineProperties::PROP_CMP_EPS);
+}
+
+#[test]
+fn closed_pline_stroke_fills_narrow_interior() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 1.0, 0.0),
+        (0.0, 1.0, 0.0)
+    ];
+    let result = pline.stroke(4.0);
+    assert_eq!(result.len(), 1);
+    assert!((result[0].area() - (54.0 + 4.0 * PI)).abs() < PlineProperties::PROP_CMP_EPS);
+}
@@ -0,0 +1,442 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{BooleanOp, PlineOpError, Polyline, Shape, SharedEdge},
+};
+use std::f64::consts::PI;
+use test_utils::{circle, rect, square};
+
+#[test]
+fn empty_shape() {
+    let shape =
+        Shape::<f64>::from_unoriented_plines(vec![pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]]);
+    assert!(shape.is_empty());
+    assert!(shape.extents().is_none());
+    assert!(shape.area().fuzzy_eq(0.0));
+    assert!(!shape.contains_point(Vector2::new(0.0, 0.0)));
+    assert_eq!(shape.iter_plines().count(), 0);
+}
+
+#[test]
+fn nested_islands() {
+    // outer > hole > island > hole, given in arbitrary order and orientation
+    let mut island_hole = square(4.0, 4.0, 2.0);
+    island_hole.invert_direction();
+    let shape = Shape::from_unoriented_plines(vec![
+        square(3.0, 3.0, 4.0),
+        island_hole,
+        square(0.0, 0.0, 10.0),
+        square(1.0, 1.0, 8.0),
+    ]);
+
+    assert_eq!(shape.ccw_plines.len(), 2);
+    assert_eq!(shape.cw_plines.len(), 2);
+    assert!(shape.ccw_plines.iter().all(|l| l.polyline.area() > 0.0));
+    let hierarchy = shape.hierarchy();
+    for (h, parent) in shape.cw_plines.iter().zip(hierarchy.cw_parents.iter()) {
+        assert!(h.polyline.area() < 0.0);
+        let parent = &shape.ccw_plines[parent.unwrap()];
+        assert!(parent.polyline.area() > -h.polyline.area());
+    }
+
+    // island is nested in the outer boundary hole
+    let island_index = hierarchy
+        .ccw_parents
+        .iter()
+        .position(|p| p.is_some())
+        .unwrap();
+    assert!(shape.ccw_plines[island_index]
+        .polyline
+        .area()
+        .fuzzy_eq(16.0));
+    let island_parent = &shape.cw_plines[hierarchy.ccw_parents[island_index].unwrap()];
+    assert!(island_parent.polyline.area().fuzzy_eq(-64.0));
+    let island_holes: Vec<_> = shape
+        .cw_plines
+        .iter()
+        .zip(hierarchy.cw_parents.iter())
+        .filter(|(_, &p)| p == Some(island_index))
+        .collect();
+    assert_eq!(island_holes.len(), 1);
+    assert!(island_holes[0].0.polyline.area().fuzzy_eq(-4.0));
+
+    assert!(shape.area().fuzzy_eq(100.0 - 64.0 + 16.0 - 4.0));
+    let extents = shape.extents().unwrap();
+    assert!(extents.min_x.fuzzy_eq(0.0));
+    assert!(extents.min_y.fuzzy_eq(0.0));
+    assert!(extents.max_x.fuzzy_eq(10.0));
+    assert!(extents.max_y.fuzzy_eq(10.0));
+
+    assert!(shape.contains_point(Vector2::new(0.5, 0.5)));
+    assert!(!shape.contains_point(Vector2::new(2.0, 2.0)));
+    assert!(shape.contains_point(Vector2::new(3.5, 3.5)));
+    assert!(!shape.contains_point(Vector2::new(5.0, 5.0)));
+    assert!(!shape.contains_point(Vector2::new(11.0, 5.0)));
+    assert_eq!(shape.iter_plines().count(), 4);
+}
+
+#[test]
+fn holes_assigned_to_containing_outer() {
+    let shape = Shape::from_unoriented_plines(vec![
+        square(2.0, 2.0, 1.0),
+        square(20.0, 20.0, 10.0),
+        square(0.0, 0.0, 10.0),
+        square(22.0, 22.0, 1.0),
+        square(25.0, 25.0, 1.0),
+    ]);
+
+    assert_eq!(shape.ccw_plines.len(), 2);
+    assert_eq!(shape.cw_plines.len(), 3);
+    let hierarchy = shape.hierarchy();
+    assert!(hierarchy.ccw_parents.iter().all(|p| p.is_none()));
+    for (i, l) in shape.ccw_plines.iter().enumerate() {
+        let expected_count = if l.polyline[0].x < 10.0 { 1 } else { 2 };
+        let hole_count = hierarchy
+            .cw_parents
+            .iter()
+            .filter(|&&p| p == Some(i))
+            .count();
+        assert_eq!(hole_count, expected_count);
+    }
+    assert!(shape.area().fuzzy_eq(200.0 - 3.0));
+}
+
+#[test]
+fn from_boolean_result() {
+    let rectangle = pline_closed![
+        (-1.0, -2.0, 0.0),
+        (3.0, -2.0, 0.0),
+        (3.0, 2.0, 0.0),
+        (-1.0, 2.0, 0.0),
+    ];
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    let shape = Shape::from(rectangle.boolean(&circle, BooleanOp::Not));
+    assert_eq!(shape.ccw_plines.len(), 1);
+    assert_eq!(shape.cw_plines.len(), 1);
+    assert_eq!(shape.hierarchy().cw_parents, vec![Some(0)]);
+    assert!(shape.area().fuzzy_eq(16.0 - PI));
+    assert!(!shape.contains_point(Vector2::new(1.0, 0.0)));
+    assert!(shape.contains_point(Vector2::new(2.5, 0.0)));
+
+    let plines = shape.into_plines();
+    assert_eq!(plines.len(), 2);
+    assert!(plines[0].area() > 0.0);
+    assert!(plines[1].area() < 0.0);
+}
+
+mod boolean {
+    use super::*;
+
+    /// 10 x 10 squares with a 2 x 2 hole, offset by 8 in x so they overlap by 2.
+    fn frames() -> (Shape<f64>, Shape<f64>) {
+        (
+            Shape::from_unoriented_plines(vec![
+                rect(0.0, 0.0, 10.0, 10.0),
+                rect(4.0, 4.0, 6.0, 6.0),
+            ]),
+            Shape::from_unoriented_plines(vec![
+                rect(8.0, 0.0, 18.0, 10.0),
+                rect(12.0, 4.0, 14.0, 6.0),
+            ]),
+        )
+    }
+
+    fn assert_counts(shape: &Shape<f64>, outer_count: usize, hole_count: usize, area: f64) {
+        assert_eq!(shape.ccw_plines.len(), outer_count);
+        assert_eq!(shape.cw_plines.len(), hole_count);
+        assert!(
+            shape.area().fuzzy_eq(area),
+            "area {} != expected {}",
+            shape.area(),
+            area
+        );
+        for l in shape.ccw_plines.iter() {
+            assert!(l.polyline.area() > 0.0);
+        }
+        for h in shape.cw_plines.iter() {
+            assert!(h.polyline.area() < 0.0);
+        }
+        assert!(shape.hierarchy().cw_parents.iter().all(|p| p.is_some()));
+    }
+
+    #[test]
+    fn overlapping_frames() {
+        let (a, b) = frames();
+        assert_counts(&a.boolean(&b, BooleanOp::Or), 1, 2, 200.0 - 20.0 - 8.0);
+        assert_counts(&a.boolean(&b, BooleanOp::And), 1, 0, 20.0);
+        assert_counts(&a.boolean(&b, BooleanOp::Not), 1, 1, 100.0 - 20.0 - 4.0);
+        assert_counts(&b.boolean(&a, BooleanOp::Not), 1, 1, 100.0 - 20.0 - 4.0);
+        let xor = a.boolean(&b, BooleanOp::Xor);
+        assert!(xor.area().fuzzy_eq(200.0 - 40.0 - 8.0));
+        assert_eq!(xor.cw_plines.len(), 2);
+    }
+
+    #[test]
+    fn subtract_cutting_into_hole() {
+        let a = Shape::from_unoriented_plines(vec![
+            rect(0.0, 0.0, 10.0, 10.0),
+            rect(2.0, 2.0, 8.0, 8.0),
+        ]);
+        let b = Shape::from_plines(vec![rect(5.0, -1.0, 15.0, 11.0)]);
+        // C shaped result, hole is opened up to the cut
+        assert_counts(&a.boolean(&b, BooleanOp::Not), 1, 0, 50.0 - 18.0);
+        // remaining part of the hole is closed off by the added rectangle
+        assert_counts(&a.boolean(&b, BooleanOp::Or), 1, 1, 50.0 - 18.0 + 120.0);
+        assert_counts(&a.boolean(&b, BooleanOp::And), 1, 0, 50.0 - 18.0);
+    }
+
+    #[test]
+    fn island_in_hole() {
+        let frame = Shape::from_unoriented_plines(vec![
+            rect(0.0, 0.0, 10.0, 10.0),
+            rect(2.0, 2.0, 8.0, 8.0),
+        ]);
+        let island = Shape::from_plines(vec![rect(4.0, 4.0, 6.0, 6.0)]);
+        let result = frame.boolean(&island, BooleanOp::Or);
+        assert_counts(&result, 2, 1, 64.0 + 4.0);
+        let nested = result
+            .hierarchy()
+            .ccw_parents
+            .iter()
+            .position(|p| p.is_some())
+            .unwrap();
+        assert!(result.ccw_plines[nested].polyline.area().fuzzy_eq(4.0));
+
+        assert!(frame.boolean(&island, BooleanOp::And).is_empty());
+        assert_counts(&frame.boolean(&island, BooleanOp::Not), 1, 1, 64.0);
+    }
+
+    #[test]
+    fn shared_edges() {
+        let a = Shape::from_plines(vec![rect(0.0, 0.0, 10.0, 10.0)]);
+        let b = Shape::from_plines(vec![rect(10.0, 0.0, 20.0, 10.0)]);
+        let result = a.boolean(&b, BooleanOp::Or);
+        assert_counts(&result, 1, 0, 200.0);
+
+        // partially shared edge with both regions on the same side
+        let c = Shape::from_plines(vec![rect(5.0, 0.0, 15.0, 5.0)]);
+        assert_counts(&a.boolean(&c, BooleanOp::Or), 1, 0, 125.0);
+        assert_counts(&a.boolean(&c, BooleanOp::And), 1, 0, 25.0);
+        assert_counts(&a.boolean(&c, BooleanOp::Not), 1, 0, 75.0);
+    }
+
+    #[test]
+    fn coincident_loops() {
+        let (a, _) = frames();
+        assert_counts(&a.boolean(&a, BooleanOp::Or), 1, 1, 96.0);
+        assert_counts(&a.boolean(&a, BooleanOp::And), 1, 1, 96.0);
+        assert!(a.boolean(&a, BooleanOp::Not).is_empty());
+        assert!(a.boolean(&a, BooleanOp::Xor).is_empty());
+    }
+
+    #[test]
+    fn empty_operand() {
+        let (a, _) = frames();
+        let empty = Shape::empty();
+        assert_counts(&a.boolean(&empty, BooleanOp::Or), 1, 1, 96.0);
+        assert_counts(&empty.boolean(&a, BooleanOp::Or), 1, 1, 96.0);
+        assert!(a.boolean(&empty, BooleanOp::And).is_empty());
+        assert_counts(&a.boolean(&empty, BooleanOp::Not), 1, 1, 96.0);
+        assert!(empty.boolean(&a, BooleanOp::Not).is_empty());
+    }
+
+    #[test]
+    fn try_boolean() {
+        let (a, b) = frames();
+        for &op in [
+            BooleanOp::Or,
+            BooleanOp::And,
+            BooleanOp::Not,
+            BooleanOp::Xor,
+        ]
+        .iter()
+        {
+            let result = a.try_boolean(&b, op).unwrap();
+            assert!(result.area().fuzzy_eq(a.boolean(&b, op).area()));
+        }
+
+        // xor with partially shared edge
+        let c = Shape::from_plines(vec![rect(5.0, 0.0, 15.0, 5.0)]);
+        let xor = a.try_boolean(&c, BooleanOp::Xor).unwrap();
+        assert!(xor.area().fuzzy_eq(96.0 + 50.0 - 2.0 * 24.0));
+
+        let repeat_pos = Shape::from_plines(vec![pline_closed![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0)
+        ]]);
+        assert_eq!(
+            a.try_boolean(&repeat_pos, BooleanOp::Or).unwrap_err(),
+            PlineOpError::RepeatPositionVertexes {
+                input: 1,
+                vertex_index: 2
+            }
+        );
+    }
+}
+
+mod batch_union {
+    use super::*;
+
+    #[test]
+    fn frame_from_overlapping_rects() {
+        let rects = vec![
+            rect(0.0, 0.0, 10.0, 2.0),
+            rect(8.0, 0.0, 10.0, 10.0),
+            rect(0.0, 8.0, 10.0, 10.0),
+            rect(0.0, 0.0, 2.0, 10.0),
+            // island inside the hole
+            rect(4.0, 4.0, 6.0, 6.0),
+        ];
+        let shape = Shape::batch_union(rects);
+        assert_eq!(shape.ccw_plines.len(), 2);
+        assert_eq!(shape.cw_plines.len(), 1);
+        assert!(shape.area().fuzzy_eq(100.0 - 36.0 + 4.0));
+        let island = shape
+            .ccw_plines
+            .iter()
+            .position(|l| l.polyline.area().fuzzy_eq(4.0))
+            .unwrap();
+        assert_eq!(shape.hierarchy().ccw_parents[island], Some(0));
+    }
+
+    #[test]
+    fn matches_sequential_union() {
+        // dilated points along a line with groups separated by gaps
+        let centers: Vec<f64> = (0..40)
+            .map(|i| 1.5 * (i as f64) + if i < 20 { 0.0 } else { 5.0 })
+            .collect();
+        let shape = Shape::batch_union(centers.iter().map(|&x| circle(x, 0.0, 1.0)));
+        assert_eq!(shape.ccw_plines.len(), 2);
+        assert!(shape.cw_plines.is_empty());
+
+        let mut sequential = Shape::empty();
+        for &x in centers.iter() {
+            let c = Shape::from_plines(vec![circle(x, 0.0, 1.0)]);
+            sequential = sequential.boolean(&c, BooleanOp::Or);
+        }
+        assert_eq!(sequential.ccw_plines.len(), 2);
+        assert!(shape.area().fuzzy_eq_eps(sequential.area(), 1e-5));
+    }
+
+    #[test]
+    fn disjoint_pass_through() {
+        let mut cw = circle(20.0, 0.0, 2.0);
+        cw.invert_direction();
+        let plines = vec![
+            rect(0.0, 0.0, 1.0, 1.0),
+            cw,
+            rect(5.0, 5.0, 6.0, 6.0),
+            pline_open![(0.0, 0.0, 0.0), (100.0, 100.0, 0.0)],
+        ];
+        let shape = Shape::batch_union(plines);
+        assert_eq!(shape.ccw_plines.len(), 3);
+        assert!(shape.cw_plines.is_empty());
+        assert!(shape.area().fuzzy_eq(2.0 + 4.0 * PI));
+        let vertex_counts: Vec<usize> = shape.ccw_plines.iter().map(|l| l.polyline.len()).collect();
+        assert_eq!(vertex_counts, vec![4, 2, 4]);
+
+        assert!(Shape::<f64>::batch_union(Vec::new()).is_empty());
+    }
+}
+
+mod shared_edges {
+    use super::*;
+
+    #[test]
+    fn grid_of_tiles() {
+        let tiles = vec![
+            square(0.0, 0.0, 10.0),
+            square(10.0, 0.0, 10.0),
+            square(0.0, 10.0, 10.0),
+            square(10.0, 10.0, 10.0),
+        ];
+        let edges = SharedEdge::find(&tiles);
+        // diagonal tiles only touch at a point
+        let mut pairs: Vec<_> = edges
+            .iter()
+            .map(|e| (e.pline_index1, e.pline_index2))
+            .collect();
+        pairs.sort_unstable();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+        for e in edges.iter() {
+            assert!(!e.pline.is_closed());
+            assert!(e.pline.path_length().fuzzy_eq(10.0));
+            assert!(e.opposing_directions);
+            // edge follows the direction of the first tile
+            let first = &tiles[e.pline_index1];
+            let start = first
+                .iter()
+                .position(|v| v.pos().fuzzy_eq(e.pline[0].pos()))
+                .unwrap();
+            let next = first[first.next_wrapping_index(start)].pos();
+            assert!(e.pline.last().unwrap().pos().fuzzy_eq(next));
+        }
+
+        let shape = Shape::from_dissolved_plines(tiles);
+        assert_eq!(shape.ccw_plines.len(), 1);
+        assert!(shape.cw_plines.is_empty());
+        assert!(shape.area().fuzzy_eq(400.0));
+    }
+
+    #[test]
+    fn ring_of_tiles() {
+        let mut tiles = Vec::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                if i != 1 || j != 1 {
+                    tiles.push(square(10.0 * (i as f64), 10.0 * (j as f64), 10.0));
+                }
+            }
+        }
+        // orientation of input is ignored
+        tiles[3].invert_direction();
+        assert_eq!(SharedEdge::find(&tiles).len(), 8);
+
+        let shape = Shape::from_dissolved_plines(tiles);
+        assert_eq!(shape.ccw_plines.len(), 1);
+        assert_eq!(shape.cw_plines.len(), 1);
+        assert!(shape.area().fuzzy_eq(800.0));
+    }
+
+    #[test]
+    fn shared_arc() {
+        // half disk and the region around it sharing the arc
+        let half_disk = pline_closed![(-1.0, 0.0, 0.0), (1.0, 0.0, 1.0)];
+        let around = pline_closed![
+            (1.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (-2.0, 2.0, 0.0),
+            (-2.0, 0.0, 0.0),
+            (-1.0, 0.0, -1.0)
+        ];
+        let plines = vec![half_disk, around];
+        let edges = SharedEdge::find(&plines);
+        assert_eq!(edges.len(), 1);
+        assert!(edges[0].pline.path_length().fuzzy_eq(PI));
+        assert!(edges[0].pline[0].pos().fuzzy_eq(Vector2::new(1.0, 0.0)));
+
+        let shape = Shape::from_dissolved_plines(plines);
+        assert_eq!(shape.ccw_plines.len(), 1);
+        assert!(shape.area().fuzzy_eq(8.0));
+    }
+
+    #[test]
+    fn no_shared_edges() {
+        // disjoint and touching at a corner only
+        let tiles = vec![
+            square(0.0, 0.0, 10.0),
+            square(10.0, 10.0, 10.0),
+            square(50.0, 0.0, 10.0),
+        ];
+        assert!(SharedEdge::find(&tiles).is_empty());
+        let shape = Shape::from_dissolved_plines(tiles);
+        assert_eq!(shape.ccw_plines.len(), 3);
+        assert!(shape.area().fuzzy_eq(300.0));
+        assert!(shape.ccw_plines.iter().all(|l| l.polyline.len() == 4));
+    }
+}
@@ -0,0 +1,427 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::traits::FuzzyEq,
+    pline_closed,
+    polyline::{OffsetJoinStyle, Polyline, Shape, ShapeIterativeOffsetOptions, ShapeOffsetOptions},
+};
+use std::f64::consts::PI;
+use test_utils::{create_property_set, property_sets_match, PlineProperties};
+
+fn rect_with_circle_island() -> Shape<f64> {
+    let outer = pline_closed![
+        (0.0, 0.0, 0.0),
+        (20.0, 0.0, 0.0),
+        (20.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // clockwise circle of radius 2 centered at (10, 5)
+    let island = pline_closed![(12.0, 5.0, -1.0), (8.0, 5.0, -1.0)];
+    Shape::from_plines(vec![outer, island])
+}
+
+fn shape_properties(shape: &Shape<f64>) -> (Vec<PlineProperties>, Vec<PlineProperties>) {
+    let ccw: Vec<&Polyline<f64>> = shape.ccw_plines.iter().map(|p| &p.polyline).collect();
+    let cw: Vec<&Polyline<f64>> = shape.cw_plines.iter().map(|p| &p.polyline).collect();
+    (
+        create_property_set(ccw, false),
+        create_property_set(cw, false),
+    )
+}
+
+#[test]
+fn from_plines_separates_orientation() {
+    let shape = rect_with_circle_island();
+    assert_eq!(shape.ccw_plines.len(), 1);
+    assert_eq!(shape.cw_plines.len(), 1);
+    assert!(shape.plines_index.is_some());
+    assert_eq!(shape.iter_plines().count(), 2);
+}
+
+#[test]
+fn from_plines_skips_open_plines() {
+    let mut open = Polyline::new();
+    open.add(0.0, 0.0, 0.0);
+    open.add(1.0, 0.0, 0.0);
+    let shape = Shape::from_plines(vec![open]);
+    assert!(shape.is_empty());
+    assert!(shape.plines_index.is_none());
+}
+
+#[test]
+fn empty_shape_offset() {
+    let shape = Shape::<f64>::empty();
+    let result = shape.parallel_offset(1.0);
+    assert!(result.is_empty());
+}
+
+#[test]
+fn island_without_interaction() {
+    let shape = rect_with_circle_island();
+    let result = shape.parallel_offset(1.0);
+    let (ccw, cw) = shape_properties(&result);
+    assert!(property_sets_match(
+        &ccw,
+        &[PlineProperties::new(4, 144.0, 52.0, 1.0, 1.0, 19.0, 9.0)]
+    ));
+    assert!(property_sets_match(
+        &cw,
+        &[PlineProperties::new(
+            2,
+            -9.0 * PI,
+            6.0 * PI,
+            7.0,
+            2.0,
+            13.0,
+            8.0
+        )]
+    ));
+}
+
+#[test]
+fn island_splits_outer_boundary() {
+    let shape = rect_with_circle_island();
+    let result = shape.parallel_offset(3.0);
+    assert_eq!(result.ccw_plines.len(), 2);
+    assert!(result.cw_plines.is_empty());
+
+    // area of the offset rectangle minus the area of the offset island within it
+    let removed_area = 4.0 * 21.0f64.sqrt() + 50.0 * 0.4f64.asin();
+    let expected_area = (56.0 - removed_area) / 2.0;
+    for ipline in result.ccw_plines.iter() {
+        assert!(ipline.polyline.area().fuzzy_eq_eps(expected_area, 1e-4));
+    }
+}
+
+#[test]
+fn negative_offset_grows_outer_and_shrinks_island() {
+    let shape = rect_with_circle_island();
+    let result = shape.parallel_offset(-1.0);
+    let (ccw, cw) = shape_properties(&result);
+    assert!(property_sets_match(
+        &ccw,
+        &[PlineProperties::new(
+            8,
+            200.0 + 60.0 + PI,
+            60.0 + 2.0 * PI,
+            -1.0,
+            -1.0,
+            21.0,
+            11.0
+        )]
+    ));
+    assert!(property_sets_match(
+        &cw,
+        &[PlineProperties::new(2, -PI, 2.0 * PI, 9.0, 4.0, 11.0, 6.0)]
+    ));
+}
+
+#[test]
+fn join_style_applies_to_outer_corners() {
+    let shape = rect_with_circle_island();
+    let offset_with = |join_style| {
+        let options = ShapeOffsetOptions {
+            join_style,
+            ..Default::default()
+        };
+        shape.parallel_offset_opt(-1.0, &options)
+    };
+
+    for &(join_style, vertex_count, area) in [
+        (OffsetJoinStyle::Miter { limit: 2.0 }, 4, 264.0),
+        (OffsetJoinStyle::Bevel, 8, 262.0),
+        (OffsetJoinStyle::Round, 8, 260.0 + PI),
+    ]
+    .iter()
+    {
+        let result = offset_with(join_style);
+        assert_eq!(result.ccw_plines.len(), 1);
+        let outer = &result.ccw_plines[0].polyline;
+        assert_eq!(outer.len(), vertex_count);
+        assert!(
+            outer.area().fuzzy_eq(area),
+            "area {} != {}",
+            outer.area(),
+            area
+        );
+        // island has no convex corners to join
+        assert_eq!(result.cw_plines.len(), 1);
+        assert!(result.cw_plines[0].polyline.area().fuzzy_eq(-PI));
+    }
+}
+
+#[test]
+fn collapsed_island_is_removed() {
+    let shape = rect_with_circle_island();
+    let result = shape.parallel_offset(-2.5);
+    assert_eq!(result.ccw_plines.len(), 1);
+    assert!(result.cw_plines.is_empty());
+}
+
+#[test]
+fn outer_boundaries_merge() {
+    let left = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let right = pline_closed![
+        (12.0, 0.0, 0.0),
+        (22.0, 0.0, 0.0),
+        (22.0, 10.0, 0.0),
+        (12.0, 10.0, 0.0)
+    ];
+    let shape = Shape::from_plines(vec![left, right]);
+    let result = shape.parallel_offset_opt(-2.0, &ShapeOffsetOptions::new());
+    assert_eq!(result.ccw_plines.len(), 1);
+    assert!(result.cw_plines.is_empty());
+
+    let single_area = 100.0 + 80.0 + 4.0 * PI;
+    // overlap between the two offset squares (center strip plus rounded corner lenses)
+    let lens_area = 2.0 * (2.0 * PI / 3.0 - 3.0f64.sqrt() / 2.0);
+    let overlap_area = 20.0 + 2.0 * lens_area;
+    let expected_area = 2.0 * single_area - overlap_area;
+    assert!(result.ccw_plines[0]
+        .polyline
+        .area()
+        .fuzzy_eq_eps(expected_area, 1e-4));
+}
+
+#[test]
+fn iterative_offset_links_split_loops() {
+    let shape = rect_with_circle_island();
+    let tree = shape.iterative_offset(1.0);
+    assert!(tree.pass_count > 3);
+
+    // input loops are the roots
+    assert_eq!(tree.roots().collect::<Vec<_>>(), vec![0, 1]);
+
+    // first pass offsets the outer boundary and island without interaction
+    let pass1 = tree.pass_nodes(1).collect::<Vec<_>>();
+    assert_eq!(pass1, vec![2, 3]);
+    assert_eq!(tree.nodes[2].parent, Some(0));
+    assert!(tree.nodes[2].polyline.area() > 0.0);
+    assert_eq!(tree.nodes[3].parent, Some(1));
+    assert!(tree.nodes[3].polyline.area() < 0.0);
+
+    // second pass island splits the outer boundary, both loops link to the same parent
+    let pass2 = tree.pass_nodes(2).collect::<Vec<_>>();
+    assert_eq!(pass2.len(), 2);
+    for &i in pass2.iter() {
+        assert_eq!(tree.nodes[i].parent, Some(2));
+        assert!(tree.nodes[i].polyline.area() > 0.0);
+    }
+    assert_eq!(tree.nodes[2].children, pass2);
+
+    // every child link matches the parent link
+    for (i, node) in tree.nodes.iter().enumerate() {
+        for &c in node.children.iter() {
+            assert_eq!(tree.nodes[c].parent, Some(i));
+            assert_eq!(tree.nodes[c].pass, node.pass + 1);
+        }
+    }
+}
+
+#[test]
+fn iterative_offset_islands_only() {
+    // clockwise islands only grow with a positive step so passes stop without a max pass count
+    let islands = vec![
+        pline_closed![(2.0, 0.0, -1.0), (0.0, 0.0, -1.0)],
+        pline_closed![(12.0, 0.0, -1.0), (10.0, 0.0, -1.0)],
+    ];
+    let shape = Shape::from_plines(islands);
+    let tree = shape.iterative_offset(1.0);
+    assert_eq!(tree.pass_count, 1);
+    assert_eq!(tree.nodes.len(), 2);
+
+    let options = ShapeIterativeOffsetOptions {
+        max_pass_count: Some(3),
+        ..Default::default()
+    };
+    let tree = shape.iterative_offset_opt(1.0, &options);
+    assert_eq!(tree.pass_count, 4);
+    assert_eq!(tree.nodes.len(), 8);
+    for node in tree.nodes.iter().skip(2) {
+        assert!(node.polyline.area() < 0.0);
+        assert!(node.parent.is_some());
+    }
+
+    // islands shrink with a negative step until they are empty
+    let tree = shape.iterative_offset(-0.4);
+    assert_eq!(tree.pass_count, 3);
+    assert_eq!(tree.nodes.len(), 6);
+}
+
+#[test]
+fn iterative_offset_max_pass_count() {
+    let rect = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let shape = Shape::from_plines(vec![rect]);
+
+    // negative step grows the shape without any shrinking loops so passes require max pass count
+    assert_eq!(shape.iterative_offset(-1.0).nodes.len(), 1);
+
+    let options = ShapeIterativeOffsetOptions {
+        max_pass_count: Some(2),
+        ..Default::default()
+    };
+    let tree = shape.iterative_offset_opt(-1.0, &options);
+    assert_eq!(tree.pass_count, 3);
+    assert_eq!(tree.nodes.len(), 3);
+    assert_eq!(tree.nodes[1].parent, Some(0));
+    assert_eq!(tree.nodes[2].parent, Some(1));
+    assert!(tree.nodes[2]
+        .polyline
+        .area()
+        .fuzzy_eq_eps(40.0 + 2.0 * 28.0 + 4.0 * PI, 1e-5));
+
+    // zero step only returns the input loops
+    let tree = shape.iterative_offset(0.0);
+    assert_eq!(tree.pass_count, 1);
+    assert_eq!(tree.nodes.len(), 1);
+}
+
+#[test]
+fn morph_open_rounds_convex_corners() {
+    let rect = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let expected = [PlineProperties::new(
+        8,
+        36.0 + PI,
+        20.0 + 2.0 * PI,
+        0.0,
+        0.0,
+        10.0,
+        4.0,
+    )];
+
+    let result = rect.morph_open(1.0);
+    let (ccw, cw) = shape_properties(&result);
+    assert!(property_sets_match(&ccw, &expected));
+    assert!(cw.is_empty());
+
+    // orientation of input is ignored
+    let mut inverted = rect.clone();
+    inverted.invert_direction();
+    let (ccw, cw) = shape_properties(&inverted.morph_open(1.0));
+    assert!(property_sets_match(&ccw, &expected));
+    assert!(cw.is_empty());
+
+    // repeated calls give identical results
+    let again = rect.morph_open(1.0);
+    let (p1, p2) = (
+        &result.ccw_plines[0].polyline,
+        &again.ccw_plines[0].polyline,
+    );
+    assert_eq!(p1.len(), p2.len());
+    assert!(p1.iter().zip(p2.iter()).all(|(v1, v2)| v1 == v2));
+}
+
+#[test]
+fn morph_open_removes_narrow_neck() {
+    let dumbbell = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (4.0, 1.5, 0.0),
+        (6.0, 1.5, 0.0),
+        (6.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (6.0, 4.0, 0.0),
+        (6.0, 2.5, 0.0),
+        (4.0, 2.5, 0.0),
+        (4.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let result = dumbbell.morph_open(1.0);
+    assert_eq!(result.ccw_plines.len(), 2);
+    assert!(result.cw_plines.is_empty());
+
+    // both halves are mirror images of each other
+    let a = &result.ccw_plines[0].polyline;
+    let b = &result.ccw_plines[1].polyline;
+    assert_eq!(a.len(), b.len());
+    assert!(a.area().fuzzy_eq(b.area()));
+    let (left, right) = if a.extents().unwrap().min_x < b.extents().unwrap().min_x {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    assert!(left.extents().unwrap().max_x < 5.0);
+    assert!(right.extents().unwrap().min_x > 5.0);
+}
+
+#[test]
+fn morph_close_fills_inside_corner() {
+    let l_shape = pline_closed![
+        (0.0, 0.0, 0.0),
+        (6.0, 0.0, 0.0),
+        (6.0, 2.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 6.0, 0.0),
+        (0.0, 6.0, 0.0)
+    ];
+    let expected = [PlineProperties::new(
+        7,
+        21.0 - PI / 4.0,
+        22.0 + PI / 2.0,
+        0.0,
+        0.0,
+        6.0,
+        6.0,
+    )];
+    let (ccw, cw) = shape_properties(&l_shape.morph_close(1.0));
+    assert!(property_sets_match(&ccw, &expected));
+    assert!(cw.is_empty());
+}
+
+#[test]
+fn morph_close_merges_nearby_shapes() {
+    let a = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (4.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let b = pline_closed![
+        (5.0, 0.0, 0.0),
+        (9.0, 0.0, 0.0),
+        (9.0, 4.0, 0.0),
+        (5.0, 4.0, 0.0)
+    ];
+    let shape = Shape::from_plines(vec![a, b]);
+    let result = shape.morph_close(1.0);
+    assert_eq!(result.ccw_plines.len(), 1);
+    assert!(result.cw_plines.is_empty());
+    let merged = &result.ccw_plines[0].polyline;
+    assert!(merged.area() > 32.0 && merged.area() < 36.0);
+
+    // gap wider than the diameter is not closed
+    let result = shape.morph_close(0.4);
+    assert_eq!(result.ccw_plines.len(), 2);
+}
+
+#[test]
+fn morph_degenerate_inputs() {
+    let mut open = Polyline::new();
+    open.add(0.0, 0.0, 0.0);
+    open.add(1.0, 0.0, 0.0);
+    assert!(open.morph_open(1.0).is_empty());
+    assert!(open.morph_close(1.0).is_empty());
+
+    let shape = rect_with_circle_island();
+    let result = shape.morph_open(0.0);
+    let (ccw, cw) = shape_properties(&result);
+    let (expected_ccw, expected_cw) = shape_properties(&shape);
+    assert!(property_sets_match(&ccw, &expected_ccw));
+    assert!(property_sets_match(&cw, &expected_cw));
+}
@@ -1,10 +1,17 @@
 #[allow(unused)]
 mod debug;
 #[allow(unused)]
+mod pline_fixtures;
+#[allow(unused)]
 mod pline_modifiers;
 #[allow(unused)]
 mod pline_test_properties;
 
+#[allow(unused_imports)]
 pub use debug::*;
+#[allow(unused_imports)]
+pub use pline_fixtures::*;
+#[allow(unused_imports)]
 pub use pline_modifiers::*;
+#[allow(unused_imports)]
 pub use pline_test_properties::*;
@@ -0,0 +1,18 @@
+use cavalier_contours::{pline_closed, polyline::Polyline};
+
+/// Counter clockwise rectangle with corners at (`x0`, `y0`) and (`x1`, `y1`), starting at
+/// (`x0`, `y0`).
+pub fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polyline<f64> {
+    pline_closed![(x0, y0, 0.0), (x1, y0, 0.0), (x1, y1, 0.0), (x0, y1, 0.0)]
+}
+
+/// Counter clockwise square with its minimum corner at (`x`, `y`), starting at (`x`, `y`).
+pub fn square(x: f64, y: f64, size: f64) -> Polyline<f64> {
+    rect(x, y, x + size, y + size)
+}
+
+/// Counter clockwise circle made of two half circle arcs centered at (`x`, `y`), starting at its
+/// minimum x point.
+pub fn circle(x: f64, y: f64, radius: f64) -> Polyline<f64> {
+    pline_closed![(x - radius, y, 1.0), (x + radius, y, 1.0)]
+}
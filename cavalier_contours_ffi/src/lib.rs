@@ -88,6 +88,7 @@ impl cavc_pline_parallel_offset_o {
             slice_join_eps: self.slice_join_eps,
             offset_dist_eps: self.offset_dist_eps,
             handle_self_intersects: self.handle_self_intersects != 0,
+            ..Default::default()
         }
     }
 }
@@ -148,6 +149,7 @@ impl cavc_pline_boolean_o {
             pline1_aabb_index: self.pline1_aabb_index.as_ref().map(|w| &w.0),
             pos_equal_eps: self.pos_equal_eps,
             slice_join_eps: self.slice_join_eps,
+            ..Default::default()
         }
     }
 }